@@ -1,7 +1,7 @@
 use std::io::Error as IoError;
 
 use flex_error::{define_error, DisplayOnly};
-use tendermint::Error as TendermintError;
+use tendermint::{node, Error as TendermintError};
 
 define_error! {
     #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,5 +34,12 @@ define_error! {
         Tendermint
             [ TendermintError ]
             |_| { format_args!("tendermint error") },
+
+        PeerIdMismatch
+            {
+                expected: node::Id,
+                actual: node::Id,
+            }
+            |e| { format_args!("peer ID mismatch: expected {}, got {}", e.expected, e.actual) },
     }
 }
@@ -56,6 +56,25 @@ impl Address {
             format!("tcp://{raw_address}").parse().ok()
         }
     }
+
+    /// ID of the remote peer, if this is a TCP address which specifies one
+    pub fn peer_id(&self) -> Option<node::Id> {
+        match self {
+            Address::Tcp { peer_id, .. } => *peer_id,
+            Address::Unix { .. } => None,
+        }
+    }
+
+    /// Check that the ID of the peer reached at this address, as authenticated
+    /// when connecting to it, is the one specified by the address, if any.
+    pub fn verify_peer_id(&self, remote_id: node::Id) -> Result<(), Error> {
+        match self.peer_id() {
+            Some(expected) if expected != remote_id => {
+                Err(Error::peer_id_mismatch(expected, remote_id))
+            },
+            _ => Ok(()),
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for Address {
@@ -136,6 +155,7 @@ impl Serialize for Address {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ErrorDetail;
 
     const EXAMPLE_TCP_ADDR: &str =
         "tcp://abd636b766dcefb5322d8ca40011ec2cb35efbc2@35.192.61.41:26656";
@@ -229,4 +249,67 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn display_roundtrip() {
+        for addr in &[
+            EXAMPLE_TCP_ADDR,
+            EXAMPLE_TCP_ADDR_WITHOUT_ID,
+            EXAMPLE_UNIX_ADDR,
+            "tcp://abd636b766dcefb5322d8ca40011ec2cb35efbc2@node.example.com:26656",
+        ] {
+            let parsed = addr.parse::<Address>().unwrap();
+            assert_eq!(&parsed.to_string(), addr);
+            assert_eq!(parsed.to_string().parse::<Address>().unwrap(), parsed);
+        }
+
+        let addr = EXAMPLE_TCP_IPV6_ADDR.parse::<Address>().unwrap();
+        assert_eq!(addr.to_string().parse::<Address>().unwrap(), addr);
+    }
+
+    #[test]
+    fn parse_malformed_addr() {
+        for addr in &[
+            "",
+            "tcp://",
+            // Missing port
+            "tcp://35.192.61.41",
+            "tcp://abd636b766dcefb5322d8ca40011ec2cb35efbc2@35.192.61.41",
+            // Invalid port
+            "tcp://35.192.61.41:port",
+            "tcp://35.192.61.41:65536",
+            // Missing host
+            "tcp://abd636b766dcefb5322d8ca40011ec2cb35efbc2@:26656",
+            // Invalid peer ID
+            "tcp://abd636b7@35.192.61.41:26656",
+            "tcp://zbd636b766dcefb5322d8ca40011ec2cb35efbc2@35.192.61.41:26656",
+            // Unsupported scheme
+            "http://35.192.61.41:26656",
+        ] {
+            assert!(addr.parse::<Address>().is_err(), "parsed {addr:?}");
+        }
+    }
+
+    #[test]
+    fn verify_peer_id() {
+        let peer_id = "abd636b766dcefb5322d8ca40011ec2cb35efbc2"
+            .parse::<node::Id>()
+            .unwrap();
+        let other_id = "bbd636b766dcefb5322d8ca40011ec2cb35efbc2"
+            .parse::<node::Id>()
+            .unwrap();
+
+        let addr = EXAMPLE_TCP_ADDR.parse::<Address>().unwrap();
+        assert_eq!(addr.peer_id(), Some(peer_id));
+        assert!(addr.verify_peer_id(peer_id).is_ok());
+        assert!(matches!(
+            addr.verify_peer_id(other_id).unwrap_err().detail(),
+            ErrorDetail::PeerIdMismatch(e) if e.expected == peer_id && e.actual == other_id
+        ));
+
+        // Any peer is accepted at an address which does not specify its ID
+        let addr = EXAMPLE_TCP_ADDR_WITHOUT_ID.parse::<Address>().unwrap();
+        assert_eq!(addr.peer_id(), None);
+        assert!(addr.verify_peer_id(other_id).is_ok());
+    }
 }
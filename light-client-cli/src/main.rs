@@ -104,10 +104,20 @@ struct Cli {
     #[clap(long, default_value = "5")]
     max_clock_drift: u64,
 
-    /// Maximum block lag, in seconds
+    /// Maximum block lag of the witnesses behind the primary, in seconds, only used
+    /// when detecting forks (verified headers may be of any age)
     #[clap(long, default_value = "5")]
     max_block_lag: u64,
 
+    /// Maximum number of bisection steps per verification
+    #[clap(long, default_value = "64")]
+    max_bisection_depth: usize,
+
+    /// Warn about verified commits which the validators holding more than this
+    /// percentage of the voting power did not vote for
+    #[clap(long)]
+    absent_power_warning_threshold: Option<u8>,
+
     /// Increase verbosity
     #[clap(flatten)]
     verbose: Verbosity,
@@ -132,9 +142,17 @@ async fn main() -> Result<()> {
     let options = Options {
         trust_threshold: args.trust_threshold,
         trusting_period: Duration::from_secs(args.trusting_period),
-        clock_drift: Duration::from_secs(args.max_clock_drift),
+        max_clock_drift: Duration::from_secs(args.max_clock_drift),
+        max_block_lag: None,
+        refresh_margin: None,
+        max_bisection_depth: args.max_bisection_depth,
+        absent_power_warning_threshold: args.absent_power_warning_threshold,
     };
 
+    options
+        .validate()
+        .map_err(|e| eyre!("invalid verification options: {e}"))?;
+
     let mut primary = make_provider(
         &args.chain_id,
         args.primary,
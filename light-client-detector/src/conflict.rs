@@ -1,10 +1,10 @@
 use tendermint::{crypto::Sha256, evidence::LightClientAttackEvidence, merkle::MerkleHash};
-use tendermint_light_client::verifier::types::LightBlock;
+use tendermint_light_client::{evidence::make_evidence, verifier::types::LightBlock};
 use tracing::{error, error_span, warn};
 
 use super::{
-    error::Error, evidence::make_evidence, examine::examine_conflicting_header_against_trace,
-    provider::Provider, trace::Trace,
+    error::Error, examine::examine_conflicting_header_against_trace, provider::Provider,
+    trace::Trace,
 };
 
 #[derive(Clone, Debug)]
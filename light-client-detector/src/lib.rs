@@ -5,7 +5,6 @@
 mod conflict;
 mod detect;
 mod error;
-mod evidence;
 mod examine;
 mod provider;
 mod trace;
@@ -61,11 +61,11 @@ fn deserialize_params(
     })?;
 
     let options = serde_wasm_bindgen::from_value::<JsOptions>(options)
-        .map(Into::into)
         .map_err(|e| Error::Serialization {
             param: "options".into(),
             msg: e.to_string(),
-        })?;
+        })?
+        .try_into()?;
 
     let now = serde_wasm_bindgen::from_value(now).map_err(|e| Error::Serialization {
         param: "now".into(),
@@ -82,6 +82,10 @@ pub enum Error {
     /// A serialization/deserialization error occurred.
     #[serde(rename = "serialization")]
     Serialization { param: String, msg: String },
+
+    /// The supplied options are not valid verification parameters.
+    #[serde(rename = "invalid_options")]
+    InvalidOptions { msg: String },
 }
 
 // Simplified options supplied from JavaScript.
@@ -92,13 +96,20 @@ pub struct JsOptions {
     pub clock_drift: u64,
 }
 
-impl From<JsOptions> for Options {
-    fn from(o: JsOptions) -> Self {
+impl TryFrom<JsOptions> for Options {
+    type Error = Error;
+
+    fn try_from(o: JsOptions) -> Result<Self, Error> {
+        let invalid = |msg: String| Error::InvalidOptions { msg };
+
         let (num, den) = o.trust_threshold;
-        Self {
-            trust_threshold: TrustThreshold::new(num, den).unwrap(),
-            trusting_period: Duration::from_secs(o.trusting_period),
-            clock_drift: Duration::from_secs(o.clock_drift),
-        }
+        let trust_threshold = TrustThreshold::new(num, den).map_err(|e| invalid(e.to_string()))?;
+
+        Self::new(
+            trust_threshold,
+            Duration::from_secs(o.trusting_period),
+            Duration::from_secs(o.clock_drift),
+        )
+        .map_err(|e| invalid(e.to_string()))
     }
 }
@@ -192,6 +192,27 @@ fn failed_verification_outside_trusting_period() {
     }
 }
 
+#[wasm_bindgen_test]
+fn invalid_options_are_reported() {
+    let (untrusted_block, trusted_block) = test_blocks();
+    // The clock drift must be shorter than the trusting period
+    let options = serde_wasm_bindgen::to_value(&JsOptions {
+        trust_threshold: (1, 3),
+        trusting_period: 5,
+        clock_drift: 5,
+    })
+    .unwrap();
+    let now =
+        serde_wasm_bindgen::to_value(&Time::parse_from_rfc3339("1970-01-07T00:00:00Z").unwrap())
+            .unwrap();
+    let js_result = verify(untrusted_block, trusted_block, options, now);
+    console_log!("js_result = {:?}", js_result);
+    let error = serde_wasm_bindgen::from_value::<Result<Verdict, Error>>(js_result)
+        .unwrap()
+        .unwrap_err();
+    assert!(matches!(error, Error::InvalidOptions { .. }));
+}
+
 fn test_blocks() -> (JsValue, JsValue) {
     let untrusted_block =
         serde_wasm_bindgen::to_value(&serde_json::from_str::<LightBlock>(UNTRUSTED_BLOCK).unwrap())
@@ -0,0 +1,45 @@
+//! Measures the time taken to look up the hash of a header repeatedly, as the verifier
+//! and fork detection do, either hashing the header each time or once and for all:
+//!
+//! ```text
+//! cargo bench -p tendermint-light-client-verifier --bench header_hash
+//! ```
+
+use std::time::Instant;
+
+use tendermint::{
+    block::{HashedHeader, Header},
+    Hash, Time,
+};
+use tendermint_testgen::{Generator, Header as TestgenHeader, Validator};
+
+const ITERATIONS: u32 = 10_000;
+
+fn header() -> Header {
+    let validators = [Validator::new("a").voting_power(50)];
+
+    TestgenHeader::new(&validators)
+        .height(10)
+        .chain_id("bench-chain")
+        .time(Time::from_unix_timestamp(10, 0).unwrap())
+        .generate()
+        .unwrap()
+}
+
+fn bench(name: &str, hash: impl Fn() -> Hash) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        std::hint::black_box(hash());
+    }
+    let elapsed = start.elapsed();
+
+    println!("{name}: {:?} per lookup", elapsed / ITERATIONS);
+}
+
+fn main() {
+    let header = header();
+    bench("Header::hash", || header.hash());
+
+    let hashed = HashedHeader::new(header);
+    bench("HashedHeader::hash", || hashed.hash());
+}
@@ -0,0 +1,73 @@
+//! Measures the time taken to check that ⅔ of a large validator set signed a commit.
+//!
+//! Run with and without the `batch-verify` feature to compare batch and
+//! per-signature verification:
+//!
+//! ```text
+//! cargo bench -p tendermint-light-client-verifier --bench voting_power
+//! cargo bench -p tendermint-light-client-verifier --bench voting_power --features batch-verify
+//! ```
+//!
+//! Each set is measured in the light mode, which stops verifying signatures once
+//! ⅔ of the voting power is reached, and in the exhaustive mode, which verifies all of them.
+
+use std::time::{Duration, Instant};
+
+use tendermint::Time;
+use tendermint_light_client_verifier::{
+    operations::{ProdVotingPowerCalculator, VerificationMode, VotingPowerCalculator},
+    types::LightBlock,
+};
+use tendermint_testgen::{Generator, Header, LightBlock as TestgenLightBlock, Validator};
+
+const ITERATIONS: u32 = 20;
+
+fn light_block(validators: usize) -> LightBlock {
+    let validators = (0..validators)
+        .map(|i| Validator::new(&format!("validator-{i}")).voting_power(50))
+        .collect::<Vec<_>>();
+
+    let header = Header::new(&validators)
+        .height(10)
+        .chain_id("bench-chain")
+        .time(Time::from_unix_timestamp(10, 0).unwrap());
+
+    let tm_lb = TestgenLightBlock::new_default_with_header(header)
+        .generate()
+        .unwrap();
+
+    LightBlock::new(
+        tm_lb.signed_header,
+        tm_lb.validators,
+        tm_lb.next_validators,
+        tm_lb.provider,
+    )
+}
+
+fn bench(validators: usize, verification_mode: VerificationMode) {
+    let light_block = light_block(validators);
+    let calculator = ProdVotingPowerCalculator::default().with_verification_mode(verification_mode);
+
+    let mut elapsed = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        calculator
+            .check_signers_overlap(&light_block.signed_header, &light_block.validators)
+            .unwrap();
+        elapsed += start.elapsed();
+    }
+
+    println!(
+        "check_signers_overlap with {validators} validators ({verification_mode:?}): {:?} per commit",
+        elapsed / ITERATIONS
+    );
+}
+
+fn main() {
+    println!("batch-verify: {}", cfg!(feature = "batch-verify"));
+
+    for validators in [10, 100, 150, 300] {
+        bench(validators, VerificationMode::Light);
+        bench(validators, VerificationMode::Exhaustive);
+    }
+}
@@ -30,6 +30,17 @@ define_error! {
                     e.header_time, e.now, e.max_clock_drift)
             },
 
+        HeaderTooOld
+            {
+                header_time: Time,
+                now: Time,
+                max_block_lag: Duration,
+            }
+            | e | {
+                format_args!("header too old: header_time={0} now={1} max_block_lag={2:?}",
+                    e.header_time, e.now, e.max_block_lag)
+            },
+
         NotEnoughTrust
             {
                 tally: VotingPowerTally,
@@ -83,6 +94,16 @@ define_error! {
                     e.header_hash, e.commit_hash)
             },
 
+        CommitHeightMismatch
+            {
+                header_height: Height,
+                commit_height: Height,
+            }
+            | e | {
+                format_args!("commit height ({1}) does not match header height ({0})",
+                    e.header_height, e.commit_height)
+            },
+
         InvalidNextValidatorSet
             {
                 header_next_validators_hash: Hash,
@@ -93,6 +114,37 @@ define_error! {
                     e.header_next_validators_hash, e.next_validators_hash)
             },
 
+        NextValidatorSetMismatch
+            {
+                trusted_next_validators_hash: Hash,
+                untrusted_validators_hash: Hash,
+            }
+            | e | {
+                format_args!("validator set of the untrusted block does not match the next validator set of the adjacent trusted block: trusted_next_validators_hash={0} untrusted_validators_hash={1}",
+                    e.trusted_next_validators_hash, e.untrusted_validators_hash)
+            },
+
+        InvalidLastBlockId
+            {
+                last_block_id_hash: Hash,
+                trusted_header_hash: Hash,
+            }
+            | e | {
+                format_args!("last_block_id of the untrusted block does not match the adjacent trusted block: last_block_id_hash={0} trusted_header_hash={1}",
+                    e.last_block_id_hash, e.trusted_header_hash)
+            },
+
+        InvalidHeaderFieldHash
+            {
+                field: String,
+                header_hash: Hash,
+                computed_hash: Hash,
+            }
+            | e | {
+                format_args!("hash of the block data does not match the {0} of the header: header_hash={1} computed_hash={2}",
+                    e.field, e.header_hash, e.computed_hash)
+            },
+
         InvalidValidatorSet
             {
                 header_validators_hash: Hash,
@@ -135,17 +187,39 @@ define_error! {
 
         NotWithinTrustPeriod
             {
+                trusted_header_time: Time,
                 expires_at: Time,
                 now: Time,
             }
             | e | {
-                format_args!("not within trusting period: expires_at={0} now={1}",
-                    e.expires_at, e.now)
+                format_args!("not within trusting period: trusted_header_time={0} expires_at={1} now={2}",
+                    e.trusted_header_time, e.expires_at, e.now)
             },
 
         NoSignatureForCommit
             | _ | { "no signatures for commit"  },
 
+        CommitForNilBlock
+            {
+                height: Height,
+            }
+            | e | {
+                format_args!("commit at height {} is for a nil block", e.height)
+            },
+
+        MissingCommitSigSignature
+            {
+                index: usize,
+                validator: Id,
+            }
+            | e | {
+                format_args!(
+                    "non-absent vote of validator {} at index {} in commit has no signature",
+                    e.validator,
+                    e.index,
+                )
+            },
+
         MismatchPreCommitLength
             {
                 pre_commit_length: usize,
@@ -174,6 +248,54 @@ define_error! {
     }
 }
 
+define_error! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    OptionsError {
+        ZeroTrustingPeriod
+            | _ | { "the trusting period must not be zero" },
+
+        ClockDriftTooLarge
+            {
+                max_clock_drift: Duration,
+                trusting_period: Duration,
+            }
+            | e | {
+                format_args!("the maximum clock drift ({0:?}) must be shorter than the trusting period ({1:?})",
+                    e.max_clock_drift, e.trusting_period)
+            },
+
+        BlockLagTooLarge
+            {
+                max_block_lag: Duration,
+                trusting_period: Duration,
+            }
+            | e | {
+                format_args!("the maximum block lag ({0:?}) must be shorter than the trusting period ({1:?})",
+                    e.max_block_lag, e.trusting_period)
+            },
+
+        RefreshMarginTooLarge
+            {
+                refresh_margin: Duration,
+                trusting_period: Duration,
+            }
+            | e | {
+                format_args!("the refresh margin ({0:?}) must be shorter than the trusting period ({1:?})",
+                    e.refresh_margin, e.trusting_period)
+            },
+
+        ZeroBisectionDepth
+            | _ | { "the maximum bisection depth must not be zero" },
+
+        AbsentPowerWarningThresholdTooLarge
+            { threshold: u8 }
+            | e | {
+                format_args!("the absent power warning threshold ({0}%) must be at most 100%",
+                    e.threshold)
+            },
+    }
+}
+
 /// Extension methods for `ErrorKind`
 pub trait ErrorExt {
     /// Whether this error means that the light block
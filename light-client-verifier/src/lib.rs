@@ -11,7 +11,8 @@ pub mod predicates;
 pub mod types;
 mod verifier;
 
-pub use verifier::{PredicateVerifier, Verdict, Verifier};
+pub use tendermint::crypto::signature::Verifier as SignatureVerifier;
+pub use verifier::{FullVerifier, PredicateVerifier, Verdict, VerificationWarning, Verifier};
 
 #[cfg(feature = "rust-crypto")]
-pub use verifier::ProdVerifier;
+pub use verifier::{ProdFullVerifier, ProdVerifier, ProvidedVerifier};
@@ -32,6 +32,35 @@ pub trait CommitValidator: Send + Sync {
             ));
         }
 
+        // Check that the commit is for an actual block, which the votes
+        // flagged as commit are for.
+        if signed_header.commit.block_id.hash.is_empty() {
+            return Err(VerificationError::commit_for_nil_block(
+                signed_header.commit.height,
+            ));
+        }
+
+        // Check that the votes for the block or for nil are signed.  Votes for
+        // nil are otherwise ignored when tallying the voting power.
+        for (index, commit_sig) in signatures.iter().enumerate() {
+            if let CommitSig::BlockIdFlagCommit {
+                validator_address,
+                signature: None,
+                ..
+            }
+            | CommitSig::BlockIdFlagNil {
+                validator_address,
+                signature: None,
+                ..
+            } = commit_sig
+            {
+                return Err(VerificationError::missing_commit_sig_signature(
+                    index,
+                    *validator_address,
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -52,7 +81,7 @@ pub trait CommitValidator: Send + Sync {
                 } => validator_address,
             };
 
-            if validator_set.validator(*validator_address).is_none() {
+            if validator_set.get(*validator_address).is_none() {
                 return Err(VerificationError::faulty_signer(
                     *validator_address,
                     validator_set.clone(),
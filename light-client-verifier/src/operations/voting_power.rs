@@ -1,6 +1,6 @@
 //! Provides an interface and default implementation for the `VotingPower` operation
 
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, vec::Vec};
 use core::{fmt, marker::PhantomData};
 
 use serde::{Deserialize, Serialize};
@@ -11,7 +11,7 @@ use tendermint::{
     crypto::signature,
     trust_threshold::TrustThreshold as _,
     validator,
-    vote::{SignedVote, ValidatorIndex, Vote},
+    vote::{EncodingScheme, SignedVote, ValidatorIndex, Vote},
 };
 
 use crate::{
@@ -46,8 +46,22 @@ impl VotingPowerTally {
         debug_assert!(self.tallied <= self.total);
     }
 
+    /// The minimum tallied voting power which meets the trust threshold,
+    /// given the total voting power.
+    ///
+    /// This may exceed the total voting power if the trust threshold
+    /// cannot be met at all.
+    pub fn required(&self) -> u64 {
+        // Widen to avoid overflowing with large voting powers or fractions
+        let required = u128::from(self.total) * u128::from(self.trust_threshold.numerator())
+            / u128::from(self.trust_threshold.denominator())
+            + 1;
+
+        u64::try_from(required).unwrap_or(u64::MAX)
+    }
+
     /// Checks whether tallied amount meets trust threshold.
-    fn check(&self) -> Result<(), Self> {
+    pub fn check(&self) -> Result<(), Self> {
         if self
             .trust_threshold
             .is_enough_power(self.tallied, self.total)
@@ -69,6 +83,50 @@ impl fmt::Display for VotingPowerTally {
     }
 }
 
+/// Breakdown of the voting power of a validator set in a commit,
+/// as computed by [`ProvidedVotingPowerCalculator::commit_tally`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitTally {
+    /// Total voting power of the validator set
+    pub total: u64,
+    /// Voting power of the validators which signed the commit
+    pub signed: u64,
+    /// Voting power of the validators which voted for nil
+    pub nil: u64,
+    /// Voting power of the validators which did not vote
+    pub absent: u64,
+    /// Vote of the proposer of the block, or `None` if it is not
+    /// in the validator set
+    #[serde(default)]
+    pub proposer: Option<ProposerVote>,
+}
+
+/// Vote of the proposer of a block in its commit, as reported in a [`CommitTally`].
+///
+/// The proposer normally signs the block it proposed, so any other vote
+/// is worth monitoring even though it does not affect verification.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProposerVote {
+    /// The proposer signed the commit
+    Signed,
+    /// The proposer voted for nil
+    Nil,
+    /// The proposer did not vote
+    Absent,
+}
+
+impl CommitTally {
+    /// The tally of the voting power which signed the commit,
+    /// against the given trust threshold.
+    pub fn voting_power(&self, trust_threshold: TrustThreshold) -> VotingPowerTally {
+        VotingPowerTally {
+            total: self.total,
+            tallied: self.signed,
+            trust_threshold,
+        }
+    }
+}
+
 /// Computes the voting power in a commit against a validator set.
 ///
 /// This trait provides default implementation of some helper functions.
@@ -81,6 +139,25 @@ pub trait VotingPowerCalculator: Send + Sync {
             .fold(0u64, |total, val_info| total + val_info.power.value())
     }
 
+    /// Compute the voting power of the validators from the given set which did
+    /// not vote in the commit of the given header, neither for the block nor for nil.
+    ///
+    /// Signatures are not verified, this is only meant for monitoring.
+    fn absent_power_of(&self, signed_header: &SignedHeader, validator_set: &ValidatorSet) -> u64 {
+        let voted = signed_header
+            .commit
+            .signatures
+            .iter()
+            .filter_map(CommitSig::validator_address)
+            .collect::<BTreeSet<_>>();
+
+        validator_set
+            .validators()
+            .iter()
+            .filter(|validator| !voted.contains(&validator.address))
+            .fold(0u64, |absent, val_info| absent + val_info.power.value())
+    }
+
     /// Check that there is enough trust between an untrusted header and given
     /// trusted and untrusted validator sets.
     ///
@@ -185,21 +262,67 @@ pub trait VotingPowerCalculator: Send + Sync {
 
 /// Default implementation of a `VotingPowerCalculator`, parameterized with
 /// the signature verification trait.
+///
+/// With the `batch-verify` feature, the signatures needed to reach the trust
+/// threshold are verified together with [`signature::Verifier::verify_batch`].
+///
+/// The signed bytes of the votes are encoded with protobuf by default,
+/// see [`ProvidedVotingPowerCalculator::with_encoding_scheme`] otherwise.
+///
+/// Signatures are only verified until the trust threshold is reached by default,
+/// see [`ProvidedVotingPowerCalculator::with_verification_mode`] otherwise.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ProvidedVotingPowerCalculator<V> {
+    encoding_scheme: EncodingScheme,
+    verification_mode: VerificationMode,
     _verifier: PhantomData<V>,
 }
 
-// Safety: the only member is phantom data
+/// Which signatures of a commit are verified when computing its voting power.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum VerificationMode {
+    /// Stop as soon as the voting power of the valid signatures reaches the
+    /// trust threshold, as `VerifyCommitLight` does in Tendermint.
+    #[default]
+    Light,
+    /// Verify all the signatures, and tally all of their voting power,
+    /// eg. to check every signature of a commit submitted as evidence.
+    Exhaustive,
+}
+
+// Safety: the encoding scheme and the verification mode are `Copy` enums,
+// which are `Send + Sync`, and the other member is phantom data
 unsafe impl<V> Send for ProvidedVotingPowerCalculator<V> {}
 unsafe impl<V> Sync for ProvidedVotingPowerCalculator<V> {}
 
 impl<V> Default for ProvidedVotingPowerCalculator<V> {
     fn default() -> Self {
+        Self::with_encoding_scheme(EncodingScheme::default())
+    }
+}
+
+impl<V> ProvidedVotingPowerCalculator<V> {
+    /// Calculator checking the signatures of votes whose signed bytes are
+    /// encoded with the given scheme, eg. Amino for chains running a version
+    /// of Tendermint older than 0.34.
+    pub fn with_encoding_scheme(encoding_scheme: EncodingScheme) -> Self {
         Self {
+            encoding_scheme,
+            verification_mode: VerificationMode::default(),
             _verifier: PhantomData,
         }
     }
+
+    /// Calculator verifying the signatures of commits according to the given mode.
+    pub fn with_verification_mode(mut self, verification_mode: VerificationMode) -> Self {
+        self.verification_mode = verification_mode;
+        self
+    }
+
+    /// Which signatures of a commit this calculator verifies.
+    pub fn verification_mode(&self) -> VerificationMode {
+        self.verification_mode
+    }
 }
 
 /// A signed non-nil vote.
@@ -269,6 +392,8 @@ struct NonAbsentCommitVotes {
     /// The buffer is reused for each canonical vote so that we allocate it
     /// once.
     sign_bytes: Vec<u8>,
+    /// How the signed bytes of the votes are encoded.
+    encoding_scheme: EncodingScheme,
 }
 
 impl NonAbsentCommitVotes {
@@ -284,7 +409,10 @@ impl NonAbsentCommitVotes {
     /// thus the largest buffer we’ll ever need is 166 bytes long.
     const SIGN_BYTES_INITIAL_CAPACITY: usize = 166;
 
-    pub fn new(signed_header: &SignedHeader) -> Result<Self, VerificationError> {
+    pub fn new(
+        signed_header: &SignedHeader,
+        encoding_scheme: EncodingScheme,
+    ) -> Result<Self, VerificationError> {
         let mut votes = signed_header
             .commit
             .signatures
@@ -317,6 +445,7 @@ impl NonAbsentCommitVotes {
             Ok(Self {
                 votes,
                 sign_bytes: Vec::with_capacity(Self::SIGN_BYTES_INITIAL_CAPACITY),
+                encoding_scheme,
             })
         }
     }
@@ -341,7 +470,7 @@ impl NonAbsentCommitVotes {
         if !vote.verified {
             self.sign_bytes.truncate(0);
             vote.signed_vote
-                .sign_bytes_into(&mut self.sign_bytes)
+                .sign_bytes_into_with(self.encoding_scheme, &mut self.sign_bytes)
                 .expect("buffer is resized if needed and encoding never fails");
             let sign_bytes = self.sign_bytes.as_slice();
             validator
@@ -358,6 +487,80 @@ impl NonAbsentCommitVotes {
         vote.verified = true;
         Ok(true)
     }
+
+    /// Verifies together the signatures of the votes cast by validators from
+    /// the given set, in order, until their voting power meets the trust
+    /// threshold of the given tally, or all of them in the exhaustive mode.
+    ///
+    /// If batch verification fails, the signatures are then verified one at a
+    /// time so as to report which of them is invalid, as `has_voted` would.
+    #[cfg(feature = "batch-verify")]
+    pub fn verify_batch<V: signature::Verifier>(
+        &mut self,
+        validator_set: &ValidatorSet,
+        mut power: VotingPowerTally,
+        verification_mode: VerificationMode,
+    ) -> Result<(), VerificationError> {
+        let mut pending = Vec::new();
+        for validator in validator_set.validators() {
+            if verification_mode == VerificationMode::Light && power.check().is_ok() {
+                break;
+            }
+            let idx = self
+                .votes
+                .binary_search_by_key(&validator.address, NonAbsentCommitVote::validator_id);
+            let Ok(idx) = idx else {
+                continue;
+            };
+            power.tally(validator.power());
+            if !self.votes[idx].verified {
+                pending.push((idx, validator));
+            }
+        }
+
+        let sign_bytes = pending
+            .iter()
+            .map(|&(idx, _)| {
+                let mut sign_bytes = Vec::with_capacity(Self::SIGN_BYTES_INITIAL_CAPACITY);
+                self.votes[idx]
+                    .signed_vote
+                    .sign_bytes_into_with(self.encoding_scheme, &mut sign_bytes)
+                    .expect("buffer is resized if needed and encoding never fails");
+                sign_bytes
+            })
+            .collect::<Vec<_>>();
+
+        let items = pending
+            .iter()
+            .zip(&sign_bytes)
+            .map(|(&(idx, validator), sign_bytes)| {
+                (
+                    validator.pub_key,
+                    sign_bytes.as_slice(),
+                    self.votes[idx].signed_vote.signature(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if V::verify_batch(&items).is_err() {
+            for (&(_, validator), (_, sign_bytes, signature)) in pending.iter().zip(&items) {
+                validator
+                    .verify_signature::<V>(sign_bytes, signature)
+                    .map_err(|_| {
+                        VerificationError::invalid_signature(
+                            signature.as_bytes().to_vec(),
+                            Box::new(validator.clone()),
+                            sign_bytes.to_vec(),
+                        )
+                    })?;
+            }
+        }
+
+        for (idx, _) in pending {
+            self.votes[idx].verified = true;
+        }
+        Ok(())
+    }
 }
 
 /// Default implementation of a `VotingPowerCalculator`.
@@ -365,6 +568,58 @@ impl NonAbsentCommitVotes {
 pub type ProdVotingPowerCalculator =
     ProvidedVotingPowerCalculator<tendermint::crypto::default::signature::Verifier>;
 
+impl<V: signature::Verifier> ProvidedVotingPowerCalculator<V> {
+    /// Compute how much of the voting power of the validator set signed the
+    /// commit of the header, voted for nil, or did not vote at all, and how
+    /// the proposer of the header voted.
+    ///
+    /// Unlike [`VotingPowerCalculator::voting_power_in`], this verifies the
+    /// signatures of all the validators which signed the commit, and fails if
+    /// any of them is invalid.  The signatures of votes for nil are not verified.
+    pub fn commit_tally(
+        &self,
+        signed_header: &SignedHeader,
+        validator_set: &ValidatorSet,
+    ) -> Result<CommitTally, VerificationError> {
+        let mut votes = NonAbsentCommitVotes::new(signed_header, self.encoding_scheme)?;
+        let nil_votes = signed_header
+            .commit
+            .signatures
+            .iter()
+            .filter_map(|commit_sig| match commit_sig {
+                CommitSig::BlockIdFlagNil {
+                    validator_address, ..
+                } => Some(*validator_address),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut tally = CommitTally {
+            total: self.total_power_of(validator_set),
+            ..CommitTally::default()
+        };
+
+        for validator in validator_set.validators() {
+            let vote = if votes.has_voted::<V>(validator)? {
+                tally.signed += validator.power();
+                ProposerVote::Signed
+            } else if nil_votes.contains(&validator.address) {
+                tally.nil += validator.power();
+                ProposerVote::Nil
+            } else {
+                tally.absent += validator.power();
+                ProposerVote::Absent
+            };
+
+            if validator.address == signed_header.header.proposer_address {
+                tally.proposer = Some(vote);
+            }
+        }
+
+        Ok(tally)
+    }
+}
+
 impl<V: signature::Verifier> VotingPowerCalculator for ProvidedVotingPowerCalculator<V> {
     fn voting_power_in(
         &self,
@@ -372,12 +627,13 @@ impl<V: signature::Verifier> VotingPowerCalculator for ProvidedVotingPowerCalcul
         validator_set: &ValidatorSet,
         trust_threshold: TrustThreshold,
     ) -> Result<VotingPowerTally, VerificationError> {
-        let mut votes = NonAbsentCommitVotes::new(signed_header)?;
+        let mut votes = NonAbsentCommitVotes::new(signed_header, self.encoding_scheme)?;
         voting_power_in_impl::<V>(
             &mut votes,
             validator_set,
             trust_threshold,
             self.total_power_of(validator_set),
+            self.verification_mode,
         )
     }
 
@@ -387,18 +643,20 @@ impl<V: signature::Verifier> VotingPowerCalculator for ProvidedVotingPowerCalcul
         first_set: (&ValidatorSet, TrustThreshold),
         second_set: (&ValidatorSet, TrustThreshold),
     ) -> Result<(VotingPowerTally, VotingPowerTally), VerificationError> {
-        let mut votes = NonAbsentCommitVotes::new(signed_header)?;
+        let mut votes = NonAbsentCommitVotes::new(signed_header, self.encoding_scheme)?;
         let first_tally = voting_power_in_impl::<V>(
             &mut votes,
             first_set.0,
             first_set.1,
             self.total_power_of(first_set.0),
+            self.verification_mode,
         )?;
         let second_tally = voting_power_in_impl::<V>(
             &mut votes,
             second_set.0,
             second_set.1,
             self.total_power_of(second_set.0),
+            self.verification_mode,
         )?;
         Ok((first_tally, second_tally))
     }
@@ -409,13 +667,17 @@ fn voting_power_in_impl<V: signature::Verifier>(
     validator_set: &ValidatorSet,
     trust_threshold: TrustThreshold,
     total_voting_power: u64,
+    verification_mode: VerificationMode,
 ) -> Result<VotingPowerTally, VerificationError> {
     let mut power = VotingPowerTally::new(total_voting_power, trust_threshold);
+    #[cfg(feature = "batch-verify")]
+    votes.verify_batch::<V>(validator_set, power, verification_mode)?;
     for validator in validator_set.validators() {
         if votes.has_voted::<V>(validator)? {
             power.tally(validator.power());
-            // Break out of the loop when we have enough voting power.
-            if power.check().is_ok() {
+            // Break out of the loop when we have enough voting power,
+            // unless all the signatures must be verified.
+            if verification_mode == VerificationMode::Light && power.check().is_ok() {
                 break;
             }
         }
@@ -431,7 +693,7 @@ fn voting_power_in_impl<V: signature::Verifier>(
 mod tests {
     use tendermint::trust_threshold::TrustThresholdFraction;
     use tendermint_testgen::{
-        light_block::generate_signed_header, Commit, Generator, Header,
+        light_block::generate_signed_header, validator::sort_validators, Commit, Generator, Header,
         LightBlock as TestgenLightBlock, ValidatorSet, Vote as TestgenVote,
     };
 
@@ -509,6 +771,91 @@ mod tests {
         assert_eq!(result_ok.unwrap(), EXPECTED_RESULT);
     }
 
+    #[test]
+    fn test_commit_tally() {
+        let vp_calculator = ProdVotingPowerCalculator::default();
+
+        let validator_set = ValidatorSet::new(vec!["a", "b", "c", "d"]);
+        let vals = validator_set.clone().validators.unwrap();
+        let header = Header::new(&vals).time(tendermint::Time::from_unix_timestamp(10, 0).unwrap());
+        // The last validator does not vote
+        let votes = vec![
+            TestgenVote::new(vals[0].clone(), header.clone()),
+            TestgenVote::new(vals[1].clone(), header.clone()),
+            TestgenVote::new(vals[2].clone(), header.clone()).nil(true),
+        ];
+        let commit = Commit::new_with_votes(header.clone(), 1, votes);
+        let signed_header = generate_signed_header(&header, &commit).unwrap();
+        let valset = validator_set.generate().unwrap();
+
+        let tally = vp_calculator.commit_tally(&signed_header, &valset).unwrap();
+        assert_eq!(
+            tally,
+            CommitTally {
+                total: 200,
+                signed: 100,
+                nil: 50,
+                absent: 50,
+                proposer: Some(ProposerVote::Signed),
+            }
+        );
+
+        assert!(tally
+            .voting_power(TrustThresholdFraction::new(1, 2).unwrap())
+            .check()
+            .is_err());
+        assert!(tally
+            .voting_power(TrustThresholdFraction::ONE_THIRD)
+            .check()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_commit_tally_proposer_vote() {
+        let vp_calculator = ProdVotingPowerCalculator::default();
+
+        let validator_set = ValidatorSet::new(vec!["a", "b", "c"]);
+        let vals = validator_set.clone().validators.unwrap();
+        let valset = validator_set.generate().unwrap();
+
+        // The first validator signs, the second one votes for nil, the last one does not vote
+        let expected = [
+            ProposerVote::Signed,
+            ProposerVote::Nil,
+            ProposerVote::Absent,
+        ];
+        for (val, expected) in vals.iter().zip(expected) {
+            let proposer = sort_validators(&vals)
+                .iter()
+                .position(|v| v == val)
+                .unwrap();
+            let header = Header::new(&vals)
+                .proposer(proposer)
+                .time(tendermint::Time::from_unix_timestamp(10, 0).unwrap());
+            let votes = vec![
+                TestgenVote::new(vals[0].clone(), header.clone()),
+                TestgenVote::new(vals[1].clone(), header.clone()).nil(true),
+            ];
+            let commit = Commit::new_with_votes(header.clone(), 1, votes);
+            let signed_header = generate_signed_header(&header, &commit).unwrap();
+
+            let tally = vp_calculator.commit_tally(&signed_header, &valset).unwrap();
+            assert_eq!(tally.proposer, Some(expected));
+            assert_eq!(tally.signed, 50);
+        }
+
+        // The proposer is not part of the validator set the commit is tallied against
+        let header = Header::new(&vals).time(tendermint::Time::from_unix_timestamp(10, 0).unwrap());
+        let commit = Commit::new(header.clone(), 1);
+        let signed_header = generate_signed_header(&header, &commit).unwrap();
+        let other_valset = ValidatorSet::new(vec!["d"]).generate().unwrap();
+
+        let tally = vp_calculator
+            .commit_tally(&signed_header, &other_valset)
+            .unwrap();
+        assert_eq!(tally.proposer, None);
+    }
+
     #[test]
     fn test_one_invalid_signature() {
         let vp_calculator = ProdVotingPowerCalculator::default();
@@ -559,6 +906,80 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_invalid_signature_is_identified() {
+        let vp_calculator = ProdVotingPowerCalculator::default();
+
+        let validator_set = ValidatorSet::new(vec!["a", "b", "c", "d", "e", "f", "g", "h"]);
+        let vals = validator_set.clone().validators.unwrap();
+        let header = Header::new(&vals).time(tendermint::Time::from_unix_timestamp(10, 0).unwrap());
+        let bad_header = header.clone().chain_id("bad-chain");
+        let votes = vals
+            .iter()
+            .enumerate()
+            .map(|(i, val)| {
+                let header = if i == 5 { &bad_header } else { &header };
+                TestgenVote::new(val.clone(), header.clone())
+            })
+            .collect();
+        let commit = Commit::new_with_votes(header.clone(), 1, votes);
+        let signed_header = generate_signed_header(&header, &commit).unwrap();
+        let valset = validator_set.generate().unwrap();
+
+        let result_err = vp_calculator.voting_power_in(
+            &signed_header,
+            &valset,
+            TrustThresholdFraction::new(1, 1).unwrap(),
+        );
+
+        match result_err {
+            Err(VerificationError(VerificationErrorDetail::InvalidSignature(e), _)) => {
+                assert_eq!(e.validator.address, vals[5].generate().unwrap().address);
+            },
+            _ => panic!("expected InvalidSignature error"),
+        }
+    }
+
+    #[test]
+    fn test_exhaustive_verification_mode() {
+        let validator_set = ValidatorSet::new(vec!["a", "b", "c", "d", "e", "f", "g", "h"]);
+        let vals = validator_set.clone().validators.unwrap();
+        let valset = validator_set.generate().unwrap();
+
+        // Only the signature of the last validator to be counted is invalid
+        let last = valset.validators().last().unwrap().address;
+        let header = Header::new(&vals).time(tendermint::Time::from_unix_timestamp(10, 0).unwrap());
+        let bad_header = header.clone().chain_id("bad-chain");
+        let votes = vals
+            .iter()
+            .map(|val| {
+                let is_last = val.generate().unwrap().address == last;
+                let header = if is_last { &bad_header } else { &header };
+                TestgenVote::new(val.clone(), header.clone())
+            })
+            .collect();
+        let commit = Commit::new_with_votes(header.clone(), 1, votes);
+        let signed_header = generate_signed_header(&header, &commit).unwrap();
+
+        let light = ProdVotingPowerCalculator::default();
+        assert_eq!(light.verification_mode(), VerificationMode::Light);
+        let tally = light
+            .voting_power_in(&signed_header, &valset, TrustThresholdFraction::ONE_THIRD)
+            .unwrap();
+        assert!(tally.tallied < tally.total);
+
+        let exhaustive = ProdVotingPowerCalculator::default()
+            .with_verification_mode(VerificationMode::Exhaustive);
+        let result_err =
+            exhaustive.voting_power_in(&signed_header, &valset, TrustThresholdFraction::ONE_THIRD);
+        match result_err {
+            Err(VerificationError(VerificationErrorDetail::InvalidSignature(e), _)) => {
+                assert_eq!(e.validator.address, last);
+            },
+            _ => panic!("expected InvalidSignature error"),
+        }
+    }
+
     #[test]
     fn test_signatures_from_diff_valset() {
         let vp_calculator = ProdVotingPowerCalculator::default();
@@ -581,4 +1002,112 @@ mod tests {
         // ensure the result matches the expected result
         assert_eq!(result_ok.unwrap(), EXPECTED_RESULT);
     }
+
+    #[test]
+    fn test_configured_trust_threshold() {
+        let vp_calculator = ProdVotingPowerCalculator::default();
+
+        // Only one out of two validators with equal power signed
+        let mut testgen_lb = TestgenLightBlock::new_default(10);
+        let mut commit = testgen_lb.commit.clone().unwrap();
+        let mut votes = commit.votes.unwrap();
+        votes.pop();
+        commit.votes = Some(votes);
+        testgen_lb.commit = Some(commit);
+        let light_block: LightBlock = testgen_lb.generate().unwrap().into();
+        let (signed_header, valset) = (light_block.signed_header, light_block.validators);
+
+        let check = |trust_threshold| {
+            vp_calculator
+                .voting_power_in(&signed_header, &valset, trust_threshold)
+                .unwrap()
+                .check()
+        };
+
+        assert!(check(TrustThresholdFraction::ONE_THIRD).is_ok());
+        assert!(check(TrustThresholdFraction::new(2, 5).unwrap()).is_ok());
+        assert!(check(TrustThresholdFraction::new(1, 2).unwrap()).is_err());
+        assert!(check(TrustThresholdFraction::TWO_THIRDS).is_err());
+    }
+
+    #[test]
+    fn test_required_voting_power() {
+        let tally = |total, trust_threshold| VotingPowerTally::new(total, trust_threshold);
+
+        assert_eq!(tally(100, TrustThresholdFraction::ONE_THIRD).required(), 34);
+        assert_eq!(tally(99, TrustThresholdFraction::ONE_THIRD).required(), 34);
+        assert_eq!(
+            tally(300, TrustThresholdFraction::TWO_THIRDS).required(),
+            201
+        );
+        assert_eq!(tally(0, TrustThresholdFraction::TWO_THIRDS).required(), 1);
+
+        let one = TrustThresholdFraction::new(1, 1).unwrap();
+        assert_eq!(tally(u64::MAX, one).required(), u64::MAX);
+
+        // The required voting power is the smallest one to pass the check
+        let mut tally = tally(100, TrustThresholdFraction::TWO_THIRDS);
+        tally.tally(tally.required() - 1);
+        assert!(tally.check().is_err());
+        tally.tally(1);
+        assert!(tally.check().is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "amino")]
+    fn test_amino_encoding_scheme() {
+        let testgen_lb = TestgenLightBlock::new_default(10);
+        let validators = testgen_lb.validators.clone().unwrap();
+        let mut light_block: LightBlock = testgen_lb.generate().unwrap().into();
+
+        // Sign the votes of the commit again, over their Amino encoding
+        let commit = light_block.signed_header.commit.clone();
+        let chain_id = light_block.signed_header.header.chain_id.clone();
+        for (idx, commit_sig) in light_block
+            .signed_header
+            .commit
+            .signatures
+            .iter_mut()
+            .enumerate()
+        {
+            let vote = NonAbsentCommitVote::new(
+                commit_sig,
+                ValidatorIndex::try_from(idx).unwrap(),
+                &commit,
+                &chain_id,
+            )
+            .unwrap()
+            .unwrap();
+
+            let mut sign_bytes = Vec::new();
+            vote.signed_vote
+                .sign_bytes_into_with(EncodingScheme::Amino, &mut sign_bytes)
+                .unwrap();
+
+            let validator = validators
+                .iter()
+                .find(|v| v.generate().unwrap().address == vote.validator_id())
+                .unwrap();
+
+            if let CommitSig::BlockIdFlagCommit { signature, .. } = commit_sig {
+                *signature = Some(validator.sign(&sign_bytes).unwrap());
+            }
+        }
+
+        let voting_power_in = |vp_calculator: ProdVotingPowerCalculator| {
+            vp_calculator.voting_power_in(
+                &light_block.signed_header,
+                &light_block.validators,
+                TrustThreshold::default(),
+            )
+        };
+
+        let amino = ProdVotingPowerCalculator::with_encoding_scheme(EncodingScheme::Amino);
+        assert!(voting_power_in(amino).unwrap().check().is_ok());
+
+        match voting_power_in(ProdVotingPowerCalculator::default()) {
+            Err(VerificationError(VerificationErrorDetail::InvalidSignature(_), _)) => {},
+            result => panic!("expected InvalidSignature error, got: {result:?}"),
+        }
+    }
 }
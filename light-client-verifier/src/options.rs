@@ -7,24 +7,316 @@ use core::time::Duration;
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
-use crate::types::TrustThreshold;
+use crate::{errors::OptionsError, types::TrustThreshold};
 
 /// Verification parameters
+///
+/// Prefer [`Options::new`] to a struct literal, as it checks the invariants documented
+/// on each field, see [`Options::validate`]. Otherwise, verification could fail in
+/// confusing ways. Struct literals remain handy for tests of such edge cases.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Display, Serialize, Deserialize)]
 #[display(fmt = "{self:?}")]
 pub struct Options {
     /// Defines what fraction of the total voting power of a known
     /// and trusted validator set is sufficient for a commit to be
     /// accepted going forward.
+    ///
+    /// Is always between 1/3 and 1, as otherwise a commit could be accepted on the
+    /// word of faulty validators only: this is checked when creating a [`TrustThreshold`].
     pub trust_threshold: TrustThreshold,
 
     /// How long a validator set is trusted for (must be shorter than the chain's
     /// unbonding period)
+    ///
+    /// Must not be zero, as no header could ever be trusted otherwise.
     pub trusting_period: Duration,
 
     /// Correction parameter dealing with only approximately synchronized clocks.
     /// The local clock should always be ahead of timestamps from the blockchain; this
     /// is the maximum amount that the local clock may drift behind a timestamp from the
-    /// blockchain.
-    pub clock_drift: Duration,
+    /// blockchain, ie. how far in the future a header may be.
+    ///
+    /// Must be shorter than the trusting period.
+    ///
+    /// Formerly named `clock_drift`, which is still accepted when deserializing.
+    #[serde(alias = "clock_drift")]
+    pub max_clock_drift: Duration,
+
+    /// If set, the maximum amount that the local clock may run ahead of the blockchain,
+    /// ie. how old an untrusted header may be. Older headers are rejected, while headers
+    /// of any age are accepted otherwise. This does not extend the trusting period.
+    ///
+    /// Must be shorter than the trusting period.
+    #[serde(default)]
+    pub max_block_lag: Option<Duration>,
+
+    /// If set, how long before the latest trusted header gets older than the trusting
+    /// period the light client should refresh its trusted state, ie. verify up to the
+    /// highest block again. Refreshing is disabled otherwise.
+    ///
+    /// Must be shorter than the trusting period, as the trusted state would have to be
+    /// refreshed constantly otherwise.
+    #[serde(default)]
+    pub refresh_margin: Option<Duration>,
+
+    /// Maximum number of bisection steps a single verification may take before
+    /// it is aborted, bounding the work done against a misbehaving or pathological chain.
+    ///
+    /// Must not be zero, as no light block could be verified otherwise.
+    #[serde(default = "default_max_bisection_depth")]
+    pub max_bisection_depth: usize,
+
+    /// If set, the percentage of the total voting power of a validator set above
+    /// which the validators that did not vote for a verified commit are reported
+    /// in a [`VerificationWarning::HighAbsentPower`](crate::VerificationWarning::HighAbsentPower).
+    /// This is meant for monitoring the liveness of the chain, and does not affect
+    /// verification itself.
+    ///
+    /// Must be at most 100.
+    #[serde(default)]
+    pub absent_power_warning_threshold: Option<u8>,
+}
+
+/// Default maximum number of bisection steps, generous enough for honest chains.
+pub const DEFAULT_MAX_BISECTION_DEPTH: usize = 64;
+
+fn default_max_bisection_depth() -> usize {
+    DEFAULT_MAX_BISECTION_DEPTH
+}
+
+impl Options {
+    /// Verification parameters tolerating headers up to `clock_drift` in the future,
+    /// without bounding how old a header may be, without refreshing the trusted state, with at most
+    /// [`DEFAULT_MAX_BISECTION_DEPTH`] bisection steps, and without warnings
+    /// about absent voting power.
+    ///
+    /// Headers of any past height can thus be verified. See [`Options::new_symmetric`]
+    /// to also reject headers older than the clock drift.
+    ///
+    /// Fails if these parameters do not uphold the invariants checked by [`Options::validate`].
+    pub fn new(
+        trust_threshold: TrustThreshold,
+        trusting_period: Duration,
+        clock_drift: Duration,
+    ) -> Result<Self, OptionsError> {
+        let options = Self {
+            trust_threshold,
+            trusting_period,
+            max_clock_drift: clock_drift,
+            max_block_lag: None,
+            refresh_margin: None,
+            max_bisection_depth: DEFAULT_MAX_BISECTION_DEPTH,
+            absent_power_warning_threshold: None,
+        };
+
+        options.validate()?;
+        Ok(options)
+    }
+
+    /// Same as [`Options::new`], but using `clock_drift` symmetrically, as both the
+    /// maximum clock drift and the maximum block lag: headers more than `clock_drift`
+    /// in the future or in the past are rejected.
+    ///
+    /// This suits a light client following the tip of the chain, but not one verifying
+    /// headers at past heights, which are older than the clock drift.
+    pub fn new_symmetric(
+        trust_threshold: TrustThreshold,
+        trusting_period: Duration,
+        clock_drift: Duration,
+    ) -> Result<Self, OptionsError> {
+        let options = Self {
+            max_block_lag: Some(clock_drift),
+            ..Self::new(trust_threshold, trusting_period, clock_drift)?
+        };
+
+        options.validate()?;
+        Ok(options)
+    }
+
+    /// Check that these parameters make sense, ie. that:
+    ///
+    /// - the trusting period is not zero,
+    /// - the maximum clock drift, the maximum block lag and the refresh margin, if any,
+    ///   are shorter than the trusting period,
+    /// - the maximum bisection depth is not zero,
+    /// - the absent power warning threshold, if any, is at most 100%.
+    pub fn validate(&self) -> Result<(), OptionsError> {
+        // The trust threshold is valid by construction
+        let trusting_period = self.trusting_period;
+
+        if trusting_period.is_zero() {
+            return Err(OptionsError::zero_trusting_period());
+        }
+
+        if self.max_clock_drift >= trusting_period {
+            return Err(OptionsError::clock_drift_too_large(
+                self.max_clock_drift,
+                trusting_period,
+            ));
+        }
+
+        if let Some(max_block_lag) = self.max_block_lag {
+            if max_block_lag >= trusting_period {
+                return Err(OptionsError::block_lag_too_large(
+                    max_block_lag,
+                    trusting_period,
+                ));
+            }
+        }
+
+        if let Some(refresh_margin) = self.refresh_margin {
+            if refresh_margin >= trusting_period {
+                return Err(OptionsError::refresh_margin_too_large(
+                    refresh_margin,
+                    trusting_period,
+                ));
+            }
+        }
+
+        if self.max_bisection_depth == 0 {
+            return Err(OptionsError::zero_bisection_depth());
+        }
+
+        if let Some(threshold) = self.absent_power_warning_threshold {
+            if threshold > 100 {
+                return Err(OptionsError::absent_power_warning_threshold_too_large(
+                    threshold,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::OptionsErrorDetail;
+
+    const DAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+    fn options() -> Options {
+        Options::new(
+            TrustThreshold::TWO_THIRDS,
+            10 * DAY,
+            Duration::from_secs(10),
+        )
+        .unwrap()
+    }
+
+    fn error(options: Options) -> OptionsErrorDetail {
+        options.validate().unwrap_err().0
+    }
+
+    #[test]
+    fn accepts_sensible_options() {
+        let mut options = options();
+        options.refresh_margin = Some(DAY);
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn symmetric_options_bound_block_lag() {
+        let options = Options::new_symmetric(
+            TrustThreshold::TWO_THIRDS,
+            10 * DAY,
+            Duration::from_secs(10),
+        )
+        .unwrap();
+        assert_eq!(options.max_clock_drift, Duration::from_secs(10));
+        assert_eq!(options.max_block_lag, Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn deserializes_options_serialized_before_clock_drift_was_split() {
+        let options = options();
+        let json = serde_json::json!({
+            "trust_threshold": options.trust_threshold,
+            "trusting_period": options.trusting_period,
+            "clock_drift": options.max_clock_drift,
+        });
+        assert_eq!(serde_json::from_value::<Options>(json).unwrap(), options);
+    }
+
+    #[test]
+    fn rejects_zero_trusting_period() {
+        let err = Options::new(TrustThreshold::ONE_THIRD, Duration::ZERO, Duration::ZERO);
+        assert!(matches!(
+            err.unwrap_err().0,
+            OptionsErrorDetail::ZeroTrustingPeriod(_)
+        ));
+    }
+
+    #[test]
+    fn rejects_clock_drift_not_shorter_than_trusting_period() {
+        let err = Options::new(TrustThreshold::ONE_THIRD, DAY, DAY);
+        assert!(matches!(
+            err.unwrap_err().0,
+            OptionsErrorDetail::ClockDriftTooLarge(_)
+        ));
+
+        let options = Options {
+            max_clock_drift: 11 * DAY,
+            ..options()
+        };
+        assert!(matches!(
+            error(options),
+            OptionsErrorDetail::ClockDriftTooLarge(e) if e.max_clock_drift == 11 * DAY
+        ));
+    }
+
+    #[test]
+    fn rejects_block_lag_not_shorter_than_trusting_period() {
+        let options = Options {
+            max_block_lag: Some(10 * DAY),
+            ..options()
+        };
+        assert!(matches!(
+            error(options),
+            OptionsErrorDetail::BlockLagTooLarge(_)
+        ));
+    }
+
+    #[test]
+    fn rejects_refresh_margin_not_shorter_than_trusting_period() {
+        let options = Options {
+            refresh_margin: Some(10 * DAY),
+            ..options()
+        };
+        assert!(matches!(
+            error(options),
+            OptionsErrorDetail::RefreshMarginTooLarge(_)
+        ));
+    }
+
+    #[test]
+    fn rejects_zero_bisection_depth() {
+        let options = Options {
+            max_bisection_depth: 0,
+            ..options()
+        };
+        assert!(matches!(
+            error(options),
+            OptionsErrorDetail::ZeroBisectionDepth(_)
+        ));
+    }
+
+    #[test]
+    fn rejects_absent_power_warning_threshold_above_100_percent() {
+        let valid = Options {
+            absent_power_warning_threshold: Some(100),
+            ..options()
+        };
+        assert!(valid.validate().is_ok());
+
+        let options = Options {
+            absent_power_warning_threshold: Some(101),
+            ..options()
+        };
+        assert!(matches!(
+            error(options),
+            OptionsErrorDetail::AbsentPowerWarningThresholdTooLarge(e) if e.threshold == 101
+        ));
+    }
 }
@@ -3,14 +3,14 @@
 use core::time::Duration;
 
 use tendermint::{
-    block::Height, chain::Id as ChainId, crypto::Sha256, hash::Hash, merkle::MerkleHash,
+    abci, block::Height, chain::Id as ChainId, crypto::Sha256, hash::Hash, merkle::MerkleHash, tx,
 };
 
 use crate::{
     errors::VerificationError,
     operations::{CommitValidator, VotingPowerCalculator},
     prelude::*,
-    types::{Header, SignedHeader, Time, TrustThreshold, ValidatorSet},
+    types::{BlockData, Header, SignedHeader, Time, TrustThreshold, ValidatorSet},
 };
 
 /// Production predicates, using the default implementation
@@ -24,6 +24,31 @@ impl VerificationPredicates for ProdPredicates {
     type Sha256 = tendermint::crypto::default::Sha256;
 }
 
+/// Whether a header with the given time is still within the given trusting period at
+/// time `now`, ie. whether it expires strictly after `now`.
+///
+/// This is the decision made by [`VerificationPredicates::is_within_trust_period`].
+/// A header from the future is within the trusting period.
+pub fn is_within_trusting_period(header_time: Time, trusting_period: Duration, now: Time) -> bool {
+    !trusting_period_remaining(header_time, trusting_period, now).is_zero()
+}
+
+/// How long a header with the given time remains within the given trusting period after
+/// time `now`, or [`Duration::ZERO`] if it has expired already.
+///
+/// See [`is_within_trusting_period`].
+pub fn trusting_period_remaining(
+    header_time: Time,
+    trusting_period: Duration,
+    now: Time,
+) -> Duration {
+    match header_time.checked_add(trusting_period) {
+        Some(expires_at) => expires_at.duration_since(now).unwrap_or(Duration::ZERO),
+        // The header never expires
+        None => Duration::MAX,
+    }
+}
+
 /// Defines the various predicates used to validate and verify light blocks.
 ///
 /// A default, spec abiding implementation is provided for each method.
@@ -86,6 +111,73 @@ pub trait VerificationPredicates: Send + Sync {
         }
     }
 
+    /// Check that the commit is for the height of the header.
+    fn header_height_matches_commit(
+        &self,
+        header: &Header,
+        commit_height: Height,
+    ) -> Result<(), VerificationError> {
+        if header.height == commit_height {
+            Ok(())
+        } else {
+            Err(VerificationError::commit_height_mismatch(
+                header.height,
+                commit_height,
+            ))
+        }
+    }
+
+    /// Check that the hashes in the header match the hashes of the given block data,
+    /// for each of its sub-structures which is supplied.
+    fn block_data_matches(
+        &self,
+        header: &Header,
+        data: &BlockData<'_>,
+    ) -> Result<(), VerificationError> {
+        let hashes = [
+            data.txs.map(|txs| {
+                (
+                    "data_hash",
+                    header.data_hash.unwrap_or_default(),
+                    tx::data_hash_with::<Self::Sha256>(txs),
+                )
+            }),
+            data.evidence.map(|evidence| {
+                (
+                    "evidence_hash",
+                    header.evidence_hash.unwrap_or_default(),
+                    evidence.hash_with::<Self::Sha256>(),
+                )
+            }),
+            data.consensus_params.map(|consensus_params| {
+                (
+                    "consensus_hash",
+                    header.consensus_hash,
+                    consensus_params.hash_with::<Self::Sha256>(),
+                )
+            }),
+            data.last_results.map(|last_results| {
+                (
+                    "last_results_hash",
+                    header.last_results_hash.unwrap_or_default(),
+                    abci::types::results_hash_with::<Self::Sha256>(last_results),
+                )
+            }),
+        ];
+
+        for (field, header_hash, computed_hash) in hashes.into_iter().flatten() {
+            if header_hash != computed_hash {
+                return Err(VerificationError::invalid_header_field_hash(
+                    field.to_string(),
+                    header_hash,
+                    computed_hash,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate the commit using the given commit validator.
     fn valid_commit(
         &self,
@@ -99,20 +191,45 @@ pub trait VerificationPredicates: Send + Sync {
         Ok(())
     }
 
-    /// Check that the trusted header is within the trusting period, adjusting for clock drift.
+    /// Check that the trusted header is within the trusting period.
     fn is_within_trust_period(
         &self,
         trusted_header_time: Time,
         trusting_period: Duration,
         now: Time,
     ) -> Result<(), VerificationError> {
-        let expires_at =
-            (trusted_header_time + trusting_period).map_err(VerificationError::tendermint)?;
+        if is_within_trusting_period(trusted_header_time, trusting_period, now) {
+            return Ok(());
+        }
 
-        if expires_at > now {
-            Ok(())
-        } else {
-            Err(VerificationError::not_within_trust_period(expires_at, now))
+        // Cannot overflow, as the header would never expire otherwise
+        let expires_at = trusted_header_time
+            .checked_add(trusting_period)
+            .ok_or_else(|| {
+                VerificationError::tendermint(tendermint::Error::duration_out_of_range())
+            })?;
+
+        Err(VerificationError::not_within_trust_period(
+            trusted_header_time,
+            expires_at,
+            now,
+        ))
+    }
+
+    /// Check that the untrusted header is not older than `max_block_lag` at time `now`.
+    fn is_header_within_block_lag(
+        &self,
+        untrusted_header_time: Time,
+        max_block_lag: Duration,
+        now: Time,
+    ) -> Result<(), VerificationError> {
+        match untrusted_header_time.checked_add(max_block_lag) {
+            Some(lagged) if lagged < now => Err(VerificationError::header_too_old(
+                untrusted_header_time,
+                now,
+                max_block_lag,
+            )),
+            _ => Ok(()),
         }
     }
 
@@ -120,18 +237,22 @@ pub trait VerificationPredicates: Send + Sync {
     fn is_header_from_past(
         &self,
         untrusted_header_time: Time,
-        clock_drift: Duration,
+        max_clock_drift: Duration,
         now: Time,
     ) -> Result<(), VerificationError> {
-        let drifted = (now + clock_drift).map_err(VerificationError::tendermint)?;
+        // No header can be dated after the end of the representable time range
+        let is_from_past = match now.checked_add(max_clock_drift) {
+            Some(drifted) => untrusted_header_time < drifted,
+            None => true,
+        };
 
-        if untrusted_header_time < drifted {
+        if is_from_past {
             Ok(())
         } else {
             Err(VerificationError::header_from_the_future(
                 untrusted_header_time,
                 now,
-                clock_drift,
+                max_clock_drift,
             ))
         }
     }
@@ -231,6 +352,28 @@ pub trait VerificationPredicates: Send + Sync {
         Ok(())
     }
 
+    /// Check that the `last_block_id` of the untrusted header, if any, is the hash
+    /// of the trusted header, ie. that the trusted block is the parent of the untrusted one.
+    fn is_linked_to_parent(
+        &self,
+        untrusted_header: &Header,
+        trusted_header: &Header,
+    ) -> Result<(), VerificationError> {
+        let Some(last_block_id) = untrusted_header.last_block_id else {
+            return Ok(());
+        };
+
+        let trusted_header_hash = trusted_header.hash_with::<Self::Sha256>();
+        if last_block_id.hash == trusted_header_hash {
+            Ok(())
+        } else {
+            Err(VerificationError::invalid_last_block_id(
+                last_block_id.hash,
+                trusted_header_hash,
+            ))
+        }
+    }
+
     /// Check that the hash of the next validator set in the trusted block matches
     /// the hash of the validator set in the untrusted one.
     fn valid_next_validator_set(
@@ -241,9 +384,9 @@ pub trait VerificationPredicates: Send + Sync {
         if trusted_next_validators_hash == untrusted_validators_hash {
             Ok(())
         } else {
-            Err(VerificationError::invalid_next_validator_set(
-                untrusted_validators_hash,
+            Err(VerificationError::next_validator_set_mismatch(
                 trusted_next_validators_hash,
+                untrusted_validators_hash,
             ))
         }
     }
@@ -263,9 +406,12 @@ mod tests {
     use crate::{
         errors::{VerificationError, VerificationErrorDetail},
         operations::{ProdCommitValidator, ProdVotingPowerCalculator, VotingPowerTally},
-        predicates::{ProdPredicates, VerificationPredicates},
+        predicates::{
+            is_within_trusting_period, trusting_period_remaining, ProdPredicates,
+            VerificationPredicates,
+        },
         prelude::*,
-        types::{LightBlock, TrustThreshold},
+        types::{LightBlock, Time, TrustThreshold},
     };
 
     impl From<TmLightBlock> for LightBlock {
@@ -372,6 +518,7 @@ mod tests {
         let expires_at = (header.time + trusting_period).unwrap();
         match result_err {
             Err(VerificationError(VerificationErrorDetail::NotWithinTrustPeriod(e), _)) => {
+                assert_eq!(e.trusted_header_time, header.time);
                 assert_eq!(e.expires_at, expires_at);
                 assert_eq!(e.now, now);
             },
@@ -379,6 +526,99 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_header_within_block_lag() {
+        let vp = ProdPredicates;
+        let header_time = Time::from_unix_timestamp(1_000, 0).unwrap();
+        let max_block_lag = Duration::from_secs(10);
+        let at = |secs: i64| Time::from_unix_timestamp(secs, 0).unwrap();
+
+        // Headers from the future and headers lagging up to `max_block_lag` are accepted
+        assert!(vp
+            .is_header_within_block_lag(header_time, max_block_lag, at(900))
+            .is_ok());
+        assert!(vp
+            .is_header_within_block_lag(header_time, max_block_lag, at(1_010))
+            .is_ok());
+
+        match vp.is_header_within_block_lag(header_time, max_block_lag, at(1_011)) {
+            Err(VerificationError(VerificationErrorDetail::HeaderTooOld(e), _)) => {
+                assert_eq!(e.header_time, header_time);
+                assert_eq!(e.now, at(1_011));
+                assert_eq!(e.max_block_lag, max_block_lag);
+            },
+            _ => panic!("expected HeaderTooOld error"),
+        }
+    }
+
+    #[test]
+    fn test_is_within_trusting_period_at_boundary() {
+        let header_time = Time::from_unix_timestamp(1_000, 0).unwrap();
+        let trusting_period = Duration::from_secs(100);
+        let at = |secs: i64, nanos: u32| Time::from_unix_timestamp(secs, nanos).unwrap();
+
+        // One nanosecond before expiry
+        let now = at(1_099, 999_999_999);
+        assert!(is_within_trusting_period(header_time, trusting_period, now));
+        assert_eq!(
+            trusting_period_remaining(header_time, trusting_period, now),
+            Duration::from_nanos(1)
+        );
+
+        // Exactly at expiry
+        let now = at(1_100, 0);
+        assert!(!is_within_trusting_period(
+            header_time,
+            trusting_period,
+            now
+        ));
+        assert_eq!(
+            trusting_period_remaining(header_time, trusting_period, now),
+            Duration::ZERO
+        );
+
+        // Long after expiry
+        let now = at(2_000, 0);
+        assert!(!is_within_trusting_period(
+            header_time,
+            trusting_period,
+            now
+        ));
+        assert_eq!(
+            trusting_period_remaining(header_time, trusting_period, now),
+            Duration::ZERO
+        );
+
+        // The predicate makes the same decision
+        let vp = ProdPredicates;
+        assert!(vp
+            .is_within_trust_period(header_time, trusting_period, at(1_099, 0))
+            .is_ok());
+        assert!(vp
+            .is_within_trust_period(header_time, trusting_period, at(1_100, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_is_within_trusting_period_before_header_time() {
+        let header_time = Time::from_unix_timestamp(1_000, 0).unwrap();
+        let trusting_period = Duration::from_secs(100);
+        let now = Time::from_unix_timestamp(990, 0).unwrap();
+
+        assert!(is_within_trusting_period(header_time, trusting_period, now));
+        assert_eq!(
+            trusting_period_remaining(header_time, trusting_period, now),
+            Duration::from_secs(110)
+        );
+
+        // A trusting period too long to be represented never expires
+        assert!(is_within_trusting_period(header_time, Duration::MAX, now));
+        assert_eq!(
+            trusting_period_remaining(header_time, Duration::MAX, now),
+            Duration::MAX
+        );
+    }
+
     #[test]
     fn test_is_header_from_past() {
         let val = Validator::new("val-1");
@@ -407,6 +647,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_header_from_past_with_overflowing_drift() {
+        let vp = ProdPredicates;
+        let now = Time::parse_from_rfc3339("9999-12-31T23:59:59Z").unwrap();
+
+        // The drifted time cannot be represented, but no header can be dated after it
+        assert!(vp.is_header_from_past(now, Duration::MAX, now).is_ok());
+        assert!(vp
+            .is_header_from_past(now, Duration::from_secs(1), Time::unix_epoch())
+            .is_err());
+    }
+
     #[test]
     // NOTE: tests both current valset and next valset
     fn test_validator_sets_match() {
@@ -508,6 +760,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_header_height_matches_commit() {
+        let testgen_lb = TestgenLightBlock::new_default(1);
+        let signed_header = testgen_lb.generate().unwrap().signed_header;
+        let next_commit = testgen_lb.next().generate().unwrap().signed_header.commit;
+
+        let vp = ProdPredicates;
+
+        // 1. ensure the commit of the header verifies
+        let result_ok =
+            vp.header_height_matches_commit(&signed_header.header, signed_header.commit.height);
+        assert!(result_ok.is_ok());
+
+        // 2. ensure the commit of the next header fails
+        let result_err = vp.header_height_matches_commit(&signed_header.header, next_commit.height);
+
+        // 3. ensure it fails with: VerificationVerificationError::CommitHeightMismatch
+        match result_err {
+            Err(VerificationError(VerificationErrorDetail::CommitHeightMismatch(e), _)) => {
+                assert_eq!(e.header_height, signed_header.header.height);
+                assert_eq!(e.commit_height, next_commit.height);
+            },
+            _ => panic!("expected CommitHeightMismatch error"),
+        }
+    }
+
+    #[test]
+    fn test_is_linked_to_parent() {
+        let testgen_lb = TestgenLightBlock::new_default(1);
+        let parent = testgen_lb.generate().unwrap().signed_header.header;
+        let child = testgen_lb.next().generate().unwrap().signed_header.header;
+
+        let vp = ProdPredicates;
+
+        // 1. ensure the child of a header is linked to it
+        assert!(vp.is_linked_to_parent(&child, &parent).is_ok());
+
+        // 2. ensure a header is not linked to another one than its parent
+        let result_err = vp.is_linked_to_parent(&child, &child);
+        match result_err {
+            Err(VerificationError(VerificationErrorDetail::InvalidLastBlockId(e), _)) => {
+                assert_eq!(e.last_block_id_hash, parent.hash());
+                assert_eq!(e.trusted_header_hash, child.hash());
+            },
+            _ => panic!("expected InvalidLastBlockId error"),
+        }
+
+        // 3. ensure headers without a last block id are not checked
+        assert!(parent.last_block_id.is_none());
+        assert!(vp.is_linked_to_parent(&parent, &child).is_ok());
+    }
+
     #[test]
     fn test_valid_commit() {
         let light_block: LightBlock = TestgenLightBlock::new_default(1).generate().unwrap().into();
@@ -595,6 +899,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_valid_commit_sig_flags() {
+        let light_block: LightBlock = TestgenLightBlock::new_default(1).generate().unwrap().into();
+        let val_set = light_block.validators;
+
+        let vp = ProdPredicates;
+        let commit_validator = ProdCommitValidator;
+
+        let valid_commit_with = |update: &dyn Fn(&mut CommitSig)| {
+            let mut signed_header = light_block.signed_header.clone();
+            update(&mut signed_header.commit.signatures[0]);
+            vp.valid_commit(&signed_header, &val_set, &commit_validator)
+                .err()
+        };
+
+        let validator = light_block.signed_header.commit.signatures[0]
+            .validator_address()
+            .unwrap();
+
+        let assert_missing_signature = |err: Option<VerificationError>| match err {
+            Some(VerificationError(VerificationErrorDetail::MissingCommitSigSignature(e), _)) => {
+                assert_eq!(e.index, 0);
+                assert_eq!(e.validator, validator);
+            },
+            _ => panic!("expected MissingCommitSigSignature error, got: {err:?}"),
+        };
+
+        // 1. vote for the block without a signature - must return error
+        assert_missing_signature(valid_commit_with(&|commit_sig| {
+            if let CommitSig::BlockIdFlagCommit { signature, .. } = commit_sig {
+                *signature = None;
+            }
+        }));
+
+        // 2. vote for nil without a signature - must return error
+        let to_nil = |commit_sig: &mut CommitSig, keep_signature: bool| {
+            if let CommitSig::BlockIdFlagCommit {
+                validator_address,
+                timestamp,
+                signature,
+            } = commit_sig.clone()
+            {
+                *commit_sig = CommitSig::BlockIdFlagNil {
+                    validator_address,
+                    timestamp,
+                    signature: signature.filter(|_| keep_signature),
+                };
+            }
+        };
+        assert_missing_signature(valid_commit_with(&|commit_sig| to_nil(commit_sig, false)));
+
+        // 3. signed vote for nil - should be "Ok"
+        assert!(valid_commit_with(&|commit_sig| to_nil(commit_sig, true)).is_none());
+
+        // 4. commit for a nil block - must return error
+        let mut signed_header = light_block.signed_header.clone();
+        signed_header.commit.block_id.hash = tendermint::Hash::None;
+        match vp.valid_commit(&signed_header, &val_set, &commit_validator) {
+            Err(VerificationError(VerificationErrorDetail::CommitForNilBlock(e), _)) => {
+                assert_eq!(e.height, signed_header.commit.height);
+            },
+            result => panic!("expected CommitForNilBlock error, got: {result:?}"),
+        }
+    }
+
     #[test]
     fn test_valid_next_validator_set() {
         let test_lb1 = TestgenLightBlock::new_default(1);
@@ -629,17 +998,17 @@ mod tests {
         );
 
         match result_err {
-            Err(VerificationError(VerificationErrorDetail::InvalidNextValidatorSet(e), _)) => {
+            Err(VerificationError(VerificationErrorDetail::NextValidatorSetMismatch(e), _)) => {
                 assert_eq!(
-                    e.header_next_validators_hash,
+                    e.untrusted_validators_hash,
                     light_block3.signed_header.header.validators_hash
                 );
                 assert_eq!(
-                    e.next_validators_hash,
+                    e.trusted_next_validators_hash,
                     light_block2.signed_header.header.next_validators_hash
                 );
             },
-            _ => panic!("expected InvalidNextValidatorSet error"),
+            _ => panic!("expected NextValidatorSetMismatch error"),
         }
     }
 
@@ -688,7 +1057,8 @@ mod tests {
         };
         match result {
             Err(VerificationError(VerificationErrorDetail::NotEnoughTrust(e), _)) => {
-                assert_eq!(expected_tally, e.tally)
+                assert_eq!(expected_tally, e.tally);
+                assert_eq!(e.tally.required(), 134);
             },
             _ => panic!("expected NotEnoughTrust error, got: {result:?}"),
         }
@@ -1,22 +1,34 @@
 //! Defines or just re-exports the main datatypes used by the light client.
 
 use derive_more::Display;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 use tendermint::{
+    abci::types::ExecTxResult,
     account::Id as TMAccountId,
     block::{
         header::Header as TMHeader, signed_header::SignedHeader as TMSignedHeader,
-        Commit as TMCommit,
+        Block as TMBlock, Commit as TMCommit,
     },
     chain::Id as ChainId,
+    consensus::Params as ConsensusParams,
+    evidence::List as EvidenceList,
     trust_threshold::TrustThresholdFraction,
     validator::{Info as TMValidatorInfo, Set as TMValidatorSet},
+    Error as TMError,
 };
 pub use tendermint::{block::Height, hash::Hash, time::Time};
+use tendermint_proto::{
+    v0_38::types::{SignedHeader as RawSignedHeader, ValidatorSet as RawValidatorSet},
+    Error as ProtobufError, Protobuf,
+};
 
 use crate::prelude::*;
 
 /// Peer ID (public key) of a full node
+///
+/// It can be derived from the node's ed25519 public key with `PeerId::try_from`,
+/// or parsed from its hex form with `str::parse`.
 pub type PeerId = tendermint::node::Id;
 
 /// defines what fraction of the total voting power of a known
@@ -100,6 +112,33 @@ impl<'a> UntrustedBlockState<'a> {
     }
 }
 
+/// Sub-structures of a block which its header commits to, besides its validator sets,
+/// to be checked by the [`FullVerifier`](crate::FullVerifier).
+///
+/// Only the sub-structures which are supplied are checked.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BlockData<'a> {
+    /// Transactions of the block, committed to by the `data_hash` of its header.
+    pub txs: Option<&'a [Vec<u8>]>,
+    /// Evidence included in the block, committed to by the `evidence_hash` of its header.
+    pub evidence: Option<&'a EvidenceList>,
+    /// Consensus parameters of the block, committed to by the `consensus_hash` of its header.
+    pub consensus_params: Option<&'a ConsensusParams>,
+    /// Results of executing the transactions of the previous block,
+    /// committed to by the `last_results_hash` of its header.
+    pub last_results: Option<&'a [ExecTxResult]>,
+}
+
+impl<'a> From<&'a TMBlock> for BlockData<'a> {
+    fn from(block: &'a TMBlock) -> Self {
+        Self {
+            txs: Some(&block.data),
+            evidence: Some(&block.evidence),
+            ..Default::default()
+        }
+    }
+}
+
 /// A light block is the core data structure used by the light client.
 /// It records everything the light client needs to know about a block.
 #[derive(Clone, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
@@ -110,7 +149,8 @@ pub struct LightBlock {
     /// Validator set at the block height
     #[serde(rename = "validator_set")]
     pub validators: ValidatorSet,
-    /// Validator set at the next block height
+    /// Validator set at the next block height, which is empty if it was not fetched
+    /// along with the rest of the block, see [`LightBlock::has_next_validators`]
     #[serde(rename = "next_validator_set")]
     pub next_validators: ValidatorSet,
     /// The peer ID of the node that provided this block
@@ -149,6 +189,39 @@ impl LightBlock {
         self.signed_header.header.time
     }
 
+    /// Whether the validator set at the next block height is known.
+    ///
+    /// ## Note
+    /// Without it, this block can be verified but can then only be used as trusted state
+    /// to verify the block right after it, as skipping to a higher block requires knowing
+    /// the voting power of the next validators.
+    pub fn has_next_validators(&self) -> bool {
+        !self.next_validators.validators().is_empty()
+    }
+
+    /// Whether this light block has the same signed header and validator sets as
+    /// the given one, regardless of which peer provided either of them.
+    ///
+    /// ## Note
+    /// Unlike `==`, this ignores the `provider` of both blocks, so that the same block
+    /// fetched from different peers compares equal.
+    pub fn same_content(&self, other: &Self) -> bool {
+        self.signed_header == other.signed_header
+            && self.validators == other.validators
+            && self.next_validators == other.next_validators
+    }
+
+    /// Encode this light block into its Protobuf representation, see [`RawLightBlock`].
+    pub fn encode_to_vec(&self) -> Vec<u8> {
+        Protobuf::<RawLightBlock>::encode_vec(self.clone())
+    }
+
+    /// Decode a light block from its Protobuf representation, as encoded by
+    /// [`LightBlock::encode_to_vec`].
+    pub fn decode(bytes: &[u8]) -> Result<Self, ProtobufError> {
+        Protobuf::<RawLightBlock>::decode_vec(bytes)
+    }
+
     /// Obtain the verification parameters for the light block when using it as
     /// trusted state.
     pub fn as_trusted_state(&self) -> TrustedBlockState<'_> {
@@ -163,15 +236,85 @@ impl LightBlock {
 
     /// Obtain the verification parameters for the light block when using it as
     /// untrusted state.
+    ///
+    /// The next validator set is left out if it is not known, in which case
+    /// verification does not check it against the header.
     pub fn as_untrusted_state(&self) -> UntrustedBlockState<'_> {
         UntrustedBlockState {
             signed_header: &self.signed_header,
             validators: &self.validators,
-            next_validators: Some(&self.next_validators),
+            next_validators: self.has_next_validators().then_some(&self.next_validators),
         }
     }
 }
 
+/// Protobuf representation of a [`LightBlock`], as used by [`LightBlock::encode_to_vec`].
+///
+/// Its signed header and validator sets are encoded with the Protobuf messages of
+/// Tendermint v0.38, and the field numbers below are fixed, so that light blocks
+/// encoded by any version of this crate can be decoded by the others.
+/// The first two fields match the `tendermint.types.LightBlock` message.
+#[derive(Clone, PartialEq, Message)]
+pub struct RawLightBlock {
+    /// Header and commit of the block
+    #[prost(message, optional, tag = "1")]
+    pub signed_header: Option<RawSignedHeader>,
+    /// Validator set at the block height
+    #[prost(message, optional, tag = "2")]
+    pub validator_set: Option<RawValidatorSet>,
+    /// Validator set at the next block height
+    #[prost(message, optional, tag = "3")]
+    pub next_validator_set: Option<RawValidatorSet>,
+    /// The peer ID of the node that provided the block
+    #[prost(bytes = "vec", tag = "4")]
+    pub provider: Vec<u8>,
+}
+
+impl TryFrom<RawLightBlock> for LightBlock {
+    type Error = TMError;
+
+    fn try_from(value: RawLightBlock) -> Result<Self, Self::Error> {
+        let signed_header = value
+            .signed_header
+            .ok_or_else(TMError::invalid_signed_header)?
+            .try_into()?;
+        let validators = value
+            .validator_set
+            .ok_or_else(TMError::missing_validator)?
+            .try_into()?;
+        let next_validators = value
+            .next_validator_set
+            .ok_or_else(TMError::missing_validator)?
+            .try_into()?;
+        let provider = value
+            .provider
+            .as_slice()
+            .try_into()
+            .map(PeerId::new)
+            .map_err(|_| TMError::length())?;
+
+        Ok(Self::new(
+            signed_header,
+            validators,
+            next_validators,
+            provider,
+        ))
+    }
+}
+
+impl From<LightBlock> for RawLightBlock {
+    fn from(value: LightBlock) -> Self {
+        RawLightBlock {
+            signed_header: Some(value.signed_header.into()),
+            validator_set: Some(value.validators.into()),
+            next_validator_set: Some(value.next_validators.into()),
+            provider: value.provider.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl Protobuf<RawLightBlock> for LightBlock {}
+
 /// Contains the local status information, like the latest height, latest block and valset hashes,
 /// list of of connected full nodes (primary and witnesses).
 #[derive(Clone, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
@@ -243,4 +386,107 @@ mod tests {
                 .collect()
         }
     }
+
+    mod light_block {
+        use tendermint_testgen::{
+            light_block::LightBlock as TestgenLightBlock, Generator, Validator,
+        };
+
+        use tendermint_proto::v0_38::types::LightBlock as ProtoLightBlock;
+
+        use crate::{
+            prelude::*,
+            types::{LightBlock, PeerId, RawLightBlock, ValidatorSet},
+        };
+
+        fn light_block(height: u64, provider: PeerId) -> LightBlock {
+            let tm_lb = TestgenLightBlock::new_default(height).generate().unwrap();
+            LightBlock::new(
+                tm_lb.signed_header,
+                tm_lb.validators,
+                tm_lb.next_validators,
+                provider,
+            )
+        }
+
+        #[test]
+        fn same_content_ignores_provider() {
+            let a = light_block(3, PeerId::new([1; 20]));
+            let b = light_block(3, PeerId::new([2; 20]));
+
+            assert_ne!(a, b);
+            assert!(a.same_content(&b));
+            assert!(b.same_content(&a));
+        }
+
+        #[test]
+        fn same_content_compares_blocks() {
+            let provider = PeerId::new([1; 20]);
+            let a = light_block(3, provider);
+
+            assert!(!a.same_content(&light_block(4, provider)));
+
+            let mut b = a.clone();
+            let validator = Validator::new("z").generate().unwrap();
+            b.next_validators = ValidatorSet::new(vec![validator], None);
+            assert!(!a.same_content(&b));
+        }
+
+        #[test]
+        fn protobuf_round_trip() {
+            let a = light_block(3, PeerId::new([1; 20]));
+            let bytes = a.encode_to_vec();
+
+            let decoded = LightBlock::decode(&bytes).unwrap();
+            assert_eq!(decoded, a);
+
+            let json = serde_json::to_string(&a).unwrap();
+            let from_json: LightBlock = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded, from_json);
+            assert_eq!(from_json.encode_to_vec(), bytes);
+
+            assert!(bytes.len() < json.len());
+        }
+
+        #[test]
+        fn protobuf_field_layout() {
+            let provider = PeerId::new([1; 20]);
+            let a = light_block(3, provider);
+            let bytes = a.encode_to_vec();
+
+            // The provider is encoded last, as field 4
+            let mut provider_field = vec![0x22, 20];
+            provider_field.extend_from_slice(provider.as_bytes());
+            assert!(bytes.ends_with(&provider_field));
+
+            // The first fields can be decoded as a `tendermint.types.LightBlock`
+            let proto = <ProtoLightBlock as prost::Message>::decode(bytes.as_slice()).unwrap();
+            assert_eq!(proto.signed_header, Some(a.signed_header.clone().into()));
+            assert_eq!(proto.validator_set, Some(a.validators.clone().into()));
+        }
+
+        #[test]
+        fn protobuf_decode_rejects_incomplete_blocks() {
+            assert!(LightBlock::decode(&[]).is_err());
+
+            let a = light_block(3, PeerId::new([1; 20]));
+            let bytes = a.encode_to_vec();
+            assert!(LightBlock::decode(&bytes[..bytes.len() - 1]).is_err());
+
+            let raw = RawLightBlock::from(a);
+            let encode = |raw: RawLightBlock| prost::Message::encode_to_vec(&raw);
+
+            let missing_next_validators = RawLightBlock {
+                next_validator_set: None,
+                ..raw.clone()
+            };
+            assert!(LightBlock::decode(&encode(missing_next_validators)).is_err());
+
+            let short_provider = RawLightBlock {
+                provider: vec![1; 19],
+                ..raw
+            };
+            assert!(LightBlock::decode(&encode(short_provider)).is_err());
+        }
+    }
 }
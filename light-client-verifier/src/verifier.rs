@@ -1,5 +1,7 @@
 //! Provides an interface and default implementation of the `Verifier` component
 
+use core::fmt;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -7,18 +9,23 @@ use crate::{
     operations::{voting_power::VotingPowerTally, CommitValidator, VotingPowerCalculator},
     options::Options,
     predicates::VerificationPredicates,
-    types::{Time, TrustedBlockState, UntrustedBlockState},
+    prelude::*,
+    types::{BlockData, Header, Height, Time, TrustedBlockState, UntrustedBlockState},
 };
 
 #[cfg(feature = "rust-crypto")]
 use crate::{
-    operations::{ProdCommitValidator, ProdVotingPowerCalculator},
+    operations::{
+        ProdCommitValidator, ProdVotingPowerCalculator, ProvidedVotingPowerCalculator,
+        VerificationMode,
+    },
     predicates::ProdPredicates,
 };
 
 /// Represents the result of the verification performed by the
 /// verifier component.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
 pub enum Verdict {
     /// Verification succeeded, the block is valid.
     Success,
@@ -41,6 +48,38 @@ impl From<Result<(), VerificationError>> for Verdict {
     }
 }
 
+/// Non-fatal finding about a light block which passed verification,
+/// worth monitoring nonetheless, as reported by [`Verifier::warnings`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum VerificationWarning {
+    /// The validators which did not vote in the commit of the light block hold
+    /// more than [`Options::absent_power_warning_threshold`] of the voting power,
+    /// which hints at a liveness issue.
+    HighAbsentPower {
+        /// Height of the light block
+        height: Height,
+        /// Voting power of the validators which did not vote
+        absent: u64,
+        /// Total voting power of the validator set
+        total: u64,
+    },
+}
+
+impl fmt::Display for VerificationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HighAbsentPower {
+                height,
+                absent,
+                total,
+            } => write!(
+                f,
+                "validators holding {absent} of the {total} voting power did not vote for the block at height {height}"
+            ),
+        }
+    }
+}
+
 /// The verifier checks:
 ///
 /// a) whether a given untrusted light block is valid, and
@@ -71,6 +110,36 @@ pub trait Verifier: Send + Sync {
         options: &Options,
         now: Time,
     ) -> Verdict;
+
+    /// Verify that the trusted header is the parent of the untrusted one, at the
+    /// next height, ie. that the `last_block_id` of the latter, if present, is the
+    /// hash of the former.
+    ///
+    /// By default, this is checked with the production predicates.
+    #[cfg(feature = "rust-crypto")]
+    fn verify_parent_link(&self, untrusted_header: &Header, trusted_header: &Header) -> Verdict {
+        ProdPredicates
+            .is_linked_to_parent(untrusted_header, trusted_header)
+            .into()
+    }
+
+    /// Verify that the trusted header is the parent of the untrusted one, at the
+    /// next height, ie. that the `last_block_id` of the latter, if present, is the
+    /// hash of the former.
+    #[cfg(not(feature = "rust-crypto"))]
+    fn verify_parent_link(&self, untrusted_header: &Header, trusted_header: &Header) -> Verdict;
+
+    /// Report the non-fatal warnings about the given light block, which passed
+    /// verification, according to the given options.
+    ///
+    /// Nothing is reported by default.
+    fn warnings(
+        &self,
+        _untrusted: UntrustedBlockState<'_>,
+        _options: &Options,
+    ) -> Vec<VerificationWarning> {
+        Vec::new()
+    }
 }
 
 macro_rules! verdict {
@@ -131,6 +200,12 @@ where
             ));
         }
 
+        // Ensure the commit is for the height of the header
+        verdict!(self.predicates.header_height_matches_commit(
+            &untrusted.signed_header.header,
+            untrusted.signed_header.commit.height,
+        ));
+
         // Ensure the header matches the commit
         verdict!(self.predicates.header_matches_commit(
             &untrusted.signed_header.header,
@@ -202,13 +277,31 @@ where
     ) -> Verdict {
         verdict!(self.predicates.is_header_from_past(
             untrusted.signed_header.header.time,
-            options.clock_drift,
+            options.max_clock_drift,
             now,
         ));
 
         Verdict::Success
     }
 
+    /// Ensure the header isn't older than the maximum block lag, if any
+    pub fn check_header_is_within_block_lag(
+        &self,
+        untrusted: &UntrustedBlockState<'_>,
+        options: &Options,
+        now: Time,
+    ) -> Verdict {
+        if let Some(max_block_lag) = options.max_block_lag {
+            verdict!(self.predicates.is_header_within_block_lag(
+                untrusted.signed_header.header.time,
+                max_block_lag,
+                now,
+            ));
+        }
+
+        Verdict::Success
+    }
+
     /// Verify that more than 2/3 of the validators correctly committed the block.
     ///
     /// Use [`PredicateVerifier::verify_commit_against_trusted()`] to also verify that there is
@@ -275,6 +368,7 @@ where
     /// - Validate the untrusted header against the trusted header
     ///     - Ensure the latest trusted header hasn't expired
     ///     - Ensure the header isn't from a future time
+    ///     - Ensure the header isn't older than the maximum block lag, if any
     ///     - Check that the untrusted block is more recent than the trusted state
     ///     - If the untrusted block is the very next block after the trusted block, check that
     ///       their (next) validator sets hashes match.
@@ -302,6 +396,7 @@ where
         ensure_verdict_success!(self.verify_validator_sets(&untrusted));
         ensure_verdict_success!(self.validate_against_trusted(&untrusted, &trusted, options, now));
         ensure_verdict_success!(self.check_header_is_from_past(&untrusted, options, now));
+        ensure_verdict_success!(self.check_header_is_within_block_lag(&untrusted, options, now));
         ensure_verdict_success!(self.verify_commit_against_trusted(&untrusted, &trusted, options));
 
         Verdict::Success
@@ -322,24 +417,188 @@ where
         ensure_verdict_success!(self.verify_commit_against_trusted(&untrusted, &trusted, options));
         Verdict::Success
     }
+
+    fn verify_parent_link(&self, untrusted_header: &Header, trusted_header: &Header) -> Verdict {
+        self.predicates
+            .is_linked_to_parent(untrusted_header, trusted_header)
+            .into()
+    }
+
+    fn warnings(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        options: &Options,
+    ) -> Vec<VerificationWarning> {
+        let mut warnings = Vec::new();
+
+        if let Some(threshold) = options.absent_power_warning_threshold {
+            let total = self
+                .voting_power_calculator
+                .total_power_of(untrusted.validators);
+            let absent = self
+                .voting_power_calculator
+                .absent_power_of(untrusted.signed_header, untrusted.validators);
+
+            if u128::from(absent) * 100 > u128::from(threshold) * u128::from(total) {
+                warnings.push(VerificationWarning::HighAbsentPower {
+                    height: untrusted.height(),
+                    absent,
+                    total,
+                });
+            }
+        }
+
+        warnings
+    }
+}
+
+#[cfg(feature = "rust-crypto")]
+/// A [`PredicateVerifier`] with the production predicates and commit validator,
+/// which verifies signatures with the given [`SignatureVerifier`](crate::SignatureVerifier).
+///
+/// This allows swapping the built-in signature verifier used by [`ProdVerifier`]
+/// for another one, eg. a hardware-accelerated one.
+pub type ProvidedVerifier<S> =
+    PredicateVerifier<ProdPredicates, ProvidedVotingPowerCalculator<S>, ProdCommitValidator>;
+
+#[cfg(feature = "rust-crypto")]
+impl<S> ProvidedVerifier<S>
+where
+    S: crate::SignatureVerifier,
+{
+    /// Verifier checking the signatures of commits according to the given mode,
+    /// eg. [`VerificationMode::Exhaustive`] to verify all of them rather than
+    /// stopping once the trust threshold is reached.
+    pub fn with_verification_mode(verification_mode: VerificationMode) -> Self {
+        Self::new(
+            ProdPredicates,
+            ProvidedVotingPowerCalculator::default().with_verification_mode(verification_mode),
+            ProdCommitValidator,
+        )
+    }
 }
 
 #[cfg(feature = "rust-crypto")]
 /// The default production implementation of the [`PredicateVerifier`].
-pub type ProdVerifier =
-    PredicateVerifier<ProdPredicates, ProdVotingPowerCalculator, ProdCommitValidator>;
+pub type ProdVerifier = ProvidedVerifier<tendermint::crypto::default::signature::Verifier>;
+
+/// Verifier which, on top of the checks performed by a [`PredicateVerifier`],
+/// checks the hashes of the sub-structures of a block committed to by its header,
+/// when they are supplied.
+///
+/// This is meant for archival verifiers, which have access to full blocks.
+/// Light clients only need the checks of the [`PredicateVerifier`], which are
+/// cheaper, and is what this verifier performs when no block data is supplied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FullVerifier<P, C, V> {
+    verifier: PredicateVerifier<P, C, V>,
+}
+
+impl<P, C, V> FullVerifier<P, C, V>
+where
+    P: VerificationPredicates,
+    C: VotingPowerCalculator,
+    V: CommitValidator,
+{
+    /// Constructor.
+    pub fn new(verifier: PredicateVerifier<P, C, V>) -> Self {
+        Self { verifier }
+    }
+
+    /// Check that the hashes in the given header match the given block data.
+    pub fn verify_block_data(&self, header: &Header, data: &BlockData<'_>) -> Verdict {
+        verdict!(self.verifier.predicates.block_data_matches(header, data));
+
+        Verdict::Success
+    }
+
+    /// Verify a header received in a `MsgUpdateClient`, as
+    /// [`Verifier::verify_update_header`] does, and then check
+    /// that its hashes match the given block data.
+    pub fn verify_update_header_with_data(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        trusted: TrustedBlockState<'_>,
+        data: &BlockData<'_>,
+        options: &Options,
+        now: Time,
+    ) -> Verdict {
+        let header = &untrusted.signed_header.header;
+        ensure_verdict_success!(self
+            .verifier
+            .verify_update_header(untrusted, trusted, options, now));
+        ensure_verdict_success!(self.verify_block_data(header, data));
+
+        Verdict::Success
+    }
+}
+
+impl<P, C, V> Verifier for FullVerifier<P, C, V>
+where
+    P: VerificationPredicates,
+    C: VotingPowerCalculator,
+    V: CommitValidator,
+{
+    fn verify_update_header(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        trusted: TrustedBlockState<'_>,
+        options: &Options,
+        now: Time,
+    ) -> Verdict {
+        self.verifier
+            .verify_update_header(untrusted, trusted, options, now)
+    }
+
+    fn verify_misbehaviour_header(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        trusted: TrustedBlockState<'_>,
+        options: &Options,
+        now: Time,
+    ) -> Verdict {
+        self.verifier
+            .verify_misbehaviour_header(untrusted, trusted, options, now)
+    }
+
+    fn verify_parent_link(&self, untrusted_header: &Header, trusted_header: &Header) -> Verdict {
+        self.verifier
+            .verify_parent_link(untrusted_header, trusted_header)
+    }
+
+    fn warnings(
+        &self,
+        untrusted: UntrustedBlockState<'_>,
+        options: &Options,
+    ) -> Vec<VerificationWarning> {
+        self.verifier.warnings(untrusted, options)
+    }
+}
+
+#[cfg(feature = "rust-crypto")]
+/// The default production implementation of the [`FullVerifier`].
+pub type ProdFullVerifier =
+    FullVerifier<ProdPredicates, ProdVotingPowerCalculator, ProdCommitValidator>;
 
 #[cfg(test)]
 mod tests {
-    use alloc::{borrow::ToOwned, string::ToString};
+    use alloc::{borrow::ToOwned, string::ToString, vec};
     use core::{ops::Sub, time::Duration};
 
-    use tendermint::Time;
-    use tendermint_testgen::{light_block::LightBlock as TestgenLightBlock, Generator};
+    use tendermint::{
+        abci::types::ExecTxResult, crypto::signature, Hash, PublicKey, Signature, Time,
+    };
+    use tendermint_testgen::{
+        light_block::LightBlock as TestgenLightBlock, Commit, Generator, Header, Validator,
+        ValidatorSet, Vote,
+    };
 
     use crate::{
-        errors::VerificationErrorDetail, options::Options, types::LightBlock, ProdVerifier,
-        Verdict, Verifier,
+        errors::VerificationErrorDetail,
+        options::{Options, DEFAULT_MAX_BISECTION_DEPTH},
+        types::{BlockData, LightBlock, TrustedBlockState, UntrustedBlockState},
+        ProdFullVerifier, ProdVerifier, ProvidedVerifier, SignatureVerifier, Verdict,
+        VerificationWarning, Verifier,
     };
 
     #[allow(dead_code)]
@@ -379,7 +638,11 @@ mod tests {
         let opt = Options {
             trust_threshold: Default::default(),
             trusting_period: Duration::from_secs(60),
-            clock_drift: Default::default(),
+            max_clock_drift: Default::default(),
+            max_block_lag: Default::default(),
+            refresh_margin: None,
+            max_bisection_depth: DEFAULT_MAX_BISECTION_DEPTH,
+            absent_power_warning_threshold: None,
         };
 
         let verdict = vp.verify_update_header(
@@ -399,4 +662,378 @@ mod tests {
             v => panic!("expected ChainIdMismatch error, got: {:?}", v),
         }
     }
+
+    /// Verify a block at height 2 dated `untrusted_age` before now against one at height 1
+    /// dated `trusted_age` before now, with a trusting period of 60 seconds.
+    fn verify_with_ages(
+        trusted_age: Duration,
+        untrusted_age: Duration,
+        max_block_lag: Option<Duration>,
+    ) -> Verdict {
+        let now = Time::now();
+        let light_block = |age, height| -> LightBlock {
+            TestgenLightBlock::new_default_with_time_and_chain_id(
+                "chain-1".to_owned(),
+                now.sub(age).unwrap(),
+                height,
+            )
+            .generate()
+            .unwrap()
+            .into()
+        };
+
+        let trusted = light_block(trusted_age, 1);
+        let untrusted = light_block(untrusted_age, 2);
+
+        let opt = Options {
+            max_block_lag,
+            ..Options::new(Default::default(), Duration::from_secs(60), Duration::ZERO).unwrap()
+        };
+
+        ProdVerifier::default().verify_update_header(
+            untrusted.as_untrusted_state(),
+            trusted.as_trusted_state(),
+            &opt,
+            now,
+        )
+    }
+
+    #[test]
+    fn test_block_lag_does_not_extend_trusting_period() {
+        let just_expired = Duration::from_secs(61);
+        let recent = Duration::from_secs(10);
+
+        for max_block_lag in [
+            None,
+            Some(Duration::from_secs(30)),
+            Some(Duration::from_secs(59)),
+        ] {
+            match verify_with_ages(just_expired, recent, max_block_lag) {
+                Verdict::Invalid(VerificationErrorDetail::NotWithinTrustPeriod(_)) => {},
+                v => panic!("expected NotWithinTrustPeriod error, got: {:?}", v),
+            }
+        }
+    }
+
+    #[test]
+    fn test_block_lag_bounds_untrusted_header_age() {
+        let trusted_age = Duration::from_secs(20);
+        let untrusted_age = Duration::from_secs(10);
+
+        assert_eq!(
+            verify_with_ages(trusted_age, untrusted_age, None),
+            Verdict::Success
+        );
+        assert_eq!(
+            verify_with_ages(trusted_age, untrusted_age, Some(Duration::from_secs(15))),
+            Verdict::Success
+        );
+
+        match verify_with_ages(trusted_age, untrusted_age, Some(Duration::from_secs(5))) {
+            Verdict::Invalid(VerificationErrorDetail::HeaderTooOld(e)) => {
+                assert_eq!(e.max_block_lag, Duration::from_secs(5));
+            },
+            v => panic!("expected HeaderTooOld error, got: {:?}", v),
+        }
+    }
+
+    /// Verify the block at height 2 against the one at height 1, once the
+    /// given function has corrupted the former, or the next validators hash
+    /// of the latter.
+    fn verify_sequential(corrupt: impl FnOnce(&mut LightBlock, &mut Hash)) -> Verdict {
+        verify_sequential_with(&ProdVerifier::default(), corrupt)
+    }
+
+    fn verify_sequential_with(
+        verifier: &impl Verifier,
+        corrupt: impl FnOnce(&mut LightBlock, &mut Hash),
+    ) -> Verdict {
+        let testgen_lb = TestgenLightBlock::new_default(1);
+        let trusted: LightBlock = testgen_lb.generate().unwrap().into();
+        let mut untrusted: LightBlock = testgen_lb.next().generate().unwrap().into();
+
+        let mut trusted_next_validators_hash = trusted.signed_header.header.next_validators_hash;
+        corrupt(&mut untrusted, &mut trusted_next_validators_hash);
+
+        let trusted_state = crate::types::TrustedBlockState {
+            next_validators_hash: trusted_next_validators_hash,
+            ..trusted.as_trusted_state()
+        };
+
+        let opt = Options {
+            trust_threshold: Default::default(),
+            trusting_period: Duration::from_secs(60),
+            max_clock_drift: Default::default(),
+            max_block_lag: Default::default(),
+            refresh_margin: None,
+            max_bisection_depth: DEFAULT_MAX_BISECTION_DEPTH,
+            absent_power_warning_threshold: None,
+        };
+
+        verifier.verify_update_header(
+            untrusted.as_untrusted_state(),
+            trusted_state,
+            &opt,
+            tendermint_testgen::helpers::get_time(3).unwrap(),
+        )
+    }
+
+    /// Signature verifier rejecting all signatures, as a faulty backend would.
+    struct RejectingSignatureVerifier;
+
+    impl SignatureVerifier for RejectingSignatureVerifier {
+        fn verify(_: PublicKey, _: &[u8], _: &Signature) -> Result<(), signature::Error> {
+            Err(signature::Error::VerificationFailed)
+        }
+    }
+
+    #[test]
+    fn test_verification_failure_with_rejecting_signature_verifier() {
+        let verifier = ProvidedVerifier::<RejectingSignatureVerifier>::default();
+
+        match verify_sequential_with(&verifier, |_, _| {}) {
+            Verdict::Invalid(VerificationErrorDetail::InvalidSignature(_)) => {},
+            v => panic!("expected InvalidSignature error, got: {:?}", v),
+        }
+    }
+
+    /// Verifier accepting every header, relying on the default parent link check.
+    struct AcceptingVerifier;
+
+    impl Verifier for AcceptingVerifier {
+        fn verify_update_header(
+            &self,
+            _: UntrustedBlockState<'_>,
+            _: TrustedBlockState<'_>,
+            _: &Options,
+            _: Time,
+        ) -> Verdict {
+            Verdict::Success
+        }
+
+        fn verify_misbehaviour_header(
+            &self,
+            _: UntrustedBlockState<'_>,
+            _: TrustedBlockState<'_>,
+            _: &Options,
+            _: Time,
+        ) -> Verdict {
+            Verdict::Success
+        }
+    }
+
+    #[test]
+    fn test_default_parent_link_verification() {
+        let testgen_lb = TestgenLightBlock::new_default(1);
+        let parent = testgen_lb.generate().unwrap().signed_header.header;
+        let child = testgen_lb.next().generate().unwrap().signed_header.header;
+
+        assert_eq!(
+            AcceptingVerifier.verify_parent_link(&child, &parent),
+            Verdict::Success
+        );
+
+        match AcceptingVerifier.verify_parent_link(&child, &child) {
+            Verdict::Invalid(VerificationErrorDetail::InvalidLastBlockId(e)) => {
+                assert_eq!(e.last_block_id_hash, parent.hash());
+            },
+            v => panic!("expected InvalidLastBlockId error, got: {:?}", v),
+        }
+    }
+
+    fn other_validator_set() -> crate::types::ValidatorSet {
+        ValidatorSet::new(vec!["other"]).generate().unwrap()
+    }
+
+    #[test]
+    fn test_sequential_verification_with_matching_validator_sets() {
+        assert_eq!(verify_sequential(|_, _| {}), Verdict::Success);
+    }
+
+    #[test]
+    fn test_verification_failure_on_validators_hash_mismatch() {
+        let verdict = verify_sequential(|untrusted, _| {
+            untrusted.validators = other_validator_set();
+        });
+        match verdict {
+            Verdict::Invalid(VerificationErrorDetail::InvalidValidatorSet(e)) => {
+                assert_eq!(e.validators_hash, other_validator_set().hash());
+            },
+            v => panic!("expected InvalidValidatorSet error, got: {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_warning_on_high_absent_power() {
+        let validators = ["a", "b", "c", "d", "e"].map(|id| Validator::new(id).voting_power(20));
+        let header = Header::new(&validators)
+            .height(1)
+            .chain_id("test-chain")
+            .time(Time::from_unix_timestamp(1, 0).unwrap());
+
+        // 40% of the voting power is absent
+        let votes = validators[..3]
+            .iter()
+            .map(|validator| Vote::new(validator.clone(), header.clone()))
+            .collect();
+        let mut testgen_lb = TestgenLightBlock::new_default_with_header(header.clone());
+        testgen_lb.commit = Some(Commit::new_with_votes(header, 1, votes));
+        let light_block: LightBlock = testgen_lb.generate().unwrap().into();
+
+        let warnings = |absent_power_warning_threshold| {
+            let options = Options {
+                absent_power_warning_threshold,
+                ..Options::new(
+                    Default::default(),
+                    Duration::from_secs(60),
+                    Default::default(),
+                )
+                .unwrap()
+            };
+            ProdFullVerifier::default().warnings(light_block.as_untrusted_state(), &options)
+        };
+
+        assert_eq!(warnings(None), vec![]);
+        assert_eq!(warnings(Some(40)), vec![]);
+        assert_eq!(
+            warnings(Some(30)),
+            vec![VerificationWarning::HighAbsentPower {
+                height: 1_u32.into(),
+                absent: 40,
+                total: 100,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_verification_failure_on_commit_height_mismatch() {
+        // The commit at height 3 is signed by the same validators as the header at height 2
+        let commit: LightBlock = TestgenLightBlock::new_default(3).generate().unwrap().into();
+        let verdict = verify_sequential(|untrusted, _| {
+            untrusted.signed_header.commit = commit.signed_header.commit.clone();
+        });
+        match verdict {
+            Verdict::Invalid(VerificationErrorDetail::CommitHeightMismatch(e)) => {
+                assert_eq!(e.header_height.value(), 2);
+                assert_eq!(e.commit_height.value(), 3);
+            },
+            v => panic!("expected CommitHeightMismatch error, got: {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_verification_failure_on_next_validators_hash_mismatch() {
+        let verdict = verify_sequential(|untrusted, _| {
+            untrusted.next_validators = other_validator_set();
+        });
+        match verdict {
+            Verdict::Invalid(VerificationErrorDetail::InvalidNextValidatorSet(e)) => {
+                assert_eq!(e.next_validators_hash, other_validator_set().hash());
+            },
+            v => panic!("expected InvalidNextValidatorSet error, got: {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_verification_failure_on_trusted_next_validators_hash_mismatch() {
+        let corrupted = other_validator_set().hash();
+        let verdict = verify_sequential(|_, trusted_next_validators_hash| {
+            *trusted_next_validators_hash = corrupted;
+        });
+        match verdict {
+            Verdict::Invalid(VerificationErrorDetail::NextValidatorSetMismatch(e)) => {
+                assert_eq!(e.trusted_next_validators_hash, corrupted);
+            },
+            v => panic!("expected NextValidatorSetMismatch error, got: {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_block_data_verification() {
+        let txs = vec![b"key=value".to_vec()];
+        let evidence = tendermint::evidence::List::default();
+        let last_results = vec![ExecTxResult::default()];
+
+        let mut header = TestgenLightBlock::new_default(1)
+            .generate()
+            .unwrap()
+            .signed_header
+            .header;
+        header.data_hash = Some(tendermint::tx::data_hash(&txs));
+        header.evidence_hash = Some(evidence.hash());
+        header.last_results_hash = Some(tendermint::abci::types::results_hash(&last_results));
+
+        let verifier = ProdFullVerifier::default();
+        let data = BlockData {
+            txs: Some(&txs),
+            evidence: Some(&evidence),
+            last_results: Some(&last_results),
+            ..Default::default()
+        };
+        assert_eq!(verifier.verify_block_data(&header, &data), Verdict::Success);
+
+        // Nothing is checked when no block data is supplied
+        header.consensus_hash = Hash::None;
+        assert_eq!(
+            verifier.verify_block_data(&header, &BlockData::default()),
+            Verdict::Success
+        );
+
+        let other_txs = vec![b"key=other".to_vec()];
+        let data = BlockData {
+            txs: Some(&other_txs),
+            ..data
+        };
+        match verifier.verify_block_data(&header, &data) {
+            Verdict::Invalid(VerificationErrorDetail::InvalidHeaderFieldHash(e)) => {
+                assert_eq!(e.field, "data_hash");
+                assert_eq!(e.header_hash, tendermint::tx::data_hash(&txs));
+                assert_eq!(e.computed_hash, tendermint::tx::data_hash(&other_txs));
+            },
+            v => panic!("expected InvalidHeaderFieldHash error, got: {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_full_verifier_checks_block_data_after_header() {
+        let testgen_lb = TestgenLightBlock::new_default(1);
+        let trusted: LightBlock = testgen_lb.generate().unwrap().into();
+        let untrusted: LightBlock = testgen_lb.next().generate().unwrap().into();
+
+        let opt = Options {
+            trust_threshold: Default::default(),
+            trusting_period: Duration::from_secs(60),
+            max_clock_drift: Default::default(),
+            max_block_lag: Default::default(),
+            refresh_margin: None,
+            max_bisection_depth: DEFAULT_MAX_BISECTION_DEPTH,
+            absent_power_warning_threshold: None,
+        };
+
+        let verify = |data: &BlockData<'_>| {
+            ProdFullVerifier::default().verify_update_header_with_data(
+                untrusted.as_untrusted_state(),
+                trusted.as_trusted_state(),
+                data,
+                &opt,
+                tendermint_testgen::helpers::get_time(3).unwrap(),
+            )
+        };
+
+        assert_eq!(verify(&BlockData::default()), Verdict::Success);
+
+        // The header of the untrusted block does not commit to any transaction
+        let txs = vec![b"key=value".to_vec()];
+        let data = BlockData {
+            txs: Some(&txs),
+            ..Default::default()
+        };
+        match verify(&data) {
+            Verdict::Invalid(VerificationErrorDetail::InvalidHeaderFieldHash(e)) => {
+                assert_eq!(e.field, "data_hash");
+                assert_eq!(e.header_hash, Hash::None);
+            },
+            v => panic!("expected InvalidHeaderFieldHash error, got: {:?}", v),
+        }
+    }
 }
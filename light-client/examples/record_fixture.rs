@@ -0,0 +1,71 @@
+//! Records the verification of a light block from a live chain as a JSON fixture for
+//! the light client tests, in the format of those under `tests/support/bisection`.
+//!
+//! ```text
+//! cargo run --example record_fixture -- <rpc-address> <trusted-height> <height-to-verify>
+//! ```
+//!
+//! Light blocks are verified as of now, with a trusting period of two weeks and
+//! the default trust threshold of 1/3, and the fixture is written to stdout.
+
+use std::{env, process, time::Duration};
+
+use tendermint_light_client::{
+    components::{
+        clock::{Clock, SystemClock},
+        io::ProdIo,
+    },
+    tests::{record_bisection, TrustOptions},
+    verifier::types::Height,
+};
+use tendermint_rpc::HttpClient;
+use tendermint_testgen::light_block::default_peer_id;
+
+const TRUSTING_PERIOD: Duration = Duration::from_secs(60 * 60 * 24 * 14);
+
+fn parse_height(height: &str) -> Height {
+    height.parse().unwrap_or_else(|e| {
+        eprintln!("invalid height {height}: {e}");
+        process::exit(1)
+    })
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [address, trusted_height, height] = args.as_slice() else {
+        eprintln!("usage: record_fixture <rpc-address> <trusted-height> <height-to-verify>");
+        process::exit(1)
+    };
+
+    let trusted_height = parse_height(trusted_height);
+    let height = parse_height(height);
+
+    let rpc_client = HttpClient::new(address.as_str()).unwrap_or_else(|e| {
+        eprintln!("invalid RPC address {address}: {e}");
+        process::exit(1)
+    });
+
+    // The tests replay fixtures with the default peer of testgen as primary
+    let io = ProdIo::new(default_peer_id(), rpc_client, None);
+
+    let trust_options = TrustOptions {
+        period: tendermint::evidence::Duration(TRUSTING_PERIOD),
+        height: trusted_height,
+        extra_heights: Vec::new(),
+        trust_level: Default::default(),
+    };
+
+    let test = record_bisection(
+        format!("Case: Trusted height={trusted_height}, bisecting to verify height={height}"),
+        io,
+        trust_options,
+        height,
+        SystemClock.now(),
+    )
+    .unwrap_or_else(|e| {
+        eprintln!("could not record verification: {e}");
+        process::exit(1)
+    });
+
+    println!("{}", serde_json::to_string_pretty(&test).unwrap());
+}
@@ -1,6 +1,6 @@
 //! DSL for building light clients and supervisor
 
 mod light_client;
-pub use light_client::LightClientBuilder;
+pub use light_client::{LightClientBuilder, TrustAnchor};
 
 pub mod error;
@@ -3,7 +3,10 @@
 use flex_error::define_error;
 use tendermint::{block::Height, Hash};
 
-use crate::{components::io::IoError, verifier::errors::VerificationError};
+use crate::{
+    components::io::IoError,
+    verifier::errors::{OptionsError, VerificationError},
+};
 
 define_error! {
     Error {
@@ -38,8 +41,21 @@ define_error! {
         NoTrustedStateInStore
             | _ | { "no trusted state in store" },
 
+        NoTrustAnchor
+            | _ | { "no trust anchor given" },
+
         EmptyWitnessList
             | _ | { "empty witness list" },
 
+        InvalidOptions
+            [ OptionsError ]
+            | _ | { "invalid options" },
+
+        MissingComponent
+            { component: String }
+            | e | {
+                format_args!("missing light client component: {0}", e.component)
+            },
+
     }
 }
@@ -1,5 +1,6 @@
 //! DSL for building a light client [`Instance`]
 
+use serde::{Deserialize, Serialize};
 use tendermint::{block::Height, crypto::Sha256, merkle::MerkleHash, Hash};
 
 #[cfg(feature = "rpc-client")]
@@ -31,6 +32,22 @@ use crate::{
     },
 };
 
+/// A known-good checkpoint to bootstrap a light client from, ie. the hash of
+/// the header of the block at the given height.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustAnchor {
+    /// Height of the block
+    pub height: Height,
+    /// Hash of the header of the block
+    pub hash: Hash,
+}
+
+impl From<(Height, Hash)> for TrustAnchor {
+    fn from((height, hash): (Height, Hash)) -> Self {
+        Self { height, hash }
+    }
+}
+
 /// No trusted state has been set yet
 pub struct NoTrustedState;
 
@@ -48,6 +65,7 @@ pub struct LightClientBuilder<State, H: MerkleHash + Sha256 + Default> {
     scheduler: Box<dyn Scheduler>,
     predicates: Box<dyn VerificationPredicates<Sha256 = H>>,
     light_store: Box<dyn LightStore>,
+    trust_anchor: Option<TrustAnchor>,
 
     #[allow(dead_code)]
     state: State,
@@ -68,6 +86,7 @@ where
             scheduler: self.scheduler,
             predicates: self.predicates,
             light_store: self.light_store,
+            trust_anchor: self.trust_anchor,
             state,
         }
     }
@@ -76,6 +95,9 @@ where
 #[cfg(feature = "rpc-client")]
 impl LightClientBuilder<NoTrustedState, tendermint::crypto::default::Sha256> {
     /// Initialize a builder for a production (non-mock) light client.
+    ///
+    /// Fetching a light block from the peer times out after `timeout`,
+    /// or [`DEFAULT_TIMEOUT`](crate::components::io::DEFAULT_TIMEOUT) if none is given.
     pub fn prod(
         peer_id: PeerId,
         rpc_client: rpc::HttpClient,
@@ -123,11 +145,15 @@ where
             scheduler,
             options,
             predicates,
+            trust_anchor: None,
             state: NoTrustedState,
         }
     }
 
     /// Set the given light block as the initial trusted state.
+    ///
+    /// Fails if the light block cannot be trusted, or if the options are invalid
+    /// (see [`Options::validate`]).
     pub fn trust_light_block(
         mut self,
         trusted_state: LightBlock,
@@ -155,11 +181,59 @@ where
         trusted_height: Height,
         trusted_hash: Hash,
     ) -> Result<LightClientBuilder<HasTrustedState, H>, Error> {
+        let anchor = TrustAnchor::from((trusted_height, trusted_hash));
         let trusted_state = self
             .io
             .fetch_light_block(AtHeight::At(trusted_height))
             .map_err(Error::io)?;
 
+        self.trust_anchor_block(anchor, trusted_state)
+    }
+
+    /// Set the block from the primary peer at the height of the newest of the given
+    /// trust anchors as the trusted state, falling back to the next newest one if
+    /// the block cannot be fetched, and so on.
+    ///
+    /// The block of each anchor must match its hash and be within the trusting period,
+    /// otherwise this fails right away: the blocks of the older anchors would not be
+    /// within the trusting period either, or the primary peer would not be trustworthy.
+    ///
+    /// The chosen anchor is recorded in the [`Instance`], see [`Instance::trust_anchor`].
+    pub fn trust_primary_at_any(
+        self,
+        anchors: impl IntoIterator<Item = (Height, Hash)>,
+    ) -> Result<LightClientBuilder<HasTrustedState, H>, Error> {
+        let mut anchors = anchors
+            .into_iter()
+            .map(TrustAnchor::from)
+            .collect::<Vec<_>>();
+        anchors.sort_by_key(|anchor| core::cmp::Reverse(anchor.height));
+
+        let mut last_error = None;
+        for anchor in anchors {
+            match self.io.fetch_light_block(AtHeight::At(anchor.height)) {
+                Ok(trusted_state) => return self.trust_anchor_block(anchor, trusted_state),
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        Err(match last_error {
+            Some(e) => Error::io(e),
+            None => Error::no_trust_anchor(),
+        })
+    }
+
+    /// Set the given light block as the trusted state, if it matches the given anchor.
+    fn trust_anchor_block(
+        mut self,
+        anchor: TrustAnchor,
+        trusted_state: LightBlock,
+    ) -> Result<LightClientBuilder<HasTrustedState, H>, Error> {
+        let TrustAnchor {
+            height: trusted_height,
+            hash: trusted_hash,
+        } = anchor;
+
         if trusted_state.height() != trusted_height {
             return Err(Error::height_mismatch(
                 trusted_height,
@@ -173,10 +247,14 @@ where
             return Err(Error::hash_mismatch(trusted_hash, header_hash));
         }
 
+        self.trust_anchor = Some(anchor);
         self.trust_light_block(trusted_state)
     }
 
     fn validate(&self, light_block: &LightBlock) -> Result<(), Error> {
+        // Checked here rather than when building the light client, which cannot fail
+        self.options.validate().map_err(Error::invalid_options)?;
+
         let header = &light_block.signed_header.header;
         let now = self.clock.now();
 
@@ -185,7 +263,7 @@ where
             .map_err(Error::invalid_light_block)?;
 
         self.predicates
-            .is_header_from_past(header.time, self.options.clock_drift, now)
+            .is_header_from_past(header.time, self.options.max_clock_drift, now)
             .map_err(Error::invalid_light_block)?;
 
         self.predicates
@@ -216,6 +294,7 @@ where
         let state = State {
             light_store: self.light_store,
             verification_trace: VerificationTrace::new(),
+            bisection_trace: Vec::new(),
         };
 
         let light_client = LightClient::from_boxed(
@@ -225,8 +304,12 @@ where
             self.scheduler,
             self.verifier,
             self.io,
-        );
+        )
+        .expect("options are validated along with the trusted state");
 
-        Instance::new(light_client, state)
+        Instance {
+            trust_anchor: self.trust_anchor,
+            ..Instance::new(light_client, state)
+        }
     }
 }
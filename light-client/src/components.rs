@@ -1,8 +1,10 @@
 //! Components used by the Light Client.
 
 pub mod clock;
+pub mod fork_detector;
 pub mod io;
 pub mod scheduler;
+pub mod telemetry;
 
 // Re-export for backward compatibility
 pub use tendermint_light_client_verifier as verifier;
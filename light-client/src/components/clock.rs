@@ -1,10 +1,23 @@
 //! Provides an interface and a default implementation of the `Clock` component
 
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
 use time::OffsetDateTime;
 
 use crate::verifier::types::Time;
 
 /// Abstracts over the current time.
+///
+/// The light client reads the current time from its clock at each verification
+/// step, so that a long-running client checks the trusting period and clock drift
+/// against the live time rather than the time at which verification started.
+///
+/// Clocks are `Send + Sync`, so that light clients sharing the same clock, eg. clones
+/// of a [`FixedClock`], can be driven from different threads, as by the
+/// [`Supervisor`](crate::supervisor::Supervisor).
 pub trait Clock: Send + Sync {
     /// Get the current time.
     fn now(&self) -> Time;
@@ -21,19 +34,61 @@ impl Clock for SystemClock {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+/// A clock which does not move on its own, but can be set or advanced at will,
+/// eg. to deterministically test time-dependent logic.
+///
+/// Clones of a `FixedClock` share the same time, so that one can keep a handle
+/// on the clock given to a [`LightClient`](crate::light_client::LightClient).
+#[derive(Clone, Debug)]
 pub struct FixedClock {
-    now: Time,
+    now: Arc<Mutex<Time>>,
 }
 
 impl FixedClock {
+    /// A clock fixed at the given time.
     pub fn new(now: Time) -> Self {
-        Self { now }
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Set the clock to the given time.
+    pub fn set(&self, now: Time) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// Move the clock forward by the given duration.
+    ///
+    /// ## Panics
+    /// If the time of the clock overflows.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now = now.checked_add(duration).expect("clock overflow");
     }
 }
 
 impl Clock for FixedClock {
     fn now(&self) -> Time {
-        self.now
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_clock_is_shared_by_clones() {
+        let start = Time::from_unix_timestamp(1, 0).unwrap();
+        let clock = FixedClock::new(start);
+        let shared = clock.clone();
+
+        assert_eq!(shared.now(), start);
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(shared.now(), Time::from_unix_timestamp(11, 0).unwrap());
+
+        shared.set(start);
+        assert_eq!(clock.now(), start);
     }
 }
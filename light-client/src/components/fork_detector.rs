@@ -0,0 +1,510 @@
+//! Provides an interface and a default implementation of the `ForkDetector` component
+
+use core::marker::PhantomData;
+
+use tendermint::{crypto::Sha256, evidence::LightClientAttackEvidence, merkle::MerkleHash};
+
+use crate::{
+    components::io::{AtHeight, Io, IoError},
+    evidence::make_evidence,
+    verifier::types::{LightBlock, PeerId},
+};
+
+/// Result of fork detection
+#[derive(Debug)]
+pub enum ForkDetection {
+    /// One or more forks have been detected
+    Detected(Vec<Fork>),
+    /// No fork has been detected
+    NotDetected,
+}
+
+/// Types of fork
+#[derive(Debug)]
+pub enum Fork {
+    /// The witness provided a light block which conflicts with the one
+    /// verified from the primary.
+    Forked {
+        /// The peer which provided the conflicting light block
+        peer: PeerId,
+        /// The light block verified from the primary
+        primary: Box<LightBlock>,
+        /// The conflicting light block provided by the witness
+        witness: Box<LightBlock>,
+    },
+    /// The witness could not provide a light block at the requested height.
+    Faulty {
+        /// The peer which failed to provide a light block
+        peer: PeerId,
+        /// The error returned when fetching from the witness
+        error: IoError,
+    },
+}
+
+impl Fork {
+    /// The peer responsible for this fork.
+    pub fn peer(&self) -> PeerId {
+        match self {
+            Self::Forked { peer, .. } | Self::Faulty { peer, .. } => *peer,
+        }
+    }
+
+    /// Form the evidence of the attack on the light client, to be reported
+    /// to the primary, given the last block both peers agreed upon.
+    ///
+    /// Returns `None` if this is not a [`Fork::Forked`], ie. there are no
+    /// conflicting light blocks to build evidence from.
+    pub fn make_evidence(&self, common: &LightBlock) -> Option<LightClientAttackEvidence> {
+        match self {
+            Self::Forked {
+                primary, witness, ..
+            } => Some(make_evidence(
+                witness.as_ref().clone(),
+                primary.as_ref().clone(),
+                common.clone(),
+            )),
+            Self::Faulty { .. } => None,
+        }
+    }
+}
+
+/// Number of witnesses which must provide the same light block as the primary
+/// for that light block to be trusted.
+///
+/// At least one witness is always required, whatever the policy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WitnessQuorum {
+    /// All the witnesses in the pool must agree with the primary.
+    AllMustAgree,
+    /// More than half of the witnesses in the pool must agree with the primary.
+    Majority,
+    /// At least the given number of witnesses must agree with the primary.
+    AtLeast(usize),
+}
+
+impl Default for WitnessQuorum {
+    /// A single witness agreeing with the primary is enough.
+    fn default() -> Self {
+        Self::AtLeast(1)
+    }
+}
+
+impl WitnessQuorum {
+    /// The number of witnesses required to reach this quorum, out of a pool of
+    /// the given size.
+    pub fn required(self, witnesses: usize) -> usize {
+        let required = match self {
+            Self::AllMustAgree => witnesses,
+            Self::Majority => witnesses / 2 + 1,
+            Self::AtLeast(n) => n,
+        };
+
+        required.max(1)
+    }
+
+    /// Classify the responses of the witnesses to a light block verified from the primary.
+    ///
+    /// The primary is deemed to have lied if a quorum of witnesses provided a conflicting
+    /// light block which passed verification. Otherwise, the light block of the primary
+    /// is accepted if a quorum of witnesses agreed with it, any witness which provided
+    /// a conflicting light block being deemed to have lied.
+    pub fn classify(self, responses: &WitnessResponses) -> QuorumOutcome {
+        let required = self.required(responses.len());
+
+        if responses.conflicting.len() >= required {
+            return QuorumOutcome::PrimaryLied {
+                witnesses: responses.conflicting.clone(),
+            };
+        }
+
+        if responses.agreeing.len() < required {
+            return QuorumOutcome::InsufficientWitnesses {
+                agreeing: responses.agreeing.len(),
+                required,
+            };
+        }
+
+        let lying: Vec<PeerId> = responses
+            .conflicting
+            .iter()
+            .chain(&responses.invalid)
+            .copied()
+            .collect();
+
+        if lying.is_empty() {
+            QuorumOutcome::Confirmed
+        } else {
+            QuorumOutcome::WitnessesLied { witnesses: lying }
+        }
+    }
+}
+
+/// Responses of the witnesses to a light block verified from the primary.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WitnessResponses {
+    /// Witnesses which provided the same light block as the primary.
+    pub agreeing: Vec<PeerId>,
+    /// Witnesses which provided a conflicting light block which passed verification.
+    pub conflicting: Vec<PeerId>,
+    /// Witnesses which provided a conflicting light block which failed verification.
+    pub invalid: Vec<PeerId>,
+    /// Witnesses which could not provide a light block.
+    pub unresponsive: Vec<PeerId>,
+}
+
+impl WitnessResponses {
+    /// The number of witnesses, whether they responded or not.
+    pub fn len(&self) -> usize {
+        self.agreeing.len() + self.conflicting.len() + self.invalid.len() + self.unresponsive.len()
+    }
+
+    /// Whether there are no witnesses at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Outcome of cross-checking a light block verified from the primary against
+/// the witnesses, under a [`WitnessQuorum`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QuorumOutcome {
+    /// A quorum of witnesses agreed with the primary, and none provided a conflicting light block.
+    Confirmed,
+    /// A quorum of witnesses agreed with the primary, but the given witnesses provided
+    /// a conflicting light block.
+    WitnessesLied {
+        /// The witnesses which provided a conflicting light block
+        witnesses: Vec<PeerId>,
+    },
+    /// A quorum of witnesses provided a conflicting light block which passed verification.
+    PrimaryLied {
+        /// The witnesses which provided a conflicting light block
+        witnesses: Vec<PeerId>,
+    },
+    /// Not enough witnesses agreed with the primary, nor against it, to reach the quorum.
+    InsufficientWitnesses {
+        /// The number of witnesses which agreed with the primary
+        agreeing: usize,
+        /// The number of witnesses required to reach the quorum
+        required: usize,
+    },
+}
+
+/// Interface for a fork detector
+pub trait ForkDetector: Send + Sync {
+    /// Detect forks by comparing the given light block, verified from the primary,
+    /// against the light blocks provided by the witnesses at the same height.
+    fn detect_forks(
+        &self,
+        verified_block: &LightBlock,
+        witnesses: &[(PeerId, &dyn Io)],
+    ) -> ForkDetection;
+}
+
+/// A fork detector which fetches the light block at the height of the
+/// verified block from each witness, and compares the header hashes.
+///
+/// If the hashes do not match, the witness light block is reported as
+/// a [`Fork::Forked`] alongside the primary light block. If a witness
+/// fails to provide a light block, it is reported as [`Fork::Faulty`].
+///
+/// Hashing is done with the Merkle hasher `H`.
+#[derive(Debug)]
+pub struct ProvidedForkDetector<H> {
+    _hasher: PhantomData<fn() -> H>,
+}
+
+impl<H> Default for ProvidedForkDetector<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H> ProvidedForkDetector<H> {
+    /// Construct a new fork detector.
+    pub fn new() -> Self {
+        Self {
+            _hasher: PhantomData,
+        }
+    }
+}
+
+/// A fork detector which uses the default hasher.
+#[cfg(feature = "rust-crypto")]
+pub type ProdForkDetector = ProvidedForkDetector<tendermint::crypto::default::Sha256>;
+
+impl<H> ForkDetector for ProvidedForkDetector<H>
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    fn detect_forks(
+        &self,
+        verified_block: &LightBlock,
+        witnesses: &[(PeerId, &dyn Io)],
+    ) -> ForkDetection {
+        let primary_hash = verified_block.signed_header.header.hash_with::<H>();
+        let height = AtHeight::At(verified_block.height());
+
+        let forks: Vec<Fork> = witnesses
+            .iter()
+            .filter_map(|(peer, io)| match io.fetch_light_block(height) {
+                // The very same block, merely provided by another peer
+                Ok(witness_block) if witness_block.same_content(verified_block) => None,
+                Ok(witness_block) => {
+                    let witness_hash = witness_block.signed_header.header.hash_with::<H>();
+
+                    (primary_hash != witness_hash).then(|| Fork::Forked {
+                        peer: *peer,
+                        primary: Box::new(verified_block.clone()),
+                        witness: Box::new(witness_block),
+                    })
+                },
+                Err(error) => Some(Fork::Faulty { peer: *peer, error }),
+            })
+            .collect();
+
+        if forks.is_empty() {
+            ForkDetection::NotDetected
+        } else {
+            ForkDetection::Detected(forks)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rust-crypto"))]
+mod tests {
+    use tendermint_testgen::{light_block::LightBlock as TestgenLightBlock, Generator};
+
+    use super::*;
+    use crate::{tests::MockIo, verifier::types::Time};
+
+    fn light_block(chain_id: &str, height: u64) -> LightBlock {
+        let time = Time::from_unix_timestamp(height as i64, 0).unwrap();
+        let tm_lb = TestgenLightBlock::new_default_with_time_and_chain_id(
+            chain_id.to_string(),
+            time,
+            height,
+        )
+        .generate()
+        .unwrap();
+
+        LightBlock::new(
+            tm_lb.signed_header,
+            tm_lb.validators,
+            tm_lb.next_validators,
+            tm_lb.provider,
+        )
+    }
+
+    fn peer_id(byte: u8) -> PeerId {
+        PeerId::new([byte; 20])
+    }
+
+    #[test]
+    fn no_fork_when_witnesses_agree() {
+        let primary = light_block("test-chain", 3);
+        let witness = MockIo::new(vec![light_block("test-chain", 3)]);
+
+        let detector = ProdForkDetector::default();
+        let result = detector.detect_forks(&primary, &[(peer_id(1), &witness)]);
+
+        assert!(matches!(result, ForkDetection::NotDetected));
+    }
+
+    #[test]
+    fn detects_conflicting_and_faulty_witnesses() {
+        let primary = light_block("test-chain", 3);
+        let honest = MockIo::new(vec![light_block("test-chain", 3)]);
+        let forked = MockIo::new(vec![light_block("other-chain", 3)]);
+        let faulty = MockIo::new(vec![light_block("test-chain", 2)]);
+
+        let detector = ProdForkDetector::default();
+        let result = detector.detect_forks(
+            &primary,
+            &[
+                (peer_id(1), &honest),
+                (peer_id(2), &forked),
+                (peer_id(3), &faulty),
+            ],
+        );
+
+        let forks = match result {
+            ForkDetection::Detected(forks) => forks,
+            ForkDetection::NotDetected => panic!("expected forks to be detected"),
+        };
+
+        assert_eq!(forks.len(), 2);
+
+        match &forks[0] {
+            Fork::Forked {
+                peer,
+                primary: primary_block,
+                witness,
+            } => {
+                assert_eq!(*peer, peer_id(2));
+                assert_eq!(primary_block.as_ref(), &primary);
+                assert_eq!(
+                    witness.signed_header.header.chain_id.as_str(),
+                    "other-chain"
+                );
+            },
+            fork => panic!("unexpected fork: {fork:?}"),
+        }
+
+        assert!(matches!(&forks[1], Fork::Faulty { .. }));
+        assert_eq!(forks[1].peer(), peer_id(3));
+        assert!(forks[1].make_evidence(&primary).is_none());
+    }
+
+    #[test]
+    fn forked_makes_evidence_against_witness_block() {
+        let common = light_block("test-chain", 1);
+        let primary = light_block("test-chain", 3);
+        let witness = light_block("other-chain", 3);
+
+        let fork = Fork::Forked {
+            peer: peer_id(2),
+            primary: Box::new(primary),
+            witness: Box::new(witness.clone()),
+        };
+
+        let evidence = fork.make_evidence(&common).unwrap();
+
+        assert_eq!(
+            evidence.conflicting_block.signed_header,
+            witness.signed_header
+        );
+        assert_eq!(evidence.conflicting_block.validator_set, witness.validators);
+    }
+
+    /// Responses from distinct witnesses, in the given numbers.
+    fn responses(agreeing: u8, conflicting: u8, invalid: u8, unresponsive: u8) -> WitnessResponses {
+        let mut peers = (1..).map(peer_id);
+        let mut take = |n: u8| peers.by_ref().take(n.into()).collect();
+
+        WitnessResponses {
+            agreeing: take(agreeing),
+            conflicting: take(conflicting),
+            invalid: take(invalid),
+            unresponsive: take(unresponsive),
+        }
+    }
+
+    #[test]
+    fn quorum_required_witnesses() {
+        assert_eq!(WitnessQuorum::AllMustAgree.required(3), 3);
+        assert_eq!(WitnessQuorum::Majority.required(3), 2);
+        assert_eq!(WitnessQuorum::Majority.required(4), 3);
+        assert_eq!(WitnessQuorum::AtLeast(2).required(3), 2);
+
+        // At least one witness is always required
+        assert_eq!(WitnessQuorum::AllMustAgree.required(0), 1);
+        assert_eq!(WitnessQuorum::AtLeast(0).required(3), 1);
+        assert_eq!(WitnessQuorum::default(), WitnessQuorum::AtLeast(1));
+    }
+
+    #[test]
+    fn all_must_agree_quorum() {
+        let quorum = WitnessQuorum::AllMustAgree;
+
+        assert_eq!(
+            quorum.classify(&responses(3, 0, 0, 0)),
+            QuorumOutcome::Confirmed
+        );
+        assert_eq!(
+            quorum.classify(&responses(2, 1, 0, 0)),
+            QuorumOutcome::InsufficientWitnesses {
+                agreeing: 2,
+                required: 3
+            }
+        );
+        assert_eq!(
+            quorum.classify(&responses(2, 0, 0, 1)),
+            QuorumOutcome::InsufficientWitnesses {
+                agreeing: 2,
+                required: 3
+            }
+        );
+        assert_eq!(
+            quorum.classify(&responses(0, 3, 0, 0)),
+            QuorumOutcome::PrimaryLied {
+                witnesses: vec![peer_id(1), peer_id(2), peer_id(3)]
+            }
+        );
+    }
+
+    #[test]
+    fn majority_quorum() {
+        let quorum = WitnessQuorum::Majority;
+
+        assert_eq!(
+            quorum.classify(&responses(2, 1, 0, 0)),
+            QuorumOutcome::WitnessesLied {
+                witnesses: vec![peer_id(3)]
+            }
+        );
+        assert_eq!(
+            quorum.classify(&responses(3, 1, 1, 0)),
+            QuorumOutcome::WitnessesLied {
+                witnesses: vec![peer_id(4), peer_id(5)]
+            }
+        );
+        assert_eq!(
+            quorum.classify(&responses(1, 2, 0, 0)),
+            QuorumOutcome::PrimaryLied {
+                witnesses: vec![peer_id(2), peer_id(3)]
+            }
+        );
+        assert_eq!(
+            quorum.classify(&responses(1, 1, 0, 1)),
+            QuorumOutcome::InsufficientWitnesses {
+                agreeing: 1,
+                required: 2
+            }
+        );
+        assert_eq!(
+            quorum.classify(&responses(1, 0, 2, 0)),
+            QuorumOutcome::InsufficientWitnesses {
+                agreeing: 1,
+                required: 2
+            }
+        );
+    }
+
+    #[test]
+    fn at_least_quorum() {
+        let quorum = WitnessQuorum::AtLeast(2);
+
+        assert_eq!(
+            quorum.classify(&responses(2, 0, 0, 3)),
+            QuorumOutcome::Confirmed
+        );
+        assert_eq!(
+            quorum.classify(&responses(2, 1, 0, 0)),
+            QuorumOutcome::WitnessesLied {
+                witnesses: vec![peer_id(3)]
+            }
+        );
+        assert_eq!(
+            quorum.classify(&responses(1, 0, 0, 0)),
+            QuorumOutcome::InsufficientWitnesses {
+                agreeing: 1,
+                required: 2
+            }
+        );
+
+        // The primary lying takes precedence over witnesses agreeing with it
+        assert_eq!(
+            quorum.classify(&responses(3, 2, 0, 0)),
+            QuorumOutcome::PrimaryLied {
+                witnesses: vec![peer_id(4), peer_id(5)]
+            }
+        );
+        assert_eq!(
+            WitnessQuorum::default().classify(&responses(1, 1, 0, 0)),
+            QuorumOutcome::PrimaryLied {
+                witnesses: vec![peer_id(2)]
+            }
+        );
+    }
+}
@@ -1,13 +1,20 @@
 //! Provides an interface and a default implementation of the `Io` component
 
-use std::time::Duration;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+    time::Duration,
+};
 
+use async_trait::async_trait;
 use flex_error::{define_error, TraceError};
 use tendermint_rpc as rpc;
 #[cfg(feature = "rpc-client")]
 use tendermint_rpc::Client;
 
-use crate::verifier::types::{Height, LightBlock};
+use tendermint::{evidence::Evidence, Hash};
+
+use crate::verifier::types::{Height, LightBlock, PeerId};
 
 #[cfg(feature = "tokio")]
 type TimeoutError = flex_error::DisplayOnly<tokio::time::error::Elapsed>;
@@ -16,6 +23,7 @@ type TimeoutError = flex_error::DisplayOnly<tokio::time::error::Elapsed>;
 type TimeoutError = flex_error::NoSource;
 
 /// Type for selecting either a specific height or the latest one
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum AtHeight {
     /// A specific height
     At(Height),
@@ -33,6 +41,23 @@ impl From<Height> for AtHeight {
     }
 }
 
+/// Which parts of a light block to fetch from a full node.
+///
+/// See the documentation of each [`Scheduler`](crate::components::scheduler::Scheduler)
+/// for the mode it requires.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FetchMode {
+    /// The signed header and the validator set at its height, leaving out the validator
+    /// set at the next height, which is only needed to skip from the block to a higher one.
+    ///
+    /// The light block then has an empty next validator set, see
+    /// [`LightBlock::has_next_validators`].
+    HeaderAndVals,
+    /// The signed header along with the validator sets at its height and the next one.
+    #[default]
+    Full,
+}
+
 define_error! {
     #[derive(Debug)]
     IoError {
@@ -40,6 +65,21 @@ define_error! {
             [ rpc::Error ]
             | _ | { "rpc error" },
 
+        Transport
+            [ rpc::Error ]
+            | _ | { "failed to reach the node" },
+
+        InvalidResponse
+            [ rpc::Error ]
+            | _ | { "node returned an invalid response" },
+
+        HeightNotFound
+            { height: Height }
+            | e | {
+                format_args!("no light block available at height {}",
+                    e.height)
+            },
+
         InvalidHeight
             | _ | {
                 "invalid height: given height must be greater than 0"
@@ -59,6 +99,17 @@ define_error! {
             [ tendermint::Error ]
             | _ | { "fetched validator set is invalid" },
 
+        TooManyValidators
+            {
+                height: Height,
+                total: i32,
+                max_validators: usize,
+            }
+            | e | {
+                format_args!("node reported {} validators at height {}, more than the maximum of {}",
+                    e.total, e.height, e.max_validators)
+            },
+
         Timeout
             { duration: Duration }
             [ TimeoutError ]
@@ -71,12 +122,54 @@ define_error! {
             [ TraceError<std::io::Error> ]
             | _ | { "failed to initialize runtime" },
 
+        ExpiredEvidence
+            { height: Height }
+            | e | {
+                format_args!("evidence of misbehavior at height {} has expired",
+                    e.height)
+            },
+
+        UnknownPeer
+            { peer_id: PeerId }
+            | e | {
+                format_args!("no RPC client registered for peer {}",
+                    e.peer_id)
+            },
+
     }
 }
 
 impl IoError {
+    /// Classify an error of the RPC client, telling apart nodes which could not be reached,
+    /// nodes which returned an invalid response, and heights which are not available.
     pub fn from_rpc(err: rpc::Error) -> Self {
-        Self::from_height_too_high(&err).unwrap_or_else(|| Self::rpc(err))
+        if let Some(e) =
+            Self::from_height_too_high(&err).or_else(|| Self::from_height_not_found(&err))
+        {
+            return e;
+        }
+
+        match err.detail() {
+            e if is_transport_error(e) => Self::transport(err),
+            rpc::error::ErrorDetail::Parse(_)
+            | rpc::error::ErrorDetail::Serde(_)
+            | rpc::error::ErrorDetail::MalformedJson(_) => Self::invalid_response(err),
+            _ => Self::rpc(err),
+        }
+    }
+
+    /// The error returned by nodes which do not have the requested height anymore,
+    /// eg. after pruning it.
+    pub fn from_height_not_found(err: &rpc::Error) -> Option<Self> {
+        use regex::Regex;
+
+        let err_str = err.to_string();
+
+        let re = Regex::new(r"height (\d+) is not available").ok()?;
+        let captures = re.captures(&err_str)?;
+        let height = Height::try_from(captures[1].parse::<i64>().ok()?).ok()?;
+
+        Some(Self::height_not_found(height))
     }
 
     pub fn from_height_too_high(err: &rpc::Error) -> Option<Self> {
@@ -109,8 +202,53 @@ impl IoErrorDetail {
             _ => None,
         }
     }
+
+    /// Whether this error is plausibly transient, ie. whether the same request
+    /// might succeed if retried later, such as on timeouts or connection failures.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Timeout(_) | Self::Transport(_) => true,
+            Self::Rpc(e) => is_transport_error(&e.source),
+            _ => false,
+        }
+    }
+
+    /// Whether this error means that the node provided data which does not make sense,
+    /// as opposed to failing to provide any.
+    pub fn is_invalid_response(&self) -> bool {
+        matches!(
+            self,
+            Self::InvalidResponse(_) | Self::InvalidValidatorSet(_) | Self::TooManyValidators(_)
+        )
+    }
+}
+
+impl From<rpc::Error> for IoError {
+    fn from(err: rpc::Error) -> Self {
+        Self::from_rpc(err)
+    }
+}
+
+/// Whether the RPC client failed to reach the node or to get a response from it.
+fn is_transport_error(err: &rpc::error::ErrorDetail) -> bool {
+    matches!(
+        err,
+        rpc::error::ErrorDetail::Io(_)
+            | rpc::error::ErrorDetail::Http(_)
+            | rpc::error::ErrorDetail::WebSocket(_)
+            | rpc::error::ErrorDetail::WebSocketTimeout(_)
+            | rpc::error::ErrorDetail::Timeout(_)
+    )
 }
 
+/// Default timeout for fetching a single light block from a full node,
+/// covering all the RPC requests this involves.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default maximum number of validators in a validator set fetched from a full node,
+/// matching the `MaxVotesCount` limit of Tendermint.
+pub const DEFAULT_MAX_VALIDATORS: usize = 10_000;
+
 /// Interface for fetching light blocks from a full node, typically via the RPC client.
 pub trait Io: Send + Sync {
     /// Fetch a light block at the given height from a peer
@@ -126,19 +264,290 @@ where
     }
 }
 
+/// Interface for fetching light blocks from a full node asynchronously,
+/// typically via the RPC client.
+///
+/// This is the asynchronous counterpart of [`Io`], to be used with eg.
+/// [`LightClient::verify_to_target_async`](crate::light_client::LightClient::verify_to_target_async).
+#[async_trait]
+pub trait AsyncIo: Send + Sync {
+    /// Fetch a light block at the given height from a peer
+    async fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError>;
+}
+
+/// Interface for reporting evidence of misbehavior to full nodes,
+/// typically via the RPC client.
+pub trait EvidenceReporter: Send + Sync {
+    /// Report the given evidence to the given peer, returning the hash of the evidence
+    fn report_evidence(&self, peer: PeerId, evidence: Evidence) -> Result<Hash, IoError>;
+}
+
+/// Fetch a light block at the given height from each of the given peers, concurrently.
+///
+/// Each request is subject to its own `timeout`, so that a single unresponsive
+/// peer cannot stall the whole batch. Failures are reported per peer rather than
+/// failing the whole call, ie. the returned map contains an entry for every peer.
+#[cfg(feature = "tokio")]
+pub async fn fetch_from_all(
+    peers: &[(PeerId, &dyn AsyncIo)],
+    height: AtHeight,
+    timeout: Option<Duration>,
+) -> HashMap<PeerId, Result<LightBlock, IoError>> {
+    let requests = peers.iter().map(|(peer_id, io)| async move {
+        let result = crate::utils::timeout(timeout, io.fetch_light_block(height))
+            .await
+            .and_then(|result| result);
+
+        (*peer_id, result)
+    });
+
+    futures::future::join_all(requests)
+        .await
+        .into_iter()
+        .collect()
+}
+
+/// Policy governing how [`RetryingIo`] retries failed fetches.
+///
+/// The delay before the `n`-th retry is `base_delay * 2^(n - 1)`. No more than
+/// `max_retries` retries are attempted, and retrying stops early if waiting for
+/// the next retry would bring the total delay above `max_total_delay`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Maximum number of retries
+    pub max_retries: u32,
+    /// Maximum delay accumulated over all retries
+    pub max_total_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(100),
+            max_retries: 3,
+            max_total_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The delay to wait for before the given retry, starting at 1,
+    /// or `None` if the retry should not be attempted given that
+    /// `elapsed` has already been spent waiting for previous retries.
+    fn delay(&self, retry: u32, elapsed: Duration) -> Option<Duration> {
+        if retry == 0 || retry > self.max_retries {
+            return None;
+        }
+
+        let delay = self.base_delay.checked_mul(2_u32.checked_pow(retry - 1)?)?;
+
+        if elapsed.checked_add(delay)? > self.max_total_delay {
+            return None;
+        }
+
+        Some(delay)
+    }
+}
+
+/// Wraps an [`Io`] component, retrying fetches which failed with a
+/// [transient](IoErrorDetail::is_transient) error according to a [`RetryPolicy`].
+///
+/// Other errors, such as a requested height not being available, are returned immediately.
+#[derive(Clone, Debug)]
+pub struct RetryingIo<I> {
+    inner: I,
+    policy: RetryPolicy,
+}
+
+impl<I: Io> RetryingIo<I> {
+    /// Wrap the given `Io` component, retrying with the given policy.
+    pub fn new(inner: I, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    /// The wrapped `Io` component.
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    /// The retry policy.
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+}
+
+impl<I: Io> Io for RetryingIo<I> {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        let mut retry = 0;
+        let mut elapsed = Duration::ZERO;
+
+        loop {
+            let error = match self.inner.fetch_light_block(height) {
+                Ok(light_block) => return Ok(light_block),
+                Err(e) if e.detail().is_transient() => e,
+                Err(e) => return Err(e),
+            };
+
+            retry += 1;
+
+            let delay = match self.policy.delay(retry, elapsed) {
+                Some(delay) => delay,
+                None => return Err(error),
+            };
+
+            std::thread::sleep(delay);
+            elapsed += delay;
+        }
+    }
+}
+
+/// Wraps an [`Io`] component, memoizing the light blocks it fetches from
+/// a given peer, keyed by peer and height.
+///
+/// The cache holds at most `capacity` light blocks, evicting the least recently used
+/// one when full. Light blocks fetched at [`AtHeight::Highest`] are never served from
+/// the cache, since the latest height keeps changing, but they are cached at their
+/// actual height. Errors are never cached.
+#[derive(Debug)]
+pub struct CachingIo<I> {
+    peer: PeerId,
+    inner: I,
+    capacity: usize,
+    cache: Mutex<LruCache>,
+}
+
+/// Light blocks by peer and height, along with the generation at which they were last used.
+///
+/// Generations only ever increase, so that the least recently used light block is
+/// the one with the lowest generation, which is found in logarithmic time.
+#[derive(Debug, Default)]
+struct LruCache {
+    light_blocks: HashMap<(PeerId, Height), (u64, LightBlock)>,
+    recency: BTreeMap<u64, (PeerId, Height)>,
+    generation: u64,
+}
+
+impl LruCache {
+    fn get(&mut self, key: &(PeerId, Height)) -> Option<LightBlock> {
+        let generation = self.next_generation();
+        let (last_used, light_block) = self.light_blocks.get_mut(key)?;
+
+        self.recency.remove(last_used);
+        self.recency.insert(generation, *key);
+        *last_used = generation;
+
+        Some(light_block.clone())
+    }
+
+    fn insert(&mut self, key: (PeerId, Height), light_block: LightBlock, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+
+        let generation = self.next_generation();
+        if let Some((last_used, _)) = self.light_blocks.insert(key, (generation, light_block)) {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(generation, key);
+
+        if self.recency.len() > capacity {
+            if let Some((_, evicted)) = self.recency.pop_first() {
+                self.light_blocks.remove(&evicted);
+            }
+        }
+    }
+
+    fn next_generation(&mut self) -> u64 {
+        self.generation += 1;
+        self.generation
+    }
+}
+
+impl<I: Io> CachingIo<I> {
+    /// Wrap the given `Io` component fetching from the given peer,
+    /// caching up to `capacity` light blocks.
+    pub fn new(peer: PeerId, inner: I, capacity: usize) -> Self {
+        Self {
+            peer,
+            inner,
+            capacity,
+            cache: Mutex::new(LruCache::default()),
+        }
+    }
+
+    /// The wrapped `Io` component.
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    /// The maximum number of light blocks held in the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of light blocks currently held in the cache.
+    pub fn len(&self) -> usize {
+        self.cache().light_blocks.len()
+    }
+
+    /// Whether the cache is currently empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all light blocks from the cache.
+    pub fn clear(&self) {
+        *self.cache() = LruCache::default();
+    }
+
+    /// Fetch a light block from the wrapped `Io` component, bypassing the cache.
+    ///
+    /// The cache is still updated with the fetched light block.
+    pub fn fetch_light_block_uncached(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        let light_block = self.inner.fetch_light_block(height)?;
+
+        self.cache().insert(
+            (self.peer, light_block.height()),
+            light_block.clone(),
+            self.capacity,
+        );
+
+        Ok(light_block)
+    }
+
+    fn cache(&self) -> std::sync::MutexGuard<'_, LruCache> {
+        // The cache is always left in a consistent state, even if a thread panicked
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<I: Io> Io for CachingIo<I> {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        if let AtHeight::At(height) = height {
+            if let Some(light_block) = self.cache().get(&(self.peer, height)) {
+                return Ok(light_block);
+            }
+        }
+
+        self.fetch_light_block_uncached(height)
+    }
+}
+
 #[cfg(feature = "rpc-client")]
-pub use self::prod::ProdIo;
+pub use self::prod::{AsyncProdIo, ProdEvidenceReporter, ProdIo};
 
 #[cfg(feature = "rpc-client")]
 mod prod {
     use tendermint::{
         account::Id as TMAccountId, block::signed_header::SignedHeader as TMSignedHeader,
-        validator::Set as TMValidatorSet,
+        consensus, validator::Set as TMValidatorSet,
     };
-    use tendermint_rpc::Paging;
+    use tendermint_rpc::{endpoint::validators::DEFAULT_VALIDATORS_PER_PAGE, Paging};
 
     use super::*;
-    use crate::{utils::block_on, verifier::types::PeerId};
+    use crate::utils::{block_on, timeout};
 
     /// Production implementation of the Io component, which fetches
     /// light blocks from full nodes via RPC.
@@ -146,33 +555,28 @@ mod prod {
     pub struct ProdIo {
         peer_id: PeerId,
         rpc_client: rpc::HttpClient,
-        timeout: Option<Duration>,
+        timeout: Duration,
+        fetch_mode: FetchMode,
+        max_validators: usize,
     }
 
     impl Io for ProdIo {
         fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
-            let signed_header = self.fetch_signed_header(height)?;
-            let height = signed_header.header.height;
-            let proposer_address = signed_header.header.proposer_address;
-
-            let validator_set = self.fetch_validator_set(height.into(), Some(proposer_address))?;
-            let next_validator_set = self.fetch_validator_set(height.increment().into(), None)?;
-
-            let light_block = LightBlock::new(
-                signed_header,
-                validator_set,
-                next_validator_set,
-                self.peer_id,
-            );
-
-            Ok(light_block)
+            let client = self.rpc_client.clone();
+            let peer_id = self.peer_id;
+            let fetch_mode = self.fetch_mode;
+            let max_validators = self.max_validators;
+            block_on(Some(self.timeout), async move {
+                fetch_light_block(&client, peer_id, height, fetch_mode, max_validators).await
+            })?
         }
     }
 
     impl ProdIo {
         /// Constructs a new ProdIo component.
         ///
-        /// A peer map which maps peer IDS to their network address must be supplied.
+        /// Fetching a light block fails with a timeout error if it takes longer than
+        /// `timeout`, or [`DEFAULT_TIMEOUT`] if none is given.
         pub fn new(
             peer_id: PeerId,
             rpc_client: rpc::HttpClient, /* TODO(thane): Generalize over client transport
@@ -182,10 +586,35 @@ mod prod {
             Self {
                 peer_id,
                 rpc_client,
-                timeout,
+                timeout: timeout.unwrap_or(DEFAULT_TIMEOUT),
+                fetch_mode: FetchMode::Full,
+                max_validators: DEFAULT_MAX_VALIDATORS,
             }
         }
 
+        /// Override the timeout for fetching a single light block.
+        pub fn with_timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = timeout;
+            self
+        }
+
+        /// Override which parts of a light block to fetch, [`FetchMode::Full`] by default.
+        pub fn with_fetch_mode(mut self, fetch_mode: FetchMode) -> Self {
+            self.fetch_mode = fetch_mode;
+            self
+        }
+
+        /// Override the maximum number of validators in a fetched validator set,
+        /// [`DEFAULT_MAX_VALIDATORS`] by default.
+        ///
+        /// Fetching a larger validator set fails as soon as the node reports its size,
+        /// before fetching all of its validators, so that a faulty node cannot
+        /// exhaust memory by claiming an arbitrarily large validator set.
+        pub fn with_max_validators(mut self, max_validators: usize) -> Self {
+            self.max_validators = max_validators;
+            self
+        }
+
         pub fn peer_id(&self) -> PeerId {
             self.peer_id
         }
@@ -194,52 +623,778 @@ mod prod {
             &self.rpc_client
         }
 
-        pub fn timeout(&self) -> Option<Duration> {
+        pub fn timeout(&self) -> Duration {
             self.timeout
         }
 
+        pub fn fetch_mode(&self) -> FetchMode {
+            self.fetch_mode
+        }
+
+        pub fn max_validators(&self) -> usize {
+            self.max_validators
+        }
+
         pub fn fetch_signed_header(&self, height: AtHeight) -> Result<TMSignedHeader, IoError> {
             let client = self.rpc_client.clone();
-            let res = block_on(self.timeout, async move {
-                match height {
-                    AtHeight::Highest => client.latest_commit().await,
-                    AtHeight::At(height) => client.commit(height).await,
-                }
-            })?;
+            block_on(Some(self.timeout), async move {
+                fetch_signed_header(&client, height).await
+            })?
+        }
+
+        pub fn fetch_validator_set(
+            &self,
+            height: AtHeight,
+            proposer_address: Option<TMAccountId>,
+        ) -> Result<TMValidatorSet, IoError> {
+            let client = self.rpc_client.clone();
+            let max_validators = self.max_validators;
+            block_on(Some(self.timeout), async move {
+                fetch_validator_set(&client, height, proposer_address, max_validators).await
+            })?
+        }
+    }
 
-            match res {
-                Ok(response) => Ok(response.signed_header),
-                Err(err) => Err(IoError::from_rpc(err)),
+    /// Production implementation of the AsyncIo component, which fetches
+    /// light blocks from full nodes via RPC, without blocking the current thread.
+    #[derive(Clone, Debug)]
+    pub struct AsyncProdIo {
+        peer_id: PeerId,
+        rpc_client: rpc::HttpClient,
+        timeout: Duration,
+        fetch_mode: FetchMode,
+        max_validators: usize,
+    }
+
+    #[async_trait]
+    impl AsyncIo for AsyncProdIo {
+        async fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+            timeout(
+                Some(self.timeout),
+                fetch_light_block(
+                    &self.rpc_client,
+                    self.peer_id,
+                    height,
+                    self.fetch_mode,
+                    self.max_validators,
+                ),
+            )
+            .await?
+        }
+    }
+
+    impl AsyncProdIo {
+        /// Constructs a new AsyncProdIo component.
+        ///
+        /// Fetching a light block fails with a timeout error if it takes longer than
+        /// `timeout`, or [`DEFAULT_TIMEOUT`] if none is given.
+        ///
+        /// The RPC requests must be awaited from within a Tokio runtime.
+        pub fn new(
+            peer_id: PeerId,
+            rpc_client: rpc::HttpClient,
+            timeout: Option<Duration>,
+        ) -> Self {
+            Self {
+                peer_id,
+                rpc_client,
+                timeout: timeout.unwrap_or(DEFAULT_TIMEOUT),
+                fetch_mode: FetchMode::Full,
+                max_validators: DEFAULT_MAX_VALIDATORS,
             }
         }
 
-        pub fn fetch_validator_set(
+        /// Override the timeout for fetching a single light block.
+        pub fn with_timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = timeout;
+            self
+        }
+
+        /// Override which parts of a light block to fetch, [`FetchMode::Full`] by default.
+        pub fn with_fetch_mode(mut self, fetch_mode: FetchMode) -> Self {
+            self.fetch_mode = fetch_mode;
+            self
+        }
+
+        /// Override the maximum number of validators in a fetched validator set,
+        /// [`DEFAULT_MAX_VALIDATORS`] by default.
+        ///
+        /// Fetching a larger validator set fails as soon as the node reports its size,
+        /// before fetching all of its validators, so that a faulty node cannot
+        /// exhaust memory by claiming an arbitrarily large validator set.
+        pub fn with_max_validators(mut self, max_validators: usize) -> Self {
+            self.max_validators = max_validators;
+            self
+        }
+
+        pub fn peer_id(&self) -> PeerId {
+            self.peer_id
+        }
+
+        pub fn rpc_client(&self) -> &rpc::HttpClient {
+            &self.rpc_client
+        }
+
+        pub fn timeout(&self) -> Duration {
+            self.timeout
+        }
+
+        pub fn fetch_mode(&self) -> FetchMode {
+            self.fetch_mode
+        }
+
+        pub fn max_validators(&self) -> usize {
+            self.max_validators
+        }
+
+        pub async fn fetch_signed_header(
+            &self,
+            height: AtHeight,
+        ) -> Result<TMSignedHeader, IoError> {
+            timeout(
+                Some(self.timeout),
+                fetch_signed_header(&self.rpc_client, height),
+            )
+            .await?
+        }
+
+        pub async fn fetch_validator_set(
             &self,
             height: AtHeight,
             proposer_address: Option<TMAccountId>,
         ) -> Result<TMValidatorSet, IoError> {
-            let height = match height {
-                AtHeight::Highest => {
-                    return Err(IoError::invalid_height());
-                },
-                AtHeight::At(height) => height,
-            };
+            timeout(
+                Some(self.timeout),
+                fetch_validator_set(
+                    &self.rpc_client,
+                    height,
+                    proposer_address,
+                    self.max_validators,
+                ),
+            )
+            .await?
+        }
+    }
+
+    impl From<ProdIo> for AsyncProdIo {
+        fn from(io: ProdIo) -> Self {
+            Self::new(io.peer_id, io.rpc_client, Some(io.timeout))
+                .with_fetch_mode(io.fetch_mode)
+                .with_max_validators(io.max_validators)
+        }
+    }
+
+    /// Production implementation of the EvidenceReporter component, which submits
+    /// evidence to full nodes via the `/broadcast_evidence` RPC endpoint.
+    #[derive(Clone, Debug)]
+    pub struct ProdEvidenceReporter {
+        peer_map: HashMap<PeerId, rpc::HttpClient>,
+        timeout: Option<Duration>,
+        consensus_params: Option<consensus::Params>,
+    }
+
+    impl EvidenceReporter for ProdEvidenceReporter {
+        fn report_evidence(&self, peer: PeerId, evidence: Evidence) -> Result<Hash, IoError> {
+            let client = self
+                .peer_map
+                .get(&peer)
+                .cloned()
+                .ok_or_else(|| IoError::unknown_peer(peer))?;
+
+            let consensus_params = self.consensus_params.clone();
 
-            let client = self.rpc_client.clone();
             let response = block_on(self.timeout, async move {
-                client.validators(height, Paging::All).await
-            })?
-            .map_err(IoError::rpc)?;
+                if let Some(consensus_params) = consensus_params {
+                    let sync_info = client.status().await?.sync_info;
+
+                    if evidence.is_expired(
+                        &consensus_params,
+                        sync_info.latest_block_height,
+                        sync_info.latest_block_time,
+                    ) {
+                        return Err(IoError::expired_evidence(evidence.height()));
+                    }
+                }
+
+                Ok(client.broadcast_evidence(evidence).await?)
+            })??;
+
+            Ok(response.hash)
+        }
+    }
+
+    impl ProdEvidenceReporter {
+        /// Constructs a new ProdEvidenceReporter component.
+        ///
+        /// A peer map which maps peer IDs to their RPC client must be supplied.
+        pub fn new(peer_map: HashMap<PeerId, rpc::HttpClient>, timeout: Option<Duration>) -> Self {
+            Self {
+                peer_map,
+                timeout,
+                consensus_params: None,
+            }
+        }
+
+        /// Refuse to report evidence which is expired according to the given consensus
+        /// parameters, as of the latest block of the peer it would be reported to,
+        /// since the peer would reject it anyway.
+        ///
+        /// This costs an extra `/status` request to the peer before each report.
+        pub fn with_consensus_params(mut self, consensus_params: consensus::Params) -> Self {
+            self.consensus_params = Some(consensus_params);
+            self
+        }
+    }
+
+    // The functions below are shared between `ProdIo` and `AsyncProdIo`, so that both
+    // components interpret the responses of the full node in exactly the same way.
+
+    async fn fetch_light_block(
+        client: &rpc::HttpClient,
+        peer_id: PeerId,
+        height: AtHeight,
+        fetch_mode: FetchMode,
+        max_validators: usize,
+    ) -> Result<LightBlock, IoError> {
+        let signed_header = fetch_signed_header(client, height).await?;
+        let height = signed_header.header.height;
+        let proposer_address = signed_header.header.proposer_address;
 
-            let validator_set = match proposer_address {
-                Some(proposer_address) => {
-                    TMValidatorSet::with_proposer(response.validators, proposer_address)
-                        .map_err(IoError::invalid_validator_set)?
+        let validator_set = fetch_validator_set(
+            client,
+            height.into(),
+            Some(proposer_address),
+            max_validators,
+        )
+        .await?;
+        let next_validator_set = match fetch_mode {
+            FetchMode::Full => {
+                fetch_validator_set(client, height.increment().into(), None, max_validators).await?
+            },
+            FetchMode::HeaderAndVals => TMValidatorSet::without_proposer(Vec::new()),
+        };
+
+        Ok(LightBlock::new(
+            signed_header,
+            validator_set,
+            next_validator_set,
+            peer_id,
+        ))
+    }
+
+    async fn fetch_signed_header(
+        client: &rpc::HttpClient,
+        height: AtHeight,
+    ) -> Result<TMSignedHeader, IoError> {
+        let response = match height {
+            AtHeight::Highest => client.latest_commit().await,
+            AtHeight::At(height) => client.commit(height).await,
+        }?;
+
+        Ok(response.signed_header)
+    }
+
+    async fn fetch_validator_set(
+        client: &rpc::HttpClient,
+        height: AtHeight,
+        proposer_address: Option<TMAccountId>,
+        max_validators: usize,
+    ) -> Result<TMValidatorSet, IoError> {
+        let height = match height {
+            AtHeight::Highest => {
+                return Err(IoError::invalid_height());
+            },
+            AtHeight::At(height) => height,
+        };
+
+        let page = |page_number: usize| {
+            client.validators(
+                height,
+                Paging::Specific {
+                    page_number: page_number.into(),
+                    per_page: DEFAULT_VALIDATORS_PER_PAGE.into(),
                 },
-                None => TMValidatorSet::without_proposer(response.validators),
+            )
+        };
+
+        // Check the total announced with the first page before fetching any other one
+        let first_page = page(1).await?;
+        let reported_total = first_page.total;
+        let total = usize::try_from(reported_total)
+            .ok()
+            .filter(|total| *total <= max_validators)
+            .ok_or_else(|| IoError::too_many_validators(height, reported_total, max_validators))?;
+
+        let mut validators = first_page.validators;
+        let mut page_number = 1;
+        while validators.len() < total {
+            page_number += 1;
+            let page = page(page_number).await?;
+            if page.validators.is_empty() {
+                break;
+            }
+            validators.extend(page.validators);
+        }
+
+        if validators.len() != total {
+            return Err(
+                rpc::Error::validators_count_mismatch(reported_total, validators.len()).into(),
+            );
+        }
+
+        let validator_set = match proposer_address {
+            Some(proposer_address) => TMValidatorSet::with_proposer(validators, proposer_address)
+                .map_err(IoError::invalid_validator_set)?,
+            None => TMValidatorSet::without_proposer(validators),
+        };
+
+        Ok(validator_set)
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    use tendermint_testgen::{light_block::LightBlock as TestgenLightBlock, Generator};
+
+    use super::*;
+
+    /// Serves the given light block, fails, or never responds at all.
+    enum TestIo {
+        Serve(Box<LightBlock>),
+        Fail,
+        Hang,
+    }
+
+    #[async_trait]
+    impl AsyncIo for TestIo {
+        async fn fetch_light_block(&self, _height: AtHeight) -> Result<LightBlock, IoError> {
+            match self {
+                Self::Serve(light_block) => Ok(light_block.as_ref().clone()),
+                Self::Fail => Err(IoError::invalid_height()),
+                Self::Hang => futures::future::pending().await,
+            }
+        }
+    }
+
+    /// Fails with the given error for the first `failures` fetches, then serves the light block.
+    struct FlakyIo {
+        light_block: LightBlock,
+        failures: u32,
+        error: fn() -> IoError,
+        calls: AtomicU32,
+    }
+
+    impl Io for FlakyIo {
+        fn fetch_light_block(&self, _height: AtHeight) -> Result<LightBlock, IoError> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                Err((self.error)())
+            } else {
+                Ok(self.light_block.clone())
+            }
+        }
+    }
+
+    fn peer_id(byte: u8) -> PeerId {
+        PeerId::new([byte; 20])
+    }
+
+    fn light_block() -> LightBlock {
+        let tm_lb = TestgenLightBlock::new_default(1).generate().unwrap();
+        LightBlock::new(
+            tm_lb.signed_header,
+            tm_lb.validators,
+            tm_lb.next_validators,
+            tm_lb.provider,
+        )
+    }
+
+    fn timeout_error() -> IoError {
+        IoError::rpc(rpc::Error::timeout(Duration::from_secs(1)))
+    }
+
+    fn flaky_io(failures: u32, error: fn() -> IoError) -> RetryingIo<FlakyIo> {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        };
+
+        let io = FlakyIo {
+            light_block: light_block(),
+            failures,
+            error,
+            calls: AtomicU32::new(0),
+        };
+
+        RetryingIo::new(io, policy)
+    }
+
+    #[test]
+    fn retrying_io_retries_transient_errors() {
+        let io = flaky_io(3, timeout_error);
+
+        assert_eq!(
+            io.fetch_light_block(AtHeight::Highest).unwrap(),
+            light_block()
+        );
+        assert_eq!(io.inner().calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn retrying_io_gives_up_after_max_retries() {
+        let io = flaky_io(4, timeout_error);
+
+        let error = io.fetch_light_block(AtHeight::Highest).unwrap_err();
+        assert!(error.detail().is_transient());
+        assert_eq!(io.inner().calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn rpc_errors_are_classified() {
+        let height = |h: u32| Height::from(h);
+
+        let error = IoError::from(rpc::Error::timeout(Duration::from_secs(1)));
+        assert!(matches!(error.detail(), IoErrorDetail::Transport(_)));
+        assert!(error.detail().is_transient());
+
+        let error = IoError::from(rpc::Error::malformed_json());
+        assert!(matches!(error.detail(), IoErrorDetail::InvalidResponse(_)));
+        assert!(error.detail().is_invalid_response());
+        assert!(!error.detail().is_transient());
+
+        let error = IoError::from(rpc::Error::server(
+            "height 5 is not available, lowest height is 10".to_string(),
+        ));
+        assert!(matches!(
+            error.detail(),
+            IoErrorDetail::HeightNotFound(e) if e.height == height(5)
+        ));
+
+        let error = IoError::from(rpc::Error::server(
+            "height 20 must be less than or equal to the current blockchain height 10".to_string(),
+        ));
+        assert!(matches!(
+            error.detail(),
+            IoErrorDetail::HeightTooHigh(e) if e.height == height(20) && e.latest_height == height(10)
+        ));
+
+        let error = IoError::from(rpc::Error::response(
+            rpc::response_error::ResponseError::new((-32600).into(), None),
+        ));
+        assert!(matches!(error.detail(), IoErrorDetail::Rpc(_)));
+        assert!(!error.detail().is_transient());
+    }
+
+    #[test]
+    fn retrying_io_does_not_retry_logical_errors() {
+        let io = flaky_io(1, IoError::invalid_height);
+
+        assert!(io.fetch_light_block(AtHeight::Highest).is_err());
+        assert_eq!(io.inner().calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn retry_policy_respects_max_total_delay() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_secs(1),
+            max_retries: 10,
+            max_total_delay: Duration::from_secs(3),
+        };
+
+        assert_eq!(
+            policy.delay(1, Duration::ZERO),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            policy.delay(2, Duration::from_secs(1)),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(policy.delay(3, Duration::from_secs(3)), None);
+    }
+
+    /// Serves light blocks up to the given height, counting the fetches.
+    struct CountingIo {
+        highest: u64,
+        calls: AtomicU32,
+    }
+
+    impl Io for CountingIo {
+        fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+
+            let height = match height {
+                AtHeight::At(height) if height.value() > self.highest => {
+                    return Err(IoError::invalid_height())
+                },
+                AtHeight::At(height) => height.value(),
+                AtHeight::Highest => self.highest,
             };
 
-            Ok(validator_set)
+            let tm_lb = TestgenLightBlock::new_default(height).generate().unwrap();
+            Ok(LightBlock::new(
+                tm_lb.signed_header,
+                tm_lb.validators,
+                tm_lb.next_validators,
+                tm_lb.provider,
+            ))
         }
     }
+
+    fn caching_io(capacity: usize) -> CachingIo<CountingIo> {
+        let io = CountingIo {
+            highest: 5,
+            calls: AtomicU32::new(0),
+        };
+
+        CachingIo::new(peer_id(1), io, capacity)
+    }
+
+    fn at(height: u32) -> AtHeight {
+        AtHeight::At(height.into())
+    }
+
+    #[test]
+    fn caching_io_memoizes_light_blocks() {
+        let io = caching_io(10);
+        let calls = || io.inner().calls.load(Ordering::SeqCst);
+
+        let light_block = io.fetch_light_block(at(1)).unwrap();
+        assert_eq!(io.fetch_light_block(at(1)).unwrap(), light_block);
+        assert_eq!(calls(), 1);
+
+        // The highest light block is always fetched, but cached at its height
+        io.fetch_light_block(AtHeight::Highest).unwrap();
+        io.fetch_light_block(AtHeight::Highest).unwrap();
+        assert_eq!(calls(), 3);
+        assert_eq!(io.fetch_light_block(at(5)).unwrap().height().value(), 5);
+        assert_eq!(calls(), 3);
+
+        // Errors are not cached
+        assert!(io.fetch_light_block(at(6)).is_err());
+        assert!(io.fetch_light_block(at(6)).is_err());
+        assert_eq!(calls(), 5);
+        assert_eq!(io.len(), 2);
+    }
+
+    #[test]
+    fn caching_io_evicts_least_recently_used() {
+        let io = caching_io(2);
+        let calls = || io.inner().calls.load(Ordering::SeqCst);
+
+        io.fetch_light_block(at(1)).unwrap();
+        io.fetch_light_block(at(2)).unwrap();
+        io.fetch_light_block(at(1)).unwrap();
+        io.fetch_light_block(at(3)).unwrap();
+        assert_eq!(calls(), 3);
+        assert_eq!(io.len(), 2);
+
+        // 2 was evicted rather than 1, which was used more recently
+        io.fetch_light_block(at(1)).unwrap();
+        assert_eq!(calls(), 3);
+        io.fetch_light_block(at(2)).unwrap();
+        assert_eq!(calls(), 4);
+    }
+
+    #[test]
+    fn caching_io_can_be_cleared_or_bypassed() {
+        let io = caching_io(2);
+        let calls = || io.inner().calls.load(Ordering::SeqCst);
+
+        io.fetch_light_block(at(1)).unwrap();
+        io.fetch_light_block_uncached(at(1)).unwrap();
+        assert_eq!(calls(), 2);
+
+        io.clear();
+        assert!(io.is_empty());
+        io.fetch_light_block(at(1)).unwrap();
+        assert_eq!(calls(), 3);
+
+        // Nothing is cached without any capacity
+        let io = caching_io(0);
+        io.fetch_light_block(at(1)).unwrap();
+        io.fetch_light_block(at(1)).unwrap();
+        assert_eq!(io.inner().calls.load(Ordering::SeqCst), 2);
+        assert!(io.is_empty());
+    }
+
+    #[test]
+    fn fetch_from_all_returns_partial_results() {
+        let tm_lb = TestgenLightBlock::new_default(1).generate().unwrap();
+        let light_block = LightBlock::new(
+            tm_lb.signed_header,
+            tm_lb.validators,
+            tm_lb.next_validators,
+            tm_lb.provider,
+        );
+
+        let serving = TestIo::Serve(Box::new(light_block.clone()));
+        let failing = TestIo::Fail;
+        let hanging = TestIo::Hang;
+
+        let peers: Vec<(PeerId, &dyn AsyncIo)> = vec![
+            (peer_id(1), &serving),
+            (peer_id(2), &failing),
+            (peer_id(3), &hanging),
+        ];
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        let results = rt.block_on(fetch_from_all(
+            &peers,
+            AtHeight::At(1_u32.into()),
+            Some(Duration::from_millis(100)),
+        ));
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[&peer_id(1)].as_ref().unwrap(), &light_block);
+        assert!(results[&peer_id(2)].is_err());
+        assert!(results[&peer_id(3)]
+            .as_ref()
+            .unwrap_err()
+            .detail()
+            .is_timeout()
+            .is_some());
+    }
+
+    #[cfg(feature = "rpc-client")]
+    #[test]
+    fn prod_io_times_out_on_unresponsive_peer() {
+        // Connections are accepted by the OS, but no response is ever sent
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let rpc_client = rpc::HttpClient::new(url.as_str()).unwrap();
+
+        let io = ProdIo::new(peer_id(1), rpc_client, None).with_timeout(Duration::from_millis(100));
+        assert_eq!(io.timeout(), Duration::from_millis(100));
+
+        let error = io.fetch_light_block(AtHeight::Highest).unwrap_err();
+        assert_eq!(
+            error.detail().is_timeout(),
+            Some(Duration::from_millis(100))
+        );
+    }
+
+    /// Serve the given JSON-RPC result to every HTTP request, from another thread,
+    /// counting the requests served.
+    #[cfg(feature = "rpc-client")]
+    fn serve_json_rpc(result: serde_json::Value) -> (String, Arc<AtomicU32>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicU32::new(0));
+
+        let served = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut reader = BufReader::new(stream.unwrap());
+
+                // Read the headers, then the body of each request on the connection
+                let mut content_length = 0;
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 0 {
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    if line == "\r\n" {
+                        let mut body = vec![0; content_length];
+                        reader.read_exact(&mut body).unwrap();
+                        served.fetch_add(1, Ordering::SeqCst);
+
+                        let response =
+                            serde_json::json!({ "jsonrpc": "2.0", "id": "", "result": result })
+                                .to_string();
+                        write!(
+                            reader.get_mut(),
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                            response.len(),
+                            response
+                        )
+                        .unwrap();
+                    }
+                    line.clear();
+                }
+            }
+        });
+
+        (url, requests)
+    }
+
+    #[cfg(feature = "rpc-client")]
+    #[test]
+    fn prod_io_rejects_oversized_validator_set() {
+        // The first page of a validator set claiming to hold a million validators
+        let validators = light_block().validators.validators().clone();
+        let response =
+            rpc::endpoint::validators::Response::new(1_u32.into(), validators.clone(), 1_000_000);
+        let (url, requests) = serve_json_rpc(serde_json::to_value(response).unwrap());
+
+        let rpc_client = rpc::HttpClient::new(url.as_str()).unwrap();
+        let io = ProdIo::new(peer_id(1), rpc_client, None);
+        assert_eq!(io.max_validators(), DEFAULT_MAX_VALIDATORS);
+
+        let error = io
+            .fetch_validator_set(AtHeight::At(1_u32.into()), None)
+            .unwrap_err();
+        match error.detail() {
+            IoErrorDetail::TooManyValidators(e) => {
+                assert_eq!(e.total, 1_000_000);
+                assert_eq!(e.max_validators, DEFAULT_MAX_VALIDATORS);
+            },
+            e => panic!("expected too many validators, got: {e:?}"),
+        }
+        assert!(error.detail().is_invalid_response());
+
+        // No other page was requested
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // The limit applies to the total, however many validators a page holds
+        let total = validators.len() as i32;
+        let response = rpc::endpoint::validators::Response::new(1_u32.into(), validators, total);
+        let (url, _) = serve_json_rpc(serde_json::to_value(response).unwrap());
+
+        let rpc_client = rpc::HttpClient::new(url.as_str()).unwrap();
+        let io = ProdIo::new(peer_id(1), rpc_client.clone(), None);
+        assert!(io
+            .fetch_validator_set(AtHeight::At(1_u32.into()), None)
+            .is_ok());
+
+        let io = ProdIo::new(peer_id(1), rpc_client, None).with_max_validators(total as usize - 1);
+        assert!(matches!(
+            io.fetch_validator_set(AtHeight::At(1_u32.into()), None)
+                .unwrap_err()
+                .detail(),
+            IoErrorDetail::TooManyValidators(_)
+        ));
+    }
+
+    #[cfg(feature = "rpc-client")]
+    #[test]
+    fn report_evidence_to_unknown_peer_fails() {
+        let tm_lb = TestgenLightBlock::new_default(1).generate().unwrap();
+        let light_block = LightBlock::new(
+            tm_lb.signed_header,
+            tm_lb.validators,
+            tm_lb.next_validators,
+            tm_lb.provider,
+        );
+
+        let evidence =
+            crate::evidence::make_evidence(light_block.clone(), light_block.clone(), light_block);
+
+        let reporter = ProdEvidenceReporter::new(HashMap::new(), None);
+        let result = reporter.report_evidence(peer_id(1), evidence.into());
+
+        assert!(matches!(
+            result.unwrap_err().detail(),
+            IoErrorDetail::UnknownPeer(e) if e.peer_id == peer_id(1)
+        ));
+    }
 }
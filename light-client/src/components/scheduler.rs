@@ -2,12 +2,34 @@
 
 use contracts::*;
 
-use crate::{store::LightStore, verifier::types::Height};
+use crate::{
+    store::LightStore,
+    verifier::types::{Height, Status},
+};
 
 /// The scheduler decides what block to verify next given the current and target heights.
 ///
 /// The scheduler is given access to the light store, in order to optionally
 /// improve performance by picking a next block that has already been fetched.
+///
+/// The light client first attempts to verify the target height directly, and asks
+/// the scheduler for the next height to verify after each verification step:
+/// - `current_height` is the height which was just verified, or failed to verify;
+/// - `target_height` is the height the light client is verifying up to.
+///
+/// Whether the block at `current_height` could be verified can be told by comparing it
+/// to the highest trusted or verified block in the light store.
+///
+/// Schedulers which skip over blocks need the next validator set of every block they
+/// skip from, and thus light blocks fetched with [`FetchMode::Full`]. Only
+/// [`sequential_schedule`] can work with [`FetchMode::HeaderAndVals`].
+///
+/// [`FetchMode::Full`]: crate::components::io::FetchMode::Full
+/// [`FetchMode::HeaderAndVals`]: crate::components::io::FetchMode::HeaderAndVals
+///
+/// Any function with the signature of [`basic_bisecting_schedule`] is a scheduler.
+/// Besides it, [`bisecting_schedule`], [`sequential_schedule`], [`bisection_with_cap`]
+/// and [`adaptive_schedule`] are provided.
 #[contract_trait]
 #[allow(missing_docs)] // This is required because of the `contracts` crate (TODO: open/link issue)
 pub trait Scheduler: Send + Sync {
@@ -46,6 +68,8 @@ where
 /// Basic bisecting scheduler which picks the appropriate midpoint without
 /// optimizing for performance using the blocks available in the light store.
 ///
+/// Requires light blocks fetched with [`FetchMode::Full`](crate::components::io::FetchMode::Full).
+///
 /// ## Precondition
 /// - The light store contains at least one verified block. [LCV-SCHEDULE-PRE.1]
 ///
@@ -73,6 +97,166 @@ pub fn basic_bisecting_schedule(
     }
 }
 
+/// How a bisecting scheduler picks the height to verify next, between the highest
+/// trusted or verified block and the block which could not be verified.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PivotStrategy {
+    /// Pick the midpoint between both blocks, as [`basic_bisecting_schedule`] does.
+    #[default]
+    Midpoint,
+
+    /// Pick the height at the given fraction of the way from the trusted block to the
+    /// block which could not be verified, eg. `0.75` for three quarters of the way.
+    ///
+    /// Fractions above one half make bisection jump more aggressively towards the target
+    /// height, which pays off when the validator set changes close to it. The picked
+    /// height is always strictly between both blocks, whatever the fraction.
+    BiasedTowardTarget(f64),
+}
+
+/// Returns a bisecting scheduler which behaves like [`basic_bisecting_schedule`],
+/// except that it picks the height to verify next with the given [`PivotStrategy`].
+///
+/// Requires light blocks fetched with [`FetchMode::Full`](crate::components::io::FetchMode::Full).
+///
+/// ## Precondition
+/// - The light store contains at least one verified block. [LCV-SCHEDULE-PRE.1]
+///
+/// ## Postcondition
+/// - The resulting height must be valid according to `valid_schedule`. [LCV-SCHEDULE-POST.1]
+pub fn bisecting_schedule(
+    strategy: PivotStrategy,
+) -> impl Fn(&dyn LightStore, Height, Height) -> Height + Send + Sync {
+    move |light_store, current_height, target_height| {
+        let trusted_height = light_store
+            .highest_trusted_or_verified_before(target_height)
+            .map(|lb| lb.height())
+            .unwrap();
+
+        if trusted_height == current_height {
+            return target_height;
+        }
+
+        match strategy {
+            PivotStrategy::Midpoint => midpoint(trusted_height, current_height),
+            PivotStrategy::BiasedTowardTarget(fraction) => {
+                biased_pivot(trusted_height, current_height, fraction)
+            },
+        }
+    }
+}
+
+/// Sequential scheduler which always picks the height right after the highest
+/// trusted or verified block, thus verifying every block up to the target height.
+///
+/// This avoids the overhead of bisection over short ranges, at the cost of
+/// fetching and verifying every intermediate block over long ones.
+///
+/// Works with light blocks fetched with any [`FetchMode`](crate::components::io::FetchMode),
+/// as blocks are verified one after the other when their next validator set is not known.
+///
+/// ## Precondition
+/// - The light store contains at least one verified block. [LCV-SCHEDULE-PRE.1]
+///
+/// ## Postcondition
+/// - The resulting height must be valid according to `valid_schedule`. [LCV-SCHEDULE-POST.1]
+#[requires(light_store.highest_trusted_or_verified().is_some())]
+#[ensures(valid_schedule(ret, target_height, current_height, light_store))]
+pub fn sequential_schedule(
+    light_store: &dyn LightStore,
+    current_height: Height,
+    target_height: Height,
+) -> Height {
+    let trusted_height = light_store
+        .highest_trusted_or_verified_before(target_height)
+        .map(|lb| lb.height())
+        .unwrap();
+
+    next_height(trusted_height, target_height)
+}
+
+/// Returns a bisecting scheduler which behaves like [`basic_bisecting_schedule`],
+/// except that it bisects at most `max_depth` times in a row, after which it falls
+/// back to [`sequential_schedule`] until a block has been verified.
+///
+/// The bisection depth is derived from how far the current height is from the
+/// highest trusted or verified block, compared to how far the target height is.
+///
+/// Requires light blocks fetched with [`FetchMode::Full`](crate::components::io::FetchMode::Full).
+///
+/// ## Precondition
+/// - The light store contains at least one verified block. [LCV-SCHEDULE-PRE.1]
+///
+/// ## Postcondition
+/// - The resulting height must be valid according to `valid_schedule`. [LCV-SCHEDULE-POST.1]
+pub fn bisection_with_cap(
+    max_depth: u32,
+) -> impl Fn(&dyn LightStore, Height, Height) -> Height + Send + Sync {
+    move |light_store, current_height, target_height| {
+        let trusted_height = light_store
+            .highest_trusted_or_verified_before(target_height)
+            .map(|lb| lb.height())
+            .unwrap();
+
+        if trusted_height == current_height {
+            return target_height;
+        }
+
+        let remaining = target_height.value() - trusted_height.value();
+        let current = current_height.value() - trusted_height.value();
+
+        // Each bisection halves the distance to the trusted height, so the number
+        // of bisections so far is roughly `log2(remaining / current)`.
+        let depth = (remaining / current).checked_ilog2().unwrap_or(0);
+
+        if depth >= max_depth {
+            next_height(trusted_height, target_height)
+        } else {
+            midpoint(trusted_height, current_height)
+        }
+    }
+}
+
+/// Returns a scheduler which bisects like [`basic_bisecting_schedule`] as long as skipping
+/// ahead mostly succeeds, but falls back to [`sequential_schedule`] once `max_failed_skips`
+/// blocks between the highest trusted or verified block and the target height could not
+/// be verified, ie. when the validator set changes too quickly for skipping to pay off.
+///
+/// Failed skips are told by the blocks left unverified in the light store, so that
+/// bisection resumes once verification has gone past them. Unlike [`bisection_with_cap`],
+/// this avoids attempting to skip to the target height again after each sequential step
+/// over chains whose validator set changes at every height.
+///
+/// Requires light blocks fetched with [`FetchMode::Full`](crate::components::io::FetchMode::Full).
+///
+/// ## Precondition
+/// - The light store contains at least one verified block. [LCV-SCHEDULE-PRE.1]
+///
+/// ## Postcondition
+/// - The resulting height must be valid according to `valid_schedule`. [LCV-SCHEDULE-POST.1]
+pub fn adaptive_schedule(
+    max_failed_skips: usize,
+) -> impl Fn(&dyn LightStore, Height, Height) -> Height + Send + Sync {
+    move |light_store, current_height, target_height| {
+        let trusted_height = light_store
+            .highest_trusted_or_verified_before(target_height)
+            .map(|lb| lb.height())
+            .unwrap();
+
+        let failed_skips = light_store
+            .all(Status::Unverified)
+            .map(|lb| lb.height())
+            .filter(|&h| trusted_height < h && h <= target_height)
+            .count();
+
+        if failed_skips >= max_failed_skips {
+            next_height(trusted_height, target_height)
+        } else {
+            basic_bisecting_schedule(light_store, current_height, target_height)
+        }
+    }
+}
+
 /// Checks whether the given `scheduled_height` is a valid schedule according to the
 /// following specification.
 ///
@@ -118,6 +302,15 @@ pub fn valid_schedule(
     }
 }
 
+/// The height right after `trusted_height`, or `target_height` if it has already been verified.
+fn next_height(trusted_height: Height, target_height: Height) -> Height {
+    if trusted_height >= target_height {
+        target_height
+    } else {
+        trusted_height.increment()
+    }
+}
+
 #[requires(low <= high)]
 #[ensures(low <= ret && ret <= high)]
 fn midpoint(low: Height, high: Height) -> Height {
@@ -125,3 +318,15 @@ fn midpoint(low: Height, high: Height) -> Height {
         .try_into()
         .unwrap() // Will panic if midpoint is higher than i64::MAX
 }
+
+#[requires(low < high)]
+#[ensures(low < ret && ret <= high)]
+fn biased_pivot(low: Height, high: Height, fraction: f64) -> Height {
+    let distance = high.value() - low.value();
+
+    // Rounding up as `midpoint` does, but staying below `high` unless it is right after `low`
+    let offset = (fraction * distance as f64).ceil() as u64;
+    let offset = offset.clamp(1, distance.saturating_sub(1).max(1));
+
+    (low.value() + offset).try_into().unwrap()
+}
@@ -0,0 +1,208 @@
+//! Provides an interface and a no-op implementation of the `Telemetry` component
+
+use std::time::Duration;
+
+use crate::{
+    components::io::{AtHeight, IoError},
+    verifier::{
+        types::{Height, LightBlock, PeerId},
+        Verdict, VerificationWarning,
+    },
+};
+
+/// Hooks invoked by the light client at key points of the verification,
+/// eg. to record metrics.
+///
+/// All methods do nothing by default.
+pub trait Telemetry: Send + Sync {
+    /// A light block was fetched from the given peer, successfully or not,
+    /// taking the given amount of time.
+    fn fetched(
+        &self,
+        _peer: PeerId,
+        _height: AtHeight,
+        _latency: Duration,
+        _result: Result<&LightBlock, &IoError>,
+    ) {
+    }
+
+    /// The given light block, provided by the given peer, was verified with the given verdict.
+    fn verified(&self, _peer: PeerId, _light_block: &LightBlock, _verdict: &Verdict) {}
+
+    /// The given light block, provided by the given peer, was verified successfully,
+    /// but with the given warning, as configured in the options of the light client.
+    fn warned(&self, _peer: PeerId, _light_block: &LightBlock, _warning: &VerificationWarning) {}
+
+    /// The light block at `current_height` could not be trusted yet, so bisection
+    /// moves on to `next_height` on the way to `target_height`.
+    fn bisected(
+        &self,
+        _peer: PeerId,
+        _current_height: Height,
+        _next_height: Height,
+        _target_height: Height,
+    ) {
+    }
+}
+
+/// Telemetry which does nothing at all.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NoopTelemetry;
+
+impl Telemetry for NoopTelemetry {}
+
+#[cfg(feature = "prometheus")]
+pub use self::prometheus::PrometheusTelemetry;
+
+#[cfg(feature = "prometheus")]
+mod prometheus {
+    use ::prometheus::{HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry};
+
+    use super::*;
+
+    /// Telemetry recording the following Prometheus metrics, labelled by peer:
+    /// - `light_client_fetch_duration_seconds`, a histogram of the time taken
+    ///   to fetch light blocks, also labelled by `outcome` (`ok` or `error`);
+    /// - `light_client_verifications_total`, a counter of the verified light blocks,
+    ///   also labelled by `verdict` (`success`, `not_enough_trust` or `invalid`);
+    /// - `light_client_bisections_total`, a counter of the bisection steps.
+    #[derive(Clone, Debug)]
+    pub struct PrometheusTelemetry {
+        fetch_duration: HistogramVec,
+        verifications: IntCounterVec,
+        bisections: IntCounterVec,
+    }
+
+    impl PrometheusTelemetry {
+        /// Create the metrics and register them with the given registry.
+        pub fn register(registry: &Registry) -> Result<Self, ::prometheus::Error> {
+            let fetch_duration = HistogramVec::new(
+                HistogramOpts::new(
+                    "light_client_fetch_duration_seconds",
+                    "Time taken to fetch light blocks",
+                ),
+                &["peer", "outcome"],
+            )?;
+
+            let verifications = IntCounterVec::new(
+                Opts::new(
+                    "light_client_verifications_total",
+                    "Number of verified light blocks",
+                ),
+                &["peer", "verdict"],
+            )?;
+
+            let bisections = IntCounterVec::new(
+                Opts::new("light_client_bisections_total", "Number of bisection steps"),
+                &["peer"],
+            )?;
+
+            registry.register(Box::new(fetch_duration.clone()))?;
+            registry.register(Box::new(verifications.clone()))?;
+            registry.register(Box::new(bisections.clone()))?;
+
+            Ok(Self {
+                fetch_duration,
+                verifications,
+                bisections,
+            })
+        }
+    }
+
+    impl Telemetry for PrometheusTelemetry {
+        fn fetched(
+            &self,
+            peer: PeerId,
+            _height: AtHeight,
+            latency: Duration,
+            result: Result<&LightBlock, &IoError>,
+        ) {
+            let outcome = if result.is_ok() { "ok" } else { "error" };
+
+            self.fetch_duration
+                .with_label_values(&[&peer.to_string(), outcome])
+                .observe(latency.as_secs_f64());
+        }
+
+        fn verified(&self, peer: PeerId, _light_block: &LightBlock, verdict: &Verdict) {
+            let verdict = match verdict {
+                Verdict::Success => "success",
+                Verdict::NotEnoughTrust(_) => "not_enough_trust",
+                Verdict::Invalid(_) => "invalid",
+            };
+
+            self.verifications
+                .with_label_values(&[&peer.to_string(), verdict])
+                .inc();
+        }
+
+        fn bisected(
+            &self,
+            peer: PeerId,
+            _current_height: Height,
+            _next_height: Height,
+            _target_height: Height,
+        ) {
+            self.bisections
+                .with_label_values(&[&peer.to_string()])
+                .inc();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "prometheus"))]
+mod tests {
+    use ::prometheus::Registry;
+    use tendermint_testgen::{light_block::LightBlock as TestgenLightBlock, Generator};
+
+    use super::*;
+    use crate::verifier::types::PeerId;
+
+    #[test]
+    fn prometheus_telemetry_records_metrics() {
+        let registry = Registry::new();
+        let telemetry = PrometheusTelemetry::register(&registry).unwrap();
+
+        let tm_lb = TestgenLightBlock::new_default(1).generate().unwrap();
+        let light_block = LightBlock::new(
+            tm_lb.signed_header,
+            tm_lb.validators,
+            tm_lb.next_validators,
+            tm_lb.provider,
+        );
+        let peer = PeerId::new([1; 20]);
+        let height = light_block.height();
+
+        telemetry.fetched(
+            peer,
+            AtHeight::At(height),
+            Duration::from_millis(10),
+            Ok(&light_block),
+        );
+        telemetry.verified(peer, &light_block, &Verdict::Success);
+        telemetry.verified(peer, &light_block, &Verdict::Success);
+        telemetry.bisected(peer, height, height, height);
+
+        let metrics = registry.gather();
+        let metric = |name: &str| {
+            metrics
+                .iter()
+                .find(|m| m.get_name() == name)
+                .unwrap_or_else(|| panic!("missing metric {name}"))
+                .get_metric()
+                .to_vec()
+        };
+
+        let fetches = metric("light_client_fetch_duration_seconds");
+        assert_eq!(fetches[0].get_histogram().get_sample_count(), 1);
+
+        let verifications = metric("light_client_verifications_total");
+        assert_eq!(verifications[0].get_counter().get_value(), 2.0);
+
+        let bisections = metric("light_client_bisections_total");
+        assert_eq!(bisections[0].get_counter().get_value(), 1.0);
+
+        // Registering the same metrics twice fails
+        assert!(PrometheusTelemetry::register(&registry).is_err());
+    }
+}
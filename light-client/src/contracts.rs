@@ -4,7 +4,10 @@ use std::time::Duration;
 
 use crate::{
     store::LightStore,
-    verifier::types::{Height, LightBlock, Status, Time},
+    verifier::{
+        predicates::is_within_trusting_period,
+        types::{Height, LightBlock, Status, Time},
+    },
 };
 
 /// Whether or not the given light store contains a verified or
@@ -19,16 +22,14 @@ pub fn trusted_store_contains_block_at_target_height(
 
 /// Whether or not the given block is within the given trusting period,
 /// relative to the given time.
+///
+/// See [`is_within_trusting_period`].
 pub fn is_within_trust_period(
     light_block: &LightBlock,
     trusting_period: Duration,
     now: Time,
 ) -> bool {
-    let header_time = light_block.signed_header.header.time;
-    match now - trusting_period {
-        Ok(start) => header_time > start,
-        Err(_) => false,
-    }
+    is_within_trusting_period(light_block.time(), trusting_period, now)
 }
 
 /// Whether or not the given light store contains a trusted block
@@ -4,6 +4,7 @@ use std::{fmt::Debug, time::Duration};
 
 use crossbeam_channel as crossbeam;
 use flex_error::{define_error, DisplayError, TraceError};
+use tendermint::evidence::LightClientAttackEvidence;
 
 // Re-export for backward compatibility
 pub use crate::verifier::errors::ErrorExt;
@@ -39,6 +40,26 @@ define_error! {
         NoWitnessesLeft
             | _ | { "no witnesses left" },
 
+        InsufficientWitnesses
+            {
+                agreeing: usize,
+                required: usize,
+            }
+            | e | {
+                format_args!("only {0} witnesses agreed with the primary, while {1} are required",
+                    e.agreeing, e.required)
+            },
+
+        NotEnoughResponsiveWitnesses
+            {
+                responsive: usize,
+                required: usize,
+            }
+            | e | {
+                format_args!("only {0} witnesses responded, while {1} are required",
+                    e.responsive, e.required)
+            },
+
         ForkDetected
             { peers: Vec<PeerId> }
             | e | {
@@ -46,6 +67,16 @@ define_error! {
                     e.peers)
             },
 
+        AttackDetected
+            {
+                primary: PeerId,
+                evidence: Vec<LightClientAttackEvidence>,
+            }
+            | e | {
+                format_args!("light client attack detected, primary {0} has been replaced",
+                    e.primary)
+            },
+
         NoInitialTrustedState
             | _ | { "no initial trusted state" },
 
@@ -66,6 +97,16 @@ define_error! {
                     e.target_height, e.trusted_height)
             },
 
+        TargetHigherThanTrustedState
+            {
+                target_height: Height,
+                trusted_height: Height,
+            }
+            | e | {
+                format_args!("target height ({0}) is higher than trusted state ({1})",
+                    e.target_height, e.trusted_height)
+            },
+
         HeightTooHigh
             {
                 height: Height,
@@ -95,6 +136,26 @@ define_error! {
                     e.target_height, e.trusted_height)
             },
 
+        BisectionDepthExceeded
+            {
+                target_height: Height,
+                max_depth: usize,
+            }
+            | e | {
+                format_args!("bisection for target at height {0} exceeded the maximum of {1} steps",
+                    e.target_height, e.max_depth)
+            },
+
+        MissingNextValidators
+            {
+                trusted_height: Height,
+                target_height: Height,
+            }
+            | e | {
+                format_args!("light block at height {0} was fetched without its next validator set, which is needed to skip to height {1}",
+                    e.trusted_height, e.target_height)
+            },
+
         InvalidLightBlock
             [ DisplayError<VerificationErrorDetail> ]
             | _ | { "invalid light block" },
@@ -119,6 +180,9 @@ define_error! {
         ChannelDisconnected
             | _ | { "internal channel disconnected" },
 
+        Cancelled
+            | _ | { "verification was cancelled" },
+
         Sled
             [ SledError ]
             | _ | { "sled error" },
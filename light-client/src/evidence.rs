@@ -1,3 +1,5 @@
+//! Construction of evidence of light client attacks, to be reported to full nodes.
+
 use std::cmp::Ordering;
 
 use tendermint::{
@@ -6,7 +8,7 @@ use tendermint::{
     validator,
 };
 
-use tendermint_light_client::verifier::types::LightBlock;
+use crate::verifier::types::LightBlock;
 
 /// Determines the type of attack and then forms the evidence filling out
 /// all the fields such that it is ready to be sent to a full node.
@@ -1,8 +1,9 @@
-//! Supervisor and Handle implementation.
+//! Light client instance, packaging a light client with its state.
 
 use tendermint::block::Height;
 
 use crate::{
+    builder::TrustAnchor,
     errors::Error,
     light_client::LightClient,
     state::State,
@@ -17,6 +18,9 @@ pub struct Instance {
 
     /// The state of the light client for this instance
     pub state: State,
+
+    /// The trust anchor this instance was bootstrapped from, if any
+    pub trust_anchor: Option<TrustAnchor>,
 }
 
 impl Instance {
@@ -25,9 +29,17 @@ impl Instance {
         Self {
             light_client,
             state,
+            trust_anchor: None,
         }
     }
 
+    /// The trust anchor this instance was bootstrapped from, if it was built with
+    /// [`LightClientBuilder::trust_primary_at`](crate::builder::LightClientBuilder::trust_primary_at)
+    /// or [`LightClientBuilder::trust_primary_at_any`](crate::builder::LightClientBuilder::trust_primary_at_any).
+    pub fn trust_anchor(&self) -> Option<TrustAnchor> {
+        self.trust_anchor
+    }
+
     /// Return the peer id of this instance.
     pub fn peer_id(&self) -> &tendermint::node::Id {
         &self.light_client.peer
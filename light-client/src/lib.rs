@@ -19,10 +19,12 @@ pub mod builder;
 pub mod components;
 pub mod contracts;
 pub mod errors;
+pub mod evidence;
 pub mod instance;
 pub mod light_client;
 pub mod state;
 pub mod store;
+pub mod supervisor;
 
 pub(crate) mod utils;
 
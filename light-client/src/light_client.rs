@@ -2,19 +2,32 @@
 //!
 //! [1]: https://github.com/informalsystems/tendermint-rs/blob/main/docs/spec/lightclient/verification/verification.md
 
-use core::fmt;
+use core::{fmt, future::Future};
+use std::time::{Duration, Instant};
 
 use contracts::*;
+use futures::{
+    future::{self, Either},
+    FutureExt,
+};
 
 // Re-export for backward compatibility
 pub use crate::verifier::options::Options;
 use crate::{
-    components::{clock::Clock, io::*, scheduler::*},
+    builder::error::Error as BuilderError,
+    components::{
+        clock::{Clock, SystemClock},
+        io::*,
+        scheduler::*,
+        telemetry::{NoopTelemetry, Telemetry},
+    },
     contracts::*,
     errors::Error,
-    state::State,
+    state::{BisectionStep, State, StateSnapshot},
+    store::LightStore,
     verifier::{
-        types::{Height, LightBlock, PeerId, Status},
+        predicates::trusting_period_remaining,
+        types::{Height, LightBlock, PeerId, Status, Time},
         Verdict, Verifier,
     },
 };
@@ -39,6 +52,7 @@ pub struct LightClient {
     scheduler: Box<dyn Scheduler>,
     verifier: Box<dyn Verifier>,
     io: Box<dyn Io>,
+    telemetry: Box<dyn Telemetry>,
 }
 
 impl fmt::Debug for LightClient {
@@ -50,8 +64,111 @@ impl fmt::Debug for LightClient {
     }
 }
 
+/// Builder for a [`LightClient`], as an alternative to [`LightClient::new`].
+///
+/// The primary peer, the options and the I/O component must be set, whereas
+/// the other components default to:
+/// - [`SystemClock`] for the clock;
+/// - [`basic_bisecting_schedule`] for the scheduler;
+/// - [`ProdVerifier`](crate::verifier::ProdVerifier) for the verifier,
+///   with the `rust-crypto` feature;
+/// - [`NoopTelemetry`] for the telemetry.
+///
+/// Note that this builds a bare [`LightClient`], whereas
+/// [`LightClientBuilder`](crate::builder::LightClientBuilder) builds an
+/// [`Instance`](crate::instance::Instance) with its own trusted state.
+#[derive(Default)]
+#[must_use]
+pub struct Builder {
+    primary: Option<PeerId>,
+    options: Option<Options>,
+    clock: Option<Box<dyn Clock>>,
+    scheduler: Option<Box<dyn Scheduler>>,
+    verifier: Option<Box<dyn Verifier>>,
+    io: Option<Box<dyn Io>>,
+    telemetry: Option<Box<dyn Telemetry>>,
+}
+
+impl Builder {
+    /// Set the peer this light client fetches light blocks from.
+    pub fn primary(mut self, peer: PeerId) -> Self {
+        self.primary = Some(peer);
+        self
+    }
+
+    /// Set the verification options.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Set the clock component.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Box::new(clock));
+        self
+    }
+
+    /// Set the scheduler component.
+    pub fn with_scheduler(mut self, scheduler: impl Scheduler + 'static) -> Self {
+        self.scheduler = Some(Box::new(scheduler));
+        self
+    }
+
+    /// Set the verifier component.
+    pub fn with_verifier(mut self, verifier: impl Verifier + 'static) -> Self {
+        self.verifier = Some(Box::new(verifier));
+        self
+    }
+
+    /// Set the I/O component, which must fetch light blocks from the primary peer.
+    pub fn with_io(mut self, io: impl Io + 'static) -> Self {
+        self.io = Some(Box::new(io));
+        self
+    }
+
+    /// Set the telemetry component.
+    pub fn with_telemetry(mut self, telemetry: impl Telemetry + 'static) -> Self {
+        self.telemetry = Some(Box::new(telemetry));
+        self
+    }
+
+    /// Build the light client, failing if any of the required components is missing,
+    /// or if the options are invalid (see [`Options::validate`]).
+    pub fn build(self) -> Result<LightClient, BuilderError> {
+        let missing = |component: &str| BuilderError::missing_component(component.to_string());
+
+        let peer = self.primary.ok_or_else(|| missing("primary"))?;
+        let options = self.options.ok_or_else(|| missing("options"))?;
+        let io = self.io.ok_or_else(|| missing("io"))?;
+
+        let clock = self.clock.unwrap_or_else(|| Box::new(SystemClock));
+        let scheduler = self
+            .scheduler
+            .unwrap_or_else(|| Box::new(basic_bisecting_schedule));
+
+        #[cfg(feature = "rust-crypto")]
+        #[allow(clippy::box_default)]
+        let verifier = self
+            .verifier
+            .unwrap_or_else(|| Box::new(crate::verifier::ProdVerifier::default()));
+        #[cfg(not(feature = "rust-crypto"))]
+        let verifier = self.verifier.ok_or_else(|| missing("verifier"))?;
+
+        let light_client = LightClient::from_boxed(peer, options, clock, scheduler, verifier, io)?;
+
+        Ok(match self.telemetry {
+            Some(telemetry) => LightClient {
+                telemetry,
+                ..light_client
+            },
+            None => light_client,
+        })
+    }
+}
+
 impl LightClient {
-    /// Constructs a new light client
+    /// Constructs a new light client, failing if the options are invalid
+    /// (see [`Options::validate`])
     pub fn new(
         peer: PeerId,
         options: Options,
@@ -59,18 +176,19 @@ impl LightClient {
         scheduler: impl Scheduler + 'static,
         verifier: impl Verifier + 'static,
         io: impl Io + 'static,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, BuilderError> {
+        Self::from_boxed(
             peer,
             options,
-            clock: Box::new(clock),
-            scheduler: Box::new(scheduler),
-            verifier: Box::new(verifier),
-            io: Box::new(io),
-        }
+            Box::new(clock),
+            Box::new(scheduler),
+            Box::new(verifier),
+            Box::new(io),
+        )
     }
 
-    /// Constructs a new light client from boxed components
+    /// Constructs a new light client from boxed components, failing if the options
+    /// are invalid (see [`Options::validate`])
     pub fn from_boxed(
         peer: PeerId,
         options: Options,
@@ -78,27 +196,164 @@ impl LightClient {
         scheduler: Box<dyn Scheduler>,
         verifier: Box<dyn Verifier>,
         io: Box<dyn Io>,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, BuilderError> {
+        options.validate().map_err(BuilderError::invalid_options)?;
+
+        Ok(Self {
             peer,
             options,
             clock,
             scheduler,
             verifier,
             io,
-        }
+            telemetry: Box::new(NoopTelemetry),
+        })
+    }
+
+    /// The I/O component this light client fetches light blocks with.
+    pub fn io(&self) -> &dyn Io {
+        self.io.as_ref()
+    }
+
+    /// Replace the telemetry component, which does nothing by default.
+    pub fn with_telemetry(mut self, telemetry: impl Telemetry + 'static) -> Self {
+        self.telemetry = Box::new(telemetry);
+        self
+    }
+
+    /// Wrap the telemetry component, eg. to observe the fetches of this light client
+    /// while still reporting them to the telemetry given by the user.
+    pub(crate) fn wrap_telemetry(
+        &mut self,
+        wrap: impl FnOnce(Box<dyn Telemetry>) -> Box<dyn Telemetry>,
+    ) {
+        let telemetry = core::mem::replace(&mut self.telemetry, Box::new(NoopTelemetry));
+        self.telemetry = wrap(telemetry);
+    }
+
+    /// Fetch a light block with the I/O component, reporting it to the telemetry.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(peer = %self.peer, height = ?height),
+            err(level = "warn"),
+        )
+    )]
+    fn fetch(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        let start = Instant::now();
+        let result = self.io.fetch_light_block(height);
+        self.telemetry
+            .fetched(self.peer, height, start.elapsed(), result.as_ref());
+
+        result
+    }
+
+    /// Fetch a light block with the given asynchronous I/O component,
+    /// reporting it to the telemetry.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(peer = %self.peer, height = ?height),
+            err(level = "warn"),
+        )
+    )]
+    async fn fetch_async(&self, height: AtHeight, io: &dyn AsyncIo) -> Result<LightBlock, IoError> {
+        let start = Instant::now();
+        let result = io.fetch_light_block(height).await;
+        self.telemetry
+            .fetched(self.peer, height, start.elapsed(), result.as_ref());
+
+        result
+    }
+
+    /// Start building a new light client, see [`Builder`].
+    pub fn builder() -> Builder {
+        Builder::default()
     }
 
     /// Attempt to update the light client to the highest block of the primary node.
     ///
+    /// The highest block is only queried once: verification then proceeds to its height,
+    /// even if the chain keeps growing in the meantime.
+    ///
     /// Note: This function delegates the actual work to `verify_to_target`.
     pub fn verify_to_highest(&mut self, state: &mut State) -> Result<LightBlock, Error> {
+        let target_block = self.fetch(AtHeight::Highest).map_err(Error::io)?;
+
+        self.verify_to_target(target_block.height(), state)
+    }
+
+    /// Attempt to update the light client to the highest block of the primary node,
+    /// fetching light blocks with the given asynchronous I/O component.
+    ///
+    /// See [`LightClient::verify_to_highest`].
+    pub async fn verify_to_highest_async(
+        &self,
+        state: &mut State,
+        io: &dyn AsyncIo,
+    ) -> Result<LightBlock, Error> {
         let target_block = self
-            .io
-            .fetch_light_block(AtHeight::Highest)
+            .fetch_async(AtHeight::Highest, io)
+            .await
             .map_err(Error::io)?;
 
-        self.verify_to_target(target_block.height(), state)
+        self.verify_to_target_async(target_block.height(), state, io)
+            .await
+    }
+
+    /// Time left until the latest trusted light block expires, ie. gets older than
+    /// the trusting period, after which it cannot be used to verify other light blocks
+    /// anymore and the light client has to be bootstrapped again.
+    ///
+    /// Returns `None` if there is no trusted light block, and [`Duration::ZERO`]
+    /// if it has expired already.
+    pub fn time_until_expiry(&self, state: &State) -> Option<Duration> {
+        let trusted_block = state.light_store.highest_trusted_or_verified()?;
+
+        Some(trusting_period_remaining(
+            trusted_block.time(),
+            self.options.trusting_period,
+            self.clock.now(),
+        ))
+    }
+
+    /// Take a snapshot of the given state, to be restored with [`LightClient::restore`].
+    pub fn snapshot(&self, state: &State) -> StateSnapshot {
+        state.snapshot()
+    }
+
+    /// Restore the given snapshot into the given light store, marking the light blocks
+    /// which have left the trusting period since the snapshot was taken as unverified,
+    /// so that they are verified again rather than trusted.
+    pub fn restore(
+        &self,
+        snapshot: StateSnapshot,
+        light_store: impl LightStore + 'static,
+    ) -> State {
+        snapshot.restore(light_store, self.options.trusting_period, self.clock.now())
+    }
+
+    /// Verify to the highest block of the primary node if refreshing is enabled,
+    /// and the latest trusted light block expires within the `refresh_margin`
+    /// set in the options, returning the newly trusted light block if so.
+    ///
+    /// This is meant to be called periodically, eg. from a background task,
+    /// to keep the trusted state of an otherwise idle light client from expiring.
+    pub fn refresh_if_expiring(&mut self, state: &mut State) -> Result<Option<LightBlock>, Error> {
+        let Some(refresh_margin) = self.options.refresh_margin else {
+            return Ok(None);
+        };
+
+        match self.time_until_expiry(state) {
+            Some(time_left) if time_left <= refresh_margin => {
+                self.verify_to_highest(state).map(Some)
+            },
+            _ => Ok(None),
+        }
     }
 
     /// Update the light client to a block of the primary node at the given height.
@@ -114,6 +369,17 @@ impl LightClient {
     /// - When doing _backward_ verification, the Hasher component is used to determine whether the
     ///   `last_block_id` hash of a block matches the hash of the block right below it.
     ///
+    /// The steps of forward verification are recorded in the `bisection_trace` of the state,
+    /// which is cleared beforehand.
+    ///
+    /// Forward verification starts from the highest trusted or verified block below the
+    /// target height in the light store, so that with a persistent light store, verification
+    /// interrupted halfway through resumes from the blocks it verified before.
+    ///
+    /// If the light store already holds a verified block at the target height, it is returned
+    /// right away, without fetching anything from the primary node, as long as it is still
+    /// within the trusting period.
+    ///
     /// ## Implements
     /// - [LCV-DIST-SAFE.1]
     /// - [LCV-DIST-LIFE.1]
@@ -129,6 +395,8 @@ impl LightClient {
     /// - The light store does not contains a trusted light block within the trusting period
     ///   [LCV-PRE-TP.1]
     /// - If the core verification loop invariant is violated [LCV-INV-TP.1]
+    /// - If the light store contains a verified block at the target height which is outside
+    ///   of the trusting period
     /// - If verification of a light block fails
     /// - If the fetching a light block from the primary node fails
     #[allow(clippy::nonminimal_bool)]
@@ -138,17 +406,131 @@ impl LightClient {
             target_height,
         )
     )]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(peer = %self.peer, target_height = %target_height),
+            err(level = "warn"),
+        )
+    )]
     pub fn verify_to_target(
         &self,
         target_height: Height,
         state: &mut State,
     ) -> Result<LightBlock, Error> {
+        state.bisection_trace.clear();
+
         // Let's first look in the store to see whether
         // we have already successfully verified this block.
-        if let Some(light_block) = state.light_store.get_trusted_or_verified(target_height) {
+        if let Some(light_block) = self.get_verified_from_store(target_height, state)? {
             return Ok(light_block);
         }
 
+        if self.is_forward(target_height, state)? {
+            // Perform forward verification with bisection
+            self.verify_forward(target_height, state)
+        } else {
+            // Perform sequential backward verification
+            self.verify_backward(target_height, state)
+        }
+    }
+
+    /// Update the light client to a block of the primary node at the given height,
+    /// fetching light blocks with the given asynchronous I/O component.
+    ///
+    /// This is the asynchronous counterpart of [`LightClient::verify_to_target`],
+    /// and performs exactly the same verification steps. The I/O component of
+    /// the light client itself is not used.
+    ///
+    /// The returned future can be dropped at any time, eg. to abort verification:
+    /// the light store is only updated once a light block has been fetched, so that
+    /// it is left as it was after the last completed step, from which verification
+    /// can later be resumed. See also [`LightClient::verify_to_target_cancellable`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(peer = %self.peer, target_height = %target_height),
+            err(level = "warn"),
+        )
+    )]
+    pub async fn verify_to_target_async(
+        &self,
+        target_height: Height,
+        state: &mut State,
+        io: &dyn AsyncIo,
+    ) -> Result<LightBlock, Error> {
+        state.bisection_trace.clear();
+
+        // Let's first look in the store to see whether
+        // we have already successfully verified this block.
+        if let Some(light_block) = self.get_verified_from_store(target_height, state)? {
+            return Ok(light_block);
+        }
+
+        if self.is_forward(target_height, state)? {
+            // Perform forward verification with bisection
+            self.verify_forward_async(target_height, state, io).await
+        } else {
+            // Perform sequential backward verification
+            self.verify_backward_async(target_height, state, io).await
+        }
+    }
+
+    /// Update the light client to a block of the primary node at the given height,
+    /// fetching light blocks with the given asynchronous I/O component, unless the
+    /// given `cancelled` future completes first.
+    ///
+    /// Upon cancellation, any in-flight fetch is abandoned and this fails with
+    /// a [`Cancelled`](crate::errors::ErrorDetail::Cancelled) error, leaving the
+    /// light store in a consistent state, see [`LightClient::verify_to_target_async`].
+    /// The `cancelled` future can for instance be a oneshot receiver, or the
+    /// `cancelled` future of a cancellation token.
+    pub async fn verify_to_target_cancellable(
+        &self,
+        target_height: Height,
+        state: &mut State,
+        io: &dyn AsyncIo,
+        cancelled: impl Future<Output = ()>,
+    ) -> Result<LightBlock, Error> {
+        let verification = self.verify_to_target_async(target_height, state, io);
+
+        futures::pin_mut!(verification, cancelled);
+
+        match future::select(verification, cancelled).await {
+            Either::Left((result, _)) => result,
+            Either::Right(((), _)) => Err(Error::cancelled()),
+        }
+    }
+
+    /// Get the block at the given height from the light store if it has already been
+    /// verified, without fetching anything from the primary.
+    ///
+    /// Fails if that block is now outside of the trusting period, as it cannot be
+    /// trusted anymore, nor verified again against an older trusted state.
+    fn get_verified_from_store(
+        &self,
+        target_height: Height,
+        state: &State,
+    ) -> Result<Option<LightBlock>, Error> {
+        let Some(light_block) = state.light_store.get_trusted_or_verified(target_height) else {
+            return Ok(None);
+        };
+
+        if !is_within_trust_period(&light_block, self.options.trusting_period, self.clock.now()) {
+            return Err(Error::trusted_state_outside_trusting_period(
+                Box::new(light_block),
+                self.options,
+            ));
+        }
+
+        Ok(Some(light_block))
+    }
+
+    /// Whether verifying the block at the given height requires forward verification,
+    /// ie. whether the target height is at or above the highest trusted state.
+    fn is_forward(&self, target_height: Height, state: &State) -> Result<bool, Error> {
         // Get the highest trusted state
         let highest = state
             .light_store
@@ -156,13 +538,7 @@ impl LightClient {
             .or_else(|| state.light_store.lowest_trusted_or_verified())
             .ok_or_else(Error::no_initial_trusted_state)?;
 
-        if target_height >= highest.height() {
-            // Perform forward verification with bisection
-            self.verify_forward(target_height, state)
-        } else {
-            // Perform sequential backward verification
-            self.verify_backward(target_height, state)
-        }
+        Ok(target_height >= highest.height())
     }
 
     /// Perform forward verification with bisection.
@@ -170,35 +546,36 @@ impl LightClient {
         &self,
         target_height: Height,
         state: &mut State,
+    ) -> Result<LightBlock, Error> {
+        run_sync(self.verify_forward_with(target_height, state, Fetcher::Io))
+    }
+
+    /// Perform forward verification with bisection, fetching light blocks
+    /// with the given asynchronous I/O component.
+    async fn verify_forward_async(
+        &self,
+        target_height: Height,
+        state: &mut State,
+        io: &dyn AsyncIo,
+    ) -> Result<LightBlock, Error> {
+        self.verify_forward_with(target_height, state, Fetcher::AsyncIo(io))
+            .await
+    }
+
+    /// Perform forward verification with bisection, fetching light blocks with the given fetcher.
+    async fn verify_forward_with(
+        &self,
+        target_height: Height,
+        state: &mut State,
+        fetcher: Fetcher<'_>,
     ) -> Result<LightBlock, Error> {
         let mut current_height = target_height;
 
         loop {
             let now = self.clock.now();
 
-            // Get the latest trusted state
-            let trusted_block = state
-                .light_store
-                .highest_trusted_or_verified_before(target_height)
-                .ok_or_else(Error::no_initial_trusted_state)?;
-
-            if target_height < trusted_block.height() {
-                return Err(Error::target_lower_than_trusted_state(
-                    target_height,
-                    trusted_block.height(),
-                ));
-            }
-
-            // Check invariant [LCV-INV-TP.1]
-            if !is_within_trust_period(&trusted_block, self.options.trusting_period, now) {
-                return Err(Error::trusted_state_outside_trusting_period(
-                    Box::new(trusted_block),
-                    self.options,
-                ));
-            }
-
-            // Log the current height as a dependency of the block at the target height
-            state.trace_block(target_height, current_height);
+            let trusted_block =
+                self.forward_trusted_block(target_height, current_height, state, now)?;
 
             // If the trusted state is now at a height equal to the target height, we are done.
             // [LCV-DIST-LIFE.1]
@@ -206,50 +583,206 @@ impl LightClient {
                 return Ok(trusted_block);
             }
 
+            current_height =
+                self.forward_height(target_height, &trusted_block, current_height, state)?;
+
             // Fetch the block at the current height from the light store if already present,
             // or from the primary peer otherwise.
-            let (current_block, status) = self.get_or_fetch_block(current_height, state)?;
+            let (current_block, status) = self
+                .get_or_fetch_block_with(current_height, state, fetcher)
+                .await?;
 
-            // Validate and verify the current block
-            let verdict = self.verifier.verify_update_header(
-                current_block.as_untrusted_state(),
-                trusted_block.as_trusted_state(),
-                &self.options,
+            current_height = self.verify_forward_step(
+                target_height,
+                &trusted_block,
+                &current_block,
+                status,
+                state,
                 now,
-            );
-
-            match verdict {
-                Verdict::Success => {
-                    // Verification succeeded, add the block to the light store with
-                    // the `Verified` status or higher if already trusted.
-                    let new_status = Status::most_trusted(Status::Verified, status);
-                    state.light_store.update(&current_block, new_status);
-
-                    // Log the trusted height as a dependency of the block at the current height
-                    state.trace_block(current_height, trusted_block.height());
-                },
-                Verdict::Invalid(e) => {
-                    // Verification failed, add the block to the light store with `Failed` status,
-                    // and abort.
-                    state.light_store.update(&current_block, Status::Failed);
-
-                    return Err(Error::invalid_light_block(e));
-                },
-                Verdict::NotEnoughTrust(_) => {
-                    // The current block cannot be trusted because of a missing overlap in the
-                    // validator sets. Add the block to the light store with
-                    // the `Unverified` status. This will engage bisection in an
-                    // attempt to raise the height of the highest trusted state
-                    // until there is enough overlap.
-                    state.light_store.update(&current_block, Status::Unverified);
-                },
+            )?;
+        }
+    }
+
+    /// Get the latest trusted state to verify the block at `current_height` against,
+    /// on the way to the block at `target_height`.
+    fn forward_trusted_block(
+        &self,
+        target_height: Height,
+        current_height: Height,
+        state: &mut State,
+        now: Time,
+    ) -> Result<LightBlock, Error> {
+        // Get the latest trusted state
+        let trusted_block = state
+            .light_store
+            .highest_trusted_or_verified_before(target_height)
+            .ok_or_else(Error::no_initial_trusted_state)?;
+
+        if target_height < trusted_block.height() {
+            return Err(Error::target_lower_than_trusted_state(
+                target_height,
+                trusted_block.height(),
+            ));
+        }
+
+        // Check invariant [LCV-INV-TP.1]
+        if !is_within_trust_period(&trusted_block, self.options.trusting_period, now) {
+            return Err(Error::trusted_state_outside_trusting_period(
+                Box::new(trusted_block),
+                self.options,
+            ));
+        }
+
+        // Log the current height as a dependency of the block at the target height
+        state.trace_block(target_height, current_height);
+
+        Ok(trusted_block)
+    }
+
+    /// Check that the current block can be verified against the trusted block.
+    ///
+    /// A trusted block fetched without its next validator set can only be used to verify
+    /// the block right after it, which is then attempted first instead of the target block.
+    /// Fails if the scheduler picks any other block next, as with bisection.
+    fn forward_height(
+        &self,
+        target_height: Height,
+        trusted_block: &LightBlock,
+        current_height: Height,
+        state: &mut State,
+    ) -> Result<Height, Error> {
+        let next_height = trusted_block.height().increment();
+
+        if trusted_block.has_next_validators() || current_height == next_height {
+            Ok(current_height)
+        } else if state.bisection_trace.is_empty() {
+            state.trace_block(target_height, next_height);
+            Ok(next_height)
+        } else {
+            Err(Error::missing_next_validators(
+                trusted_block.height(),
+                current_height,
+            ))
+        }
+    }
+
+    /// Validate and verify the current block against the trusted block, record
+    /// the outcome in the light store, and compute the next height to fetch and verify.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                peer = %self.peer,
+                trusted_height = %trusted_block.height(),
+                height = %current_block.height(),
+                verdict = tracing::field::Empty,
+            ),
+        )
+    )]
+    fn verify_forward_step(
+        &self,
+        target_height: Height,
+        trusted_block: &LightBlock,
+        current_block: &LightBlock,
+        status: Status,
+        state: &mut State,
+        now: Time,
+    ) -> Result<Height, Error> {
+        let current_height = current_block.height();
+
+        // Validate and verify the current block
+        let verdict = self.verifier.verify_update_header(
+            current_block.as_untrusted_state(),
+            trusted_block.as_trusted_state(),
+            &self.options,
+            now,
+        );
+
+        // The current block must also be the child of the trusted block right below it
+        let verdict = if verdict == Verdict::Success
+            && current_height == trusted_block.height().increment()
+        {
+            self.verifier.verify_parent_link(
+                &current_block.signed_header.header,
+                &trusted_block.signed_header.header,
+            )
+        } else {
+            verdict
+        };
+
+        self.telemetry.verified(self.peer, current_block, &verdict);
+        #[cfg(feature = "tracing")]
+        {
+            tracing::Span::current().record("verdict", tracing::field::debug(&verdict));
+            if verdict != Verdict::Success {
+                tracing::warn!(?verdict, "light block was not verified");
             }
+        }
 
-            // Compute the next height to fetch and verify
-            current_height =
-                self.scheduler
-                    .schedule(state.light_store.as_ref(), current_height, target_height);
+        state.bisection_trace.push(BisectionStep {
+            trusted_height: trusted_block.height(),
+            attempted_height: current_height,
+            verdict: verdict.clone(),
+        });
+        let not_enough_trust = matches!(verdict, Verdict::NotEnoughTrust(_));
+
+        match verdict {
+            Verdict::Success => {
+                for warning in self
+                    .verifier
+                    .warnings(current_block.as_untrusted_state(), &self.options)
+                {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(%warning, "light block was verified with a warning");
+                    self.telemetry.warned(self.peer, current_block, &warning);
+                }
+
+                // Verification succeeded, add the block to the light store with
+                // the `Verified` status or higher if already trusted.
+                let new_status = Status::most_trusted(Status::Verified, status);
+                state.light_store.update(current_block, new_status);
+
+                // Log the trusted height as a dependency of the block at the current height
+                state.trace_block(current_height, trusted_block.height());
+            },
+            Verdict::Invalid(e) => {
+                // Verification failed, add the block to the light store with `Failed` status,
+                // and abort.
+                state.light_store.update(current_block, Status::Failed);
+
+                return Err(Error::invalid_light_block(e));
+            },
+            Verdict::NotEnoughTrust(_) => {
+                // The current block cannot be trusted because of a missing overlap in the
+                // validator sets. Add the block to the light store with
+                // the `Unverified` status. This will engage bisection in an
+                // attempt to raise the height of the highest trusted state
+                // until there is enough overlap.
+                state.light_store.update(current_block, Status::Unverified);
+
+                // Abort once bisection took more steps than allowed
+                if state.bisection_depth() > self.options.max_bisection_depth {
+                    return Err(Error::bisection_depth_exceeded(
+                        target_height,
+                        self.options.max_bisection_depth,
+                    ));
+                }
+            },
+        }
+
+        // Compute the next height to fetch and verify
+        let next_height =
+            self.scheduler
+                .schedule(state.light_store.as_ref(), current_height, target_height);
+
+        if not_enough_trust {
+            self.telemetry
+                .bisected(self.peer, current_height, next_height, target_height);
         }
+
+        Ok(next_height)
     }
 
     /// Stub for when "unstable" feature is disabled.
@@ -272,6 +805,18 @@ impl LightClient {
         ))
     }
 
+    /// Stub for when "unstable" feature is disabled.
+    #[doc(hidden)]
+    #[cfg(not(feature = "unstable"))]
+    async fn verify_backward_async(
+        &self,
+        target_height: Height,
+        state: &mut State,
+        _io: &dyn AsyncIo,
+    ) -> Result<LightBlock, Error> {
+        self.verify_backward(target_height, state)
+    }
+
     /// Perform sequential backward verification.
     ///
     /// Backward verification is implemented by taking a sliding window
@@ -296,14 +841,127 @@ impl LightClient {
         target_height: Height,
         state: &mut State,
     ) -> Result<LightBlock, Error> {
-        use tendermint::crypto::default::Sha256;
+        let root = self.backward_root(target_height, state)?;
+        self.verify_backward_from(root, target_height, state)
+    }
+
+    /// Perform sequential backward verification from the given trusted block.
+    ///
+    /// See `verify_backward`.
+    #[cfg(feature = "unstable")]
+    fn verify_backward_from(
+        &self,
+        root: LightBlock,
+        target_height: Height,
+        state: &mut State,
+    ) -> Result<LightBlock, Error> {
+        run_sync(self.verify_backward_with(root, target_height, state, Fetcher::Io))
+    }
+
+    /// Perform sequential backward verification from the given trusted block,
+    /// fetching light blocks with the given fetcher.
+    ///
+    /// See `verify_backward`.
+    #[cfg(feature = "unstable")]
+    async fn verify_backward_with(
+        &self,
+        root: LightBlock,
+        target_height: Height,
+        state: &mut State,
+        fetcher: Fetcher<'_>,
+    ) -> Result<LightBlock, Error> {
+        // Compute a range of `Height`s from `trusted_height - 1` to `target_height`, inclusive.
+        let heights = Height::range(target_height..=root.height()).rev().skip(1);
+
+        let mut latest = root;
+
+        for height in heights {
+            let (current, _status) = self.get_or_fetch_block_with(height, state, fetcher).await?;
+
+            self.verify_backward_link(&latest, &current, state)?;
+
+            latest = current;
+        }
+
+        // We reached the target height.
+        assert_eq!(latest.height(), target_height);
 
+        Ok(latest)
+    }
+
+    /// Verify the block at the given height, below the trusted state, by walking down
+    /// from the lowest trusted or verified block at or above that height, and checking
+    /// that the hash of each block is the `last_block_id` of the block right above it.
+    ///
+    /// Unlike [`LightClient::verify_to_target`], which verifies forward from the highest
+    /// trusted or verified block below the target height if there is one, this only ever
+    /// verifies backward, eg. to check a historical header against a recent trusted one.
+    ///
+    /// ## Error conditions
+    /// - If the light store does not contain any trusted or verified block
+    /// - If all trusted or verified blocks in the light store are below the target height
+    /// - If the block to start from is outside of the trusting period
+    /// - If the hash of a block does not match the `last_block_id` of the block above it
+    /// - If the fetching a light block from the primary node fails
+    #[cfg(feature = "unstable")]
+    pub fn verify_backwards(
+        &self,
+        target_height: Height,
+        state: &mut State,
+    ) -> Result<LightBlock, Error> {
+        let highest = state
+            .light_store
+            .highest_trusted_or_verified()
+            .ok_or_else(Error::no_initial_trusted_state)?;
+
+        let root = [Status::Trusted, Status::Verified]
+            .into_iter()
+            .flat_map(|status| state.light_store.all(status))
+            .filter(|light_block| light_block.height() >= target_height)
+            .min_by_key(LightBlock::height)
+            .ok_or_else(|| {
+                Error::target_higher_than_trusted_state(target_height, highest.height())
+            })?;
+
+        let root = self.check_backward_root(root, target_height)?;
+        self.verify_backward_from(root, target_height, state)
+    }
+
+    /// Perform sequential backward verification, fetching light blocks
+    /// with the given asynchronous I/O component.
+    ///
+    /// See `verify_backward`.
+    #[cfg(feature = "unstable")]
+    async fn verify_backward_async(
+        &self,
+        target_height: Height,
+        state: &mut State,
+        io: &dyn AsyncIo,
+    ) -> Result<LightBlock, Error> {
+        let root = self.backward_root(target_height, state)?;
+        self.verify_backward_with(root, target_height, state, Fetcher::AsyncIo(io))
+            .await
+    }
+
+    /// Get the trusted state to start backward verification from.
+    #[cfg(feature = "unstable")]
+    fn backward_root(&self, target_height: Height, state: &State) -> Result<LightBlock, Error> {
         let root = state
             .light_store
             .highest_trusted_or_verified_before(target_height)
             .or_else(|| state.light_store.lowest_trusted_or_verified())
             .ok_or_else(Error::no_initial_trusted_state)?;
 
+        self.check_backward_root(root, target_height)
+    }
+
+    /// Check that backward verification to the given height can start from the given block.
+    #[cfg(feature = "unstable")]
+    fn check_backward_root(
+        &self,
+        root: LightBlock,
+        target_height: Height,
+    ) -> Result<LightBlock, Error> {
         assert!(root.height() >= target_height);
 
         // Check invariant [LCV-INV-TP.1]
@@ -314,45 +972,59 @@ impl LightClient {
             ));
         }
 
-        // Compute a range of `Height`s from `trusted_height - 1` to `target_height`, inclusive.
-        let range = (target_height.value()..root.height().value()).rev();
-        let heights = range.map(|h| Height::try_from(h).unwrap());
-
-        let mut latest = root;
+        Ok(root)
+    }
 
-        for height in heights {
-            let (current, _status) = self.get_or_fetch_block(height, state)?;
+    /// Check that the `last_block_id` hash of the `latest` block matches
+    /// the hash of the `current` block right below it, and if so trust both.
+    #[cfg(feature = "unstable")]
+    fn verify_backward_link(
+        &self,
+        latest: &LightBlock,
+        current: &LightBlock,
+        state: &mut State,
+    ) -> Result<(), Error> {
+        use tendermint::crypto::default::Sha256;
 
-            let latest_last_block_id = latest
-                .signed_header
-                .header
-                .last_block_id
-                .ok_or_else(|| Error::missing_last_block_id(latest.height()))?;
+        use crate::verifier::errors::VerificationError;
 
-            let current_hash = current.signed_header.header.hash_with::<Sha256>();
+        // Report blocks from another chain as such, rather than as a mismatch of hashes
+        let latest_chain_id = &latest.signed_header.header.chain_id;
+        let current_chain_id = &current.signed_header.header.chain_id;
+        if current_chain_id != latest_chain_id {
+            return Err(Error::invalid_light_block(
+                VerificationError::chain_id_mismatch(
+                    current_chain_id.to_string(),
+                    latest_chain_id.to_string(),
+                )
+                .0,
+            ));
+        }
 
-            if current_hash != latest_last_block_id.hash {
-                return Err(Error::invalid_adjacent_headers(
-                    current_hash,
-                    latest_last_block_id.hash,
-                ));
-            }
+        let latest_last_block_id = latest
+            .signed_header
+            .header
+            .last_block_id
+            .ok_or_else(|| Error::missing_last_block_id(latest.height()))?;
 
-            // `latest` and `current` are linked together by `last_block_id`,
-            // therefore it is not relevant which we verified first.
-            // For consistency, we say that `latest` was verifed using
-            // `current` so that the trace is always pointing down the chain.
-            state.light_store.insert(current.clone(), Status::Trusted);
-            state.light_store.insert(latest.clone(), Status::Trusted);
-            state.trace_block(latest.height(), current.height());
+        let current_hash = current.signed_header.header.hash_with::<Sha256>();
 
-            latest = current;
+        if current_hash != latest_last_block_id.hash {
+            return Err(Error::invalid_adjacent_headers(
+                current_hash,
+                latest_last_block_id.hash,
+            ));
         }
 
-        // We reached the target height.
-        assert_eq!(latest.height(), target_height);
+        // `latest` and `current` are linked together by `last_block_id`,
+        // therefore it is not relevant which we verified first.
+        // For consistency, we say that `latest` was verifed using
+        // `current` so that the trace is always pointing down the chain.
+        state.light_store.insert(current.clone(), Status::Trusted);
+        state.light_store.insert(latest.clone(), Status::Trusted);
+        state.trace_block(latest.height(), current.height());
 
-        Ok(latest)
+        Ok(())
     }
 
     /// Look in the light store for a block from the given peer at the given height,
@@ -375,9 +1047,33 @@ impl LightClient {
             return Ok(block);
         }
 
+        let block = self.fetch(AtHeight::At(height)).map_err(Error::io)?;
+
+        state.light_store.insert(block.clone(), Status::Unverified);
+
+        Ok((block, Status::Unverified))
+    }
+
+    /// Look in the light store for a block at the given height which has not
+    /// previously failed verification, or fetch it with the given asynchronous
+    /// I/O component otherwise.
+    ///
+    /// See [`LightClient::get_or_fetch_block`].
+    pub async fn get_or_fetch_block_async(
+        &self,
+        height: Height,
+        state: &mut State,
+        io: &dyn AsyncIo,
+    ) -> Result<(LightBlock, Status), Error> {
+        let block = state.light_store.get_non_failed(height);
+
+        if let Some(block) = block {
+            return Ok(block);
+        }
+
         let block = self
-            .io
-            .fetch_light_block(AtHeight::At(height))
+            .fetch_async(AtHeight::At(height), io)
+            .await
             .map_err(Error::io)?;
 
         state.light_store.insert(block.clone(), Status::Unverified);
@@ -385,6 +1081,20 @@ impl LightClient {
         Ok((block, Status::Unverified))
     }
 
+    /// Look in the light store for a block at the given height which has not
+    /// previously failed verification, or fetch it with the given fetcher otherwise.
+    async fn get_or_fetch_block_with(
+        &self,
+        height: Height,
+        state: &mut State,
+        fetcher: Fetcher<'_>,
+    ) -> Result<(LightBlock, Status), Error> {
+        match fetcher {
+            Fetcher::Io => self.get_or_fetch_block(height, state),
+            Fetcher::AsyncIo(io) => self.get_or_fetch_block_async(height, state, io).await,
+        }
+    }
+
     /// Get the block at the given height or the latest block from the chain if the given height is
     /// lower than the latest height.
     pub fn get_target_block_or_latest(
@@ -398,16 +1108,13 @@ impl LightClient {
             return Ok(TargetOrLatest::Target(block));
         }
 
-        let block = self.io.fetch_light_block(AtHeight::At(height));
+        let block = self.fetch(AtHeight::At(height));
 
         if let Ok(block) = block {
             return Ok(TargetOrLatest::Target(block));
         }
 
-        let latest = self
-            .io
-            .fetch_light_block(AtHeight::Highest)
-            .map_err(Error::io)?;
+        let latest = self.fetch(AtHeight::Highest).map_err(Error::io)?;
 
         if latest.height() == height {
             Ok(TargetOrLatest::Target(latest))
@@ -417,6 +1124,26 @@ impl LightClient {
     }
 }
 
+/// Where the light blocks missing from the light store are fetched from,
+/// so that verification is implemented once for both kinds of I/O components.
+#[derive(Clone, Copy)]
+enum Fetcher<'a> {
+    /// The I/O component of the light client
+    Io,
+    /// The given asynchronous I/O component
+    AsyncIo(&'a dyn AsyncIo),
+}
+
+/// Run verification with [`Fetcher::Io`] to completion.
+///
+/// The synchronous I/O component blocks rather than suspends, so the future
+/// completes the first time it is polled.
+fn run_sync<T>(verification: impl Future<Output = T>) -> T {
+    verification
+        .now_or_never()
+        .expect("verification with the synchronous I/O component never suspends")
+}
+
 pub enum TargetOrLatest {
     Latest(LightBlock),
     Target(LightBlock),
@@ -1,17 +1,40 @@
 //! State maintained by the light client.
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
 use contracts::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    contracts::is_within_trust_period,
+    errors::Error,
     store::LightStore,
-    verifier::types::{Height, LightBlock},
+    verifier::{
+        errors::VerificationError,
+        types::{Height, LightBlock, Status, Time},
+        Verdict,
+    },
 };
 
 /// Records which blocks were needed to verify a target block, eg. during bisection.
 pub type VerificationTrace = HashMap<Height, HashSet<Height>>;
 
+/// A step of forward verification, ie. the verification of a light block against a trusted one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BisectionStep {
+    /// Height of the trusted light block verified against.
+    pub trusted_height: Height,
+
+    /// Height of the light block attempted to be verified.
+    pub attempted_height: Height,
+
+    /// Outcome of the verification.
+    pub verdict: Verdict,
+}
+
 /// The state managed by the light client.
 #[derive(Debug)]
 pub struct State {
@@ -20,17 +43,143 @@ pub struct State {
 
     /// Records which blocks were needed to verify a target block, eg. during bisection.
     pub verification_trace: VerificationTrace,
+
+    /// Records the steps taken by the latest verification to a target block, in order,
+    /// eg. to understand which path bisection took when verification fails.
+    pub bisection_trace: Vec<BisectionStep>,
+}
+
+/// A one-shot dump of a [`State`], which can be serialized to restore the state later on,
+/// eg. to start up again without verifying light blocks anew.
+///
+/// As opposed to a persistent light store, the snapshot is only taken when asked for,
+/// see [`LightClient::snapshot`](crate::light_client::LightClient::snapshot) and
+/// [`LightClient::restore`](crate::light_client::LightClient::restore).
+///
+/// The bisection trace of the state is left out, as it only pertains to the latest
+/// verification to a target block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    /// The light blocks of the light store along with their status, by increasing height.
+    pub light_blocks: Vec<(LightBlock, Status)>,
+
+    /// Records which blocks were needed to verify a target block, eg. during bisection.
+    pub verification_trace: VerificationTrace,
+}
+
+impl StateSnapshot {
+    /// Restore the snapshot into the given light store, marking the trusted or verified
+    /// light blocks outside of the given trusting period as unverified, so that they are
+    /// verified again before being relied upon.
+    pub fn restore(
+        self,
+        light_store: impl LightStore + 'static,
+        trusting_period: Duration,
+        now: Time,
+    ) -> State {
+        let mut state = State::new(light_store);
+
+        for (light_block, status) in self.light_blocks {
+            let status = match status {
+                Status::Trusted | Status::Verified
+                    if !is_within_trust_period(&light_block, trusting_period, now) =>
+                {
+                    Status::Unverified
+                },
+                status => status,
+            };
+
+            state.light_store.insert(light_block, status);
+        }
+
+        state.verification_trace = self.verification_trace;
+        state
+    }
 }
 
 impl State {
-    /// Create a new state from the given light store with an empty verification trace.
+    /// Create a new state from the given light store with empty traces.
     pub fn new(light_store: impl LightStore + 'static) -> Self {
         Self {
             light_store: Box::new(light_store),
             verification_trace: VerificationTrace::new(),
+            bisection_trace: Vec::new(),
+        }
+    }
+
+    /// Take a snapshot of the light blocks in the light store and of the verification trace.
+    pub fn snapshot(&self) -> StateSnapshot {
+        let mut light_blocks = Status::iter()
+            .iter()
+            .flat_map(|&status| {
+                self.light_store
+                    .all(status)
+                    .map(move |light_block| (light_block, status))
+            })
+            .collect::<Vec<_>>();
+        light_blocks.sort_by_key(|(light_block, status)| (light_block.height(), *status));
+
+        StateSnapshot {
+            light_blocks,
+            verification_trace: self.verification_trace.clone(),
         }
     }
 
+    /// Record the given light block, verified against the highest trusted or verified one,
+    /// as the new highest verified light block, so that the next verification step uses it
+    /// as its trusted state.
+    ///
+    /// If the light block is right after the previous trusted state, its validator set
+    /// must be the next validator set committed to by that state, and becomes the next
+    /// validator set of that state if it was fetched without one.
+    pub fn advance_trusted(&mut self, light_block: LightBlock) -> Result<(), Error> {
+        let (mut trusted_block, trusted_status) = self
+            .light_store
+            .highest_trusted_or_verified()
+            .and_then(|lb| self.light_store.get_non_failed(lb.height()))
+            .ok_or_else(Error::no_initial_trusted_state)?;
+
+        if light_block.height() <= trusted_block.height() {
+            return Err(Error::target_lower_than_trusted_state(
+                light_block.height(),
+                trusted_block.height(),
+            ));
+        }
+
+        if light_block.height() == trusted_block.height().increment() {
+            let trusted_next_validators_hash =
+                trusted_block.signed_header.header.next_validators_hash;
+            let validators_hash = light_block.signed_header.header.validators_hash;
+
+            if validators_hash != trusted_next_validators_hash {
+                let e = VerificationError::next_validator_set_mismatch(
+                    trusted_next_validators_hash,
+                    validators_hash,
+                );
+                return Err(Error::invalid_light_block(e.0));
+            }
+
+            if !trusted_block.has_next_validators() {
+                trusted_block.next_validators = light_block.validators.clone();
+                self.light_store.update(&trusted_block, trusted_status);
+            }
+        }
+
+        self.light_store.update(&light_block, Status::Verified);
+        self.trace_block(light_block.height(), trusted_block.height());
+
+        Ok(())
+    }
+
+    /// Number of bisection steps taken by the latest verification to a target block so far,
+    /// ie. how many light blocks could not be verified for lack of trust.
+    pub fn bisection_depth(&self) -> usize {
+        self.bisection_trace
+            .iter()
+            .filter(|step| matches!(step.verdict, Verdict::NotEnoughTrust(_)))
+            .count()
+    }
+
     /// Record that the block at `height` was needed to verify the block at `target_height`.
     ///
     /// ## Preconditions
@@ -60,4 +209,269 @@ impl State {
         trace.sort_by_key(|lb| lb.height());
         trace
     }
+
+    /// Remove all light blocks lower than the given height from the light store,
+    /// except for the highest trusted one, as per [`LightStore::prune`].
+    ///
+    /// The verification trace is pruned accordingly, so that it does not
+    /// reference any of the removed light blocks.
+    pub fn prune(&mut self, height: Height) {
+        let keep = self
+            .light_store
+            .highest(Status::Trusted)
+            .map(|lb| lb.height());
+        let kept = |h: &Height| *h >= height || Some(*h) == keep;
+
+        self.light_store.prune(height);
+
+        self.verification_trace.retain(|target_height, trace| {
+            trace.retain(kept);
+            kept(target_height)
+        });
+    }
+
+    /// Remove all light blocks outside of the given trusting period from the light store,
+    /// except for the highest trusted one, as per [`State::prune`].
+    pub fn prune_outside_trusting_period(&mut self, trusting_period: Duration, now: Time) {
+        let lowest_within_trusting_period = [Status::Trusted, Status::Verified]
+            .iter()
+            .flat_map(|&status| self.light_store.all(status))
+            .filter(|lb| is_within_trust_period(lb, trusting_period, now))
+            .map(|lb| lb.height())
+            .min();
+
+        match lowest_within_trusting_period {
+            Some(height) => self.prune(height),
+            // Only the highest trusted light block is left
+            None => {
+                if let Some(highest) = self.light_store.highest_trusted_or_verified() {
+                    self.prune(highest.height().increment())
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint_testgen::{Generator, LightChain};
+
+    use super::*;
+    use crate::{
+        errors::ErrorDetail,
+        store::memory::MemoryStore,
+        verifier::{
+            errors::VerificationErrorDetail,
+            types::{Hash, ValidatorSet},
+            Verifier,
+        },
+    };
+
+    /// A state holding a chain of the given length, verified by skipping from the first block.
+    fn state(length: u64) -> (State, Vec<LightBlock>) {
+        let blocks = LightChain::default_with_length(length)
+            .light_blocks
+            .into_iter()
+            .map(|lb| {
+                let tm_lb = lb.generate().unwrap();
+                LightBlock::new(
+                    tm_lb.signed_header,
+                    tm_lb.validators,
+                    tm_lb.next_validators,
+                    tm_lb.provider,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut state = State::new(MemoryStore::new());
+        state.light_store.insert(blocks[0].clone(), Status::Trusted);
+        for block in &blocks[1..] {
+            state.light_store.insert(block.clone(), Status::Verified);
+            state.trace_block(block.height(), blocks[0].height());
+        }
+
+        (state, blocks)
+    }
+
+    fn heights(state: &State) -> Vec<u64> {
+        let mut heights = Status::iter()
+            .iter()
+            .flat_map(|&status| state.light_store.all(status))
+            .map(|lb| lb.height().value())
+            .collect::<Vec<_>>();
+        heights.sort_unstable();
+        heights
+    }
+
+    #[test]
+    fn prune_removes_blocks_and_their_traces() {
+        let (mut state, blocks) = state(6);
+        state.light_store.insert(blocks[3].clone(), Status::Trusted);
+
+        state.prune(blocks[3].height());
+
+        assert_eq!(heights(&state), vec![4, 5, 6]);
+        assert_eq!(state.get_trace(blocks[4].height()), vec![blocks[4].clone()]);
+        assert!(!state.verification_trace.contains_key(&blocks[2].height()));
+        assert!(state
+            .verification_trace
+            .values()
+            .all(|trace| !trace.contains(&blocks[0].height())));
+    }
+
+    #[test]
+    fn prune_keeps_highest_trusted_block() {
+        let (mut state, blocks) = state(4);
+        state.light_store.insert(blocks[1].clone(), Status::Trusted);
+
+        // The verified blocks above the highest trusted one are pruned nonetheless
+        state.prune(10_u32.into());
+
+        assert_eq!(heights(&state), vec![2]);
+        assert_eq!(state.get_trace(blocks[1].height()), vec![blocks[1].clone()]);
+    }
+
+    #[test]
+    fn snapshot_roundtrip() {
+        let (mut state, blocks) = state(4);
+        state.light_store.insert(blocks[3].clone(), Status::Failed);
+        let now = blocks[3].signed_header.header.time;
+
+        let snapshot = state.snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let snapshot = serde_json::from_str::<StateSnapshot>(&json).unwrap();
+        assert_eq!(snapshot, state.snapshot());
+
+        let restored = snapshot.restore(MemoryStore::new(), Duration::from_secs(60), now);
+        assert_eq!(restored.snapshot(), state.snapshot());
+        assert_eq!(heights(&restored), vec![1, 2, 3, 4]);
+        assert_eq!(
+            restored.get_trace(blocks[2].height()),
+            vec![blocks[0].clone(), blocks[2].clone()]
+        );
+        assert_eq!(
+            restored.light_store.get_non_failed(blocks[3].height()),
+            None
+        );
+    }
+
+    #[test]
+    fn restore_marks_blocks_outside_trusting_period_as_unverified() {
+        let (state, blocks) = state(4);
+        // Blocks times are one second apart
+        let now = blocks[3].signed_header.header.time;
+
+        let restored = state
+            .snapshot()
+            .restore(MemoryStore::new(), Duration::from_secs(2), now);
+
+        let status = |block: &LightBlock| {
+            restored
+                .light_store
+                .get_non_failed(block.height())
+                .unwrap()
+                .1
+        };
+        assert_eq!(status(&blocks[0]), Status::Unverified);
+        assert_eq!(status(&blocks[1]), Status::Unverified);
+        assert_eq!(status(&blocks[2]), Status::Verified);
+        assert_eq!(status(&blocks[3]), Status::Verified);
+        assert_eq!(
+            restored.light_store.highest_trusted_or_verified(),
+            Some(blocks[3].clone())
+        );
+    }
+
+    /// Verify the given light block against the highest trusted or verified one.
+    fn verify_step(state: &State, light_block: &LightBlock) -> Verdict {
+        let trusted_block = state.light_store.highest_trusted_or_verified().unwrap();
+        let now = light_block.signed_header.header.time;
+        let options = crate::verifier::options::Options::new(
+            Default::default(),
+            Duration::from_secs(60),
+            Duration::from_secs(1),
+        )
+        .unwrap();
+
+        crate::verifier::ProdVerifier::default().verify_update_header(
+            light_block.as_untrusted_state(),
+            trusted_block.as_trusted_state(),
+            &options,
+            now,
+        )
+    }
+
+    #[test]
+    fn advance_trusted_sequentially() {
+        let (_, mut blocks) = state(3);
+
+        // The first block was fetched without its next validator set
+        let mut trusted_block = blocks[0].clone();
+        trusted_block.next_validators = ValidatorSet::without_proposer(vec![]);
+        let mut state = State::new(MemoryStore::new());
+        state
+            .light_store
+            .insert(trusted_block.clone(), Status::Trusted);
+
+        // The next validator set of the first block is the validator set of the second one
+        assert_eq!(verify_step(&state, &blocks[1]), Verdict::Success);
+        state.advance_trusted(blocks[1].clone()).unwrap();
+
+        let (first, status) = state
+            .light_store
+            .get_non_failed(blocks[0].height())
+            .unwrap();
+        assert_eq!(status, Status::Trusted);
+        assert_eq!(first.next_validators, blocks[1].validators);
+
+        assert_eq!(
+            state.light_store.highest_trusted_or_verified(),
+            Some(blocks[1].clone())
+        );
+
+        // The second step starts from the second block
+        assert_eq!(verify_step(&state, &blocks[2]), Verdict::Success);
+        state.advance_trusted(blocks[2].clone()).unwrap();
+
+        assert_eq!(
+            state.light_store.highest_trusted_or_verified(),
+            Some(blocks[2].clone())
+        );
+        assert_eq!(
+            state.get_trace(blocks[2].height()),
+            vec![blocks[1].clone(), blocks[2].clone()]
+        );
+
+        // Trusted states cannot go backwards
+        let err = state.advance_trusted(blocks[1].clone()).unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::TargetLowerThanTrustedState(_)
+        ));
+
+        // Nor skip to a block whose validators are not the ones committed to
+        let (mut state, _) = self::state(1);
+        blocks[1].signed_header.header.validators_hash = Hash::None;
+        let err = state.advance_trusted(blocks[1].clone()).unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::InvalidLightBlock(e)
+                if matches!(e.source, VerificationErrorDetail::NextValidatorSetMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn prune_outside_trusting_period() {
+        let (mut state, blocks) = state(6);
+        // Blocks times are one second apart
+        let now = blocks[5].signed_header.header.time;
+
+        state.light_store.insert(blocks[4].clone(), Status::Trusted);
+
+        state.prune_outside_trusting_period(Duration::from_secs(3), now);
+        assert_eq!(heights(&state), vec![4, 5, 6]);
+
+        state.prune_outside_trusting_period(Duration::from_secs(0), now);
+        assert_eq!(heights(&state), vec![5]);
+    }
 }
@@ -9,7 +9,7 @@ use std::fmt::Debug;
 
 use crate::{
     utils::std_ext,
-    verifier::types::{Height, LightBlock, Status},
+    verifier::types::{Height, LightBlock, PeerId, Status},
 };
 
 pub mod memory;
@@ -49,7 +49,7 @@ pub trait LightStore: Debug + Send + Sync {
     /// Get the light block of lowest height with the given status.
     fn lowest(&self, status: Status) -> Option<LightBlock>;
 
-    /// Get an iterator of all light blocks with the given status.
+    /// Get an iterator of all light blocks with the given status, by ascending height.
     fn all(&self, status: Status) -> Box<dyn Iterator<Item = LightBlock>>;
 
     /// Get a block at a given height whatever its verification status as long as it hasn't failed
@@ -69,6 +69,25 @@ pub trait LightStore: Debug + Send + Sync {
         })
     }
 
+    /// Record a light block received from a peer other than the primary, eg. a witness,
+    /// so that conflicting blocks at the same height can be kept for fork analysis.
+    ///
+    /// Such blocks are only ever returned by [`LightStore::get_by_peer`]. Stores which do
+    /// not keep track of the provider of their blocks discard them, which is the default.
+    fn insert_from_peer(&mut self, light_block: LightBlock) {
+        let _ = light_block;
+    }
+
+    /// Get the light block at the given height provided by the given peer, if any.
+    ///
+    /// The default implementation only finds the block returned by
+    /// [`LightStore::get_non_failed`], if it was provided by that peer.
+    fn get_by_peer(&self, height: Height, peer: PeerId) -> Option<LightBlock> {
+        self.get_non_failed(height)
+            .map(|(lb, _)| lb)
+            .filter(|lb| lb.provider == peer)
+    }
+
     /// Get the light block of greatest height with the trusted or verified status.
     fn highest_trusted_or_verified(&self) -> Option<LightBlock> {
         let latest_trusted = self.highest(Status::Trusted);
@@ -104,4 +123,22 @@ pub trait LightStore: Debug + Send + Sync {
         self.get(height, Status::Trusted)
             .or_else(|| self.get(height, Status::Verified))
     }
+
+    /// Remove all light blocks lower than the given height, whatever their status,
+    /// except for the highest trusted light block which is always kept.
+    fn prune(&mut self, height: Height) {
+        let keep = self.highest(Status::Trusted).map(|lb| lb.height());
+
+        for &status in Status::iter() {
+            let pruned = self
+                .all(status)
+                .map(|lb| lb.height())
+                .filter(|&h| h < height && Some(h) != keep)
+                .collect::<Vec<_>>();
+
+            for h in pruned {
+                self.remove(h, status);
+            }
+        }
+    }
 }
@@ -4,7 +4,7 @@ use std::collections::{btree_map::Entry::*, BTreeMap};
 
 use crate::{
     store::{LightStore, Status},
-    verifier::types::{Height, LightBlock},
+    verifier::types::{Height, LightBlock, PeerId},
 };
 
 /// Internal entry for the memory store
@@ -24,9 +24,13 @@ impl StoreEntry {
 }
 
 /// Transient in-memory store.
+///
+/// Light blocks are kept ordered by height, so that [`LightStore::all`]
+/// enumerates them by ascending height, deterministically.
 #[derive(Debug, Clone, Default)]
 pub struct MemoryStore {
     store: BTreeMap<Height, StoreEntry>,
+    by_peer: Option<BTreeMap<(Height, PeerId), LightBlock>>,
 }
 
 impl MemoryStore {
@@ -34,6 +38,17 @@ impl MemoryStore {
     pub fn new() -> Self {
         Self {
             store: BTreeMap::new(),
+            by_peer: None,
+        }
+    }
+
+    /// Create a new, empty, in-memory store which additionally keeps every block
+    /// by height and provider, including the ones recorded with
+    /// [`LightStore::insert_from_peer`], for [`LightStore::get_by_peer`].
+    pub fn with_peer_tracking() -> Self {
+        Self {
+            store: BTreeMap::new(),
+            by_peer: Some(BTreeMap::new()),
         }
     }
 }
@@ -48,10 +63,33 @@ impl LightStore for MemoryStore {
     }
 
     fn insert(&mut self, light_block: LightBlock, status: Status) {
+        if let Some(by_peer) = &mut self.by_peer {
+            by_peer.insert(
+                (light_block.height(), light_block.provider),
+                light_block.clone(),
+            );
+        }
+
         self.store
             .insert(light_block.height(), StoreEntry::new(light_block, status));
     }
 
+    fn insert_from_peer(&mut self, light_block: LightBlock) {
+        if let Some(by_peer) = &mut self.by_peer {
+            by_peer.insert((light_block.height(), light_block.provider), light_block);
+        }
+    }
+
+    fn get_by_peer(&self, height: Height, peer: PeerId) -> Option<LightBlock> {
+        match &self.by_peer {
+            Some(by_peer) => by_peer.get(&(height, peer)).cloned(),
+            None => self
+                .get_non_failed(height)
+                .map(|(lb, _)| lb)
+                .filter(|lb| lb.provider == peer),
+        }
+    }
+
     fn remove(&mut self, height: Height, status: Status) {
         if let Occupied(e) = self.store.entry(height) {
             if e.get().status == status {
@@ -66,27 +104,36 @@ impl LightStore for MemoryStore {
 
     fn highest(&self, status: Status) -> Option<LightBlock> {
         self.store
-            .iter()
-            .filter(|(_, e)| e.status == status)
-            .max_by_key(|(&height, _)| height)
-            .map(|(_, e)| e.light_block.clone())
+            .values()
+            .rev()
+            .find(|e| e.status == status)
+            .map(|e| e.light_block.clone())
     }
 
     fn highest_before(&self, height: Height, status: Status) -> Option<LightBlock> {
         self.store
-            .iter()
-            .filter(|(_, e)| e.status == status)
-            .filter(|(h, _)| h <= &&height)
-            .max_by_key(|(&height, _)| height)
-            .map(|(_, e)| e.light_block.clone())
+            .range(..=height)
+            .map(|(_, e)| e)
+            .rev()
+            .find(|e| e.status == status)
+            .map(|e| e.light_block.clone())
     }
 
     fn lowest(&self, status: Status) -> Option<LightBlock> {
         self.store
-            .iter()
-            .filter(|(_, e)| e.status == status)
-            .min_by_key(|(&height, _)| height)
-            .map(|(_, e)| e.light_block.clone())
+            .values()
+            .find(|e| e.status == status)
+            .map(|e| e.light_block.clone())
+    }
+
+    fn prune(&mut self, height: Height) {
+        let keep = self.highest(Status::Trusted).map(|lb| lb.height());
+
+        self.store.retain(|&h, _| h >= height || Some(h) == keep);
+
+        if let Some(by_peer) = &mut self.by_peer {
+            by_peer.retain(|&(h, _), _| h >= height || Some(h) == keep);
+        }
     }
 
     #[allow(clippy::needless_collect)]
@@ -101,3 +148,119 @@ impl LightStore for MemoryStore {
         Box::new(light_blocks.into_iter())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tendermint_testgen::{Generator, LightChain};
+
+    use super::*;
+
+    fn light_blocks(length: u64) -> Vec<LightBlock> {
+        LightChain::default_with_length(length)
+            .light_blocks
+            .into_iter()
+            .map(|lb| {
+                let tm_lb = lb.generate().unwrap();
+                LightBlock::new(
+                    tm_lb.signed_header,
+                    tm_lb.validators,
+                    tm_lb.next_validators,
+                    tm_lb.provider,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn all_returns_blocks_by_ascending_height() {
+        let blocks = light_blocks(5);
+
+        let mut store = MemoryStore::new();
+        for block in blocks.iter().rev() {
+            store.insert(block.clone(), Status::Verified);
+        }
+        store.update(&blocks[2], Status::Trusted);
+
+        assert_eq!(
+            store.all(Status::Verified).collect::<Vec<_>>(),
+            [&blocks[..2], &blocks[3..]].concat()
+        );
+        assert_eq!(
+            store.all(Status::Trusted).collect::<Vec<_>>(),
+            vec![blocks[2].clone()]
+        );
+    }
+
+    #[test]
+    fn finds_highest_and_lowest_blocks_by_status() {
+        let blocks = light_blocks(5);
+
+        let mut store = MemoryStore::new();
+        for (i, block) in blocks.iter().enumerate().rev() {
+            let status = if i % 2 == 0 {
+                Status::Verified
+            } else {
+                Status::Unverified
+            };
+            store.insert(block.clone(), status);
+        }
+
+        assert_eq!(store.highest(Status::Verified), Some(blocks[4].clone()));
+        assert_eq!(store.lowest(Status::Verified), Some(blocks[0].clone()));
+        assert_eq!(store.lowest(Status::Unverified), Some(blocks[1].clone()));
+        assert_eq!(
+            store.highest_before(blocks[3].height(), Status::Verified),
+            Some(blocks[2].clone())
+        );
+        assert_eq!(
+            store.highest_before(blocks[3].height(), Status::Unverified),
+            Some(blocks[3].clone())
+        );
+        assert_eq!(store.highest(Status::Trusted), None);
+
+        store.prune(blocks[2].height());
+        assert_eq!(store.lowest(Status::Verified), Some(blocks[2].clone()));
+        assert_eq!(
+            store.all(Status::Unverified).collect::<Vec<_>>(),
+            vec![blocks[3].clone()]
+        );
+    }
+
+    #[test]
+    fn keeps_blocks_from_several_peers_when_tracking() {
+        let blocks = light_blocks(3);
+        let primary = blocks[1].provider;
+        let witness = PeerId::new([0xAA; 20]);
+
+        let mut conflicting = blocks[1].clone();
+        conflicting.provider = witness;
+        conflicting.signed_header.header.app_hash = Default::default();
+
+        // Without tracking, blocks from other peers are discarded
+        let mut store = MemoryStore::new();
+        store.insert(blocks[1].clone(), Status::Verified);
+        store.insert_from_peer(conflicting.clone());
+        assert_eq!(
+            store.get_by_peer(blocks[1].height(), primary),
+            Some(blocks[1].clone())
+        );
+        assert_eq!(store.get_by_peer(blocks[1].height(), witness), None);
+
+        let mut store = MemoryStore::with_peer_tracking();
+        store.insert(blocks[0].clone(), Status::Verified);
+        store.insert(blocks[1].clone(), Status::Trusted);
+        store.insert_from_peer(conflicting.clone());
+
+        let height = blocks[1].height();
+        assert_eq!(store.get(height, Status::Trusted), Some(blocks[1].clone()));
+        assert_eq!(store.get_by_peer(height, primary), Some(blocks[1].clone()));
+        assert_eq!(
+            store.get_by_peer(height, witness),
+            Some(conflicting.clone())
+        );
+
+        store.prune(blocks[2].height());
+        assert_eq!(store.get_by_peer(blocks[0].height(), primary), None);
+        assert_eq!(store.get_by_peer(height, witness), Some(conflicting));
+    }
+}
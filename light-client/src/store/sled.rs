@@ -54,15 +54,17 @@ impl LightStore for SledStore {
     }
 
     fn update(&mut self, light_block: &LightBlock, status: Status) {
-        let height = light_block.height();
-
-        for other in Status::iter() {
-            if status != *other {
-                self.db(*other).remove(height).ok();
-            }
-        }
+        let others = Status::iter()
+            .iter()
+            .filter(|other| **other != status)
+            .map(|other| self.db(*other))
+            .collect::<Vec<_>>();
 
-        self.db(status).insert(height, light_block).ok();
+        // Move the block to the tree for its new status atomically, so that a crash
+        // cannot leave it either missing or stored with several statuses at once.
+        self.db(status)
+            .insert_exclusive(light_block.height(), light_block, &others)
+            .ok();
     }
 
     fn insert(&mut self, light_block: LightBlock, status: Status) {
@@ -141,19 +143,100 @@ mod tests {
         })
     }
 
+    #[test]
+    fn all_returns_blocks_by_ascending_height() {
+        with_blocks(5, |mut db, blocks| {
+            for block in blocks.iter().rev() {
+                db.insert(block.clone(), Status::Verified);
+            }
+            db.update(&blocks[2], Status::Trusted);
+
+            assert_eq!(
+                db.all(Status::Verified).collect::<Vec<_>>(),
+                [&blocks[..2], &blocks[3..]].concat()
+            );
+            assert_eq!(
+                db.all(Status::Trusted).collect::<Vec<_>>(),
+                vec![blocks[2].clone()]
+            );
+        })
+    }
+
+    #[test]
+    fn update_moves_block_to_new_status() {
+        with_blocks(1, |mut db, blocks| {
+            let block = blocks[0].clone();
+            db.insert(block.clone(), Status::Unverified);
+
+            db.update(&block, Status::Verified);
+            assert_eq!(db.get(block.height(), Status::Unverified), None);
+            assert_eq!(
+                db.get(block.height(), Status::Verified),
+                Some(block.clone())
+            );
+
+            db.update(&block, Status::Trusted);
+            assert_eq!(db.get(block.height(), Status::Verified), None);
+            assert_eq!(db.get(block.height(), Status::Trusted), Some(block));
+        })
+    }
+
+    #[test]
+    fn prune_keeps_highest_trusted_block() {
+        with_blocks(6, |mut db, blocks| {
+            db.insert(blocks[0].clone(), Status::Trusted);
+            db.insert(blocks[1].clone(), Status::Verified);
+            db.insert(blocks[2].clone(), Status::Failed);
+            db.insert(blocks[3].clone(), Status::Trusted);
+            db.insert(blocks[5].clone(), Status::Unverified);
+
+            db.prune(blocks[5].height());
+
+            assert_eq!(
+                db.all(Status::Trusted).collect::<Vec<_>>(),
+                vec![blocks[3].clone()]
+            );
+            assert_eq!(db.all(Status::Verified).count(), 0);
+            assert_eq!(db.all(Status::Failed).count(), 0);
+            assert_eq!(
+                db.all(Status::Unverified).collect::<Vec<_>>(),
+                vec![blocks[5].clone()]
+            );
+        })
+    }
+
+    #[test]
+    fn reopen_persists_blocks() {
+        let tmp_dir = tempdir().unwrap();
+        let blocks = gen_blocks(3);
+
+        {
+            let mut db = SledStore::open(tmp_dir.path()).unwrap();
+            for block in &blocks {
+                db.update(block, Status::Verified);
+            }
+        }
+
+        let db = SledStore::open(tmp_dir.path()).unwrap();
+        assert_eq!(db.all(Status::Verified).collect::<Vec<_>>(), blocks);
+        assert_eq!(db.highest(Status::Verified), blocks.last().cloned());
+    }
+
     fn with_blocks(height: u64, f: impl FnOnce(SledStore, Vec<LightBlock>)) {
         let tmp_dir = tempdir().unwrap();
         let db = SledStore::open(tmp_dir).unwrap();
 
+        f(db, gen_blocks(height))
+    }
+
+    fn gen_blocks(height: u64) -> Vec<LightBlock> {
         let chain = LightChain::default_with_length(height);
-        let blocks = chain
+        chain
             .light_blocks
             .into_iter()
             .map(|lb| lb.generate().unwrap())
             .map(testgen_to_lb)
-            .collect::<Vec<_>>();
-
-        f(db, blocks)
+            .collect::<Vec<_>>()
     }
 
     fn testgen_to_lb(tm_lb: TGLightBlock) -> LightBlock {
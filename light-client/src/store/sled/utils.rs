@@ -6,6 +6,10 @@ use std::marker::PhantomData;
 use std::ops::{Bound, RangeBounds};
 
 use serde::{de::DeserializeOwned, Serialize};
+use sled::{
+    transaction::{ConflictableTransactionError, TransactionError},
+    Transactional,
+};
 
 use crate::{errors::Error, verifier::types::Height};
 
@@ -79,6 +83,40 @@ where
         Ok(())
     }
 
+    /// Insert a value associated with a height within this tree, and remove the
+    /// values associated with the same height within the `others` trees.
+    ///
+    /// This is performed as a single transaction, ie. either all changes are
+    /// persisted, or none of them are.
+    pub fn insert_exclusive(
+        &self,
+        height: Height,
+        value: &V,
+        others: &[&Self],
+    ) -> Result<(), Error> {
+        let key = key_bytes(height);
+        let bytes = serde_cbor::to_vec(&value).map_err(Error::serde_cbor)?;
+
+        let mut trees = vec![&self.tree];
+        trees.extend(others.iter().map(|db| &db.tree));
+
+        trees
+            .transaction(|trees| {
+                for tree in &trees[1..] {
+                    tree.remove(key.as_ref())?;
+                }
+
+                trees[0].insert(key.as_ref(), bytes.as_slice())?;
+
+                Ok::<_, ConflictableTransactionError<sled::Error>>(())
+            })
+            .map_err(|e| match e {
+                TransactionError::Abort(e) | TransactionError::Storage(e) => Error::sled(e),
+            })?;
+
+        Ok(())
+    }
+
     /// Remove the value associated with a height within this tree
     pub fn remove(&self, height: Height) -> Result<(), Error> {
         let key = key_bytes(height);
@@ -0,0 +1,1158 @@
+//! Supervisor coordinating a primary light client instance with its witnesses.
+
+use core::{fmt, time::Duration};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Instant,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    builder::TrustAnchor,
+    components::{
+        fork_detector::{
+            Fork, ForkDetection, ForkDetector, QuorumOutcome, WitnessQuorum, WitnessResponses,
+        },
+        io::{AtHeight, Io, IoError},
+        telemetry::Telemetry,
+    },
+    errors::{Error, ErrorDetail},
+    evidence::make_evidence,
+    instance::Instance,
+    verifier::{
+        types::{Height, LightBlock, PeerId, Time},
+        Verdict, VerificationWarning,
+    },
+};
+
+/// Summary of the health of a [`Supervisor`], as returned by [`Supervisor::status`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightClientStatus {
+    /// Height of the latest trusted light block of the primary, if any.
+    pub latest_trusted_height: Option<Height>,
+    /// Time of the latest trusted light block of the primary, if any.
+    pub latest_trusted_time: Option<Time>,
+    /// Time left until the latest trusted light block of the primary expires, if any.
+    pub time_until_expiry: Option<Duration>,
+    /// The peer of the primary instance.
+    pub primary: PeerId,
+    /// The number of witness instances left in the pool.
+    pub witnesses: usize,
+    /// The score of the primary and of each witness left in the pool.
+    #[serde(default)]
+    pub scores: BTreeMap<PeerId, PeerScore>,
+    /// The trust anchor the primary instance was bootstrapped from, if any.
+    #[serde(default)]
+    pub trust_anchor: Option<TrustAnchor>,
+}
+
+/// Fetches and failures recorded for a peer, as returned by [`Supervisor::scores`].
+///
+/// Fetches are those made by the light client of the corresponding [`Instance`],
+/// and by the supervisor itself, eg. when cross-checking a light block.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerScore {
+    /// The number of successful fetches.
+    pub successes: u64,
+    /// The number of failed fetches and of invalid light blocks provided by the peer.
+    pub failures: u64,
+    /// Moving average of the latency of the fetches, weighing recent ones more,
+    /// if any fetch completed.
+    pub average_latency: Option<Duration>,
+}
+
+impl PeerScore {
+    /// The proportion of failures among all the recorded requests,
+    /// or zero if none was recorded yet.
+    pub fn error_rate(&self) -> f64 {
+        match self.successes + self.failures {
+            0 => 0.0,
+            total => self.failures as f64 / total as f64,
+        }
+    }
+
+    /// Whether this peer is preferable to the other one, ie. if it has a lower error rate,
+    /// or the same error rate and a lower average latency.
+    pub fn is_better_than(&self, other: &PeerScore) -> bool {
+        let (error_rate, other_error_rate) = (self.error_rate(), other.error_rate());
+        if error_rate != other_error_rate {
+            return error_rate < other_error_rate;
+        }
+
+        match (self.average_latency, other.average_latency) {
+            (Some(latency), Some(other_latency)) => latency < other_latency,
+            _ => false,
+        }
+    }
+
+    fn record_fetch(&mut self, latency: Duration, success: bool) {
+        if success {
+            self.successes += 1;
+        } else {
+            self.failures += 1;
+        }
+
+        self.average_latency = Some(match self.average_latency {
+            Some(average) => (average * 3 + latency) / 4,
+            None => latency,
+        });
+    }
+
+    fn record_failure(&mut self) {
+        self.failures += 1;
+    }
+}
+
+/// When the [`Supervisor`] swaps its primary for the best-scoring witness,
+/// see [`PeerScore::is_better_than`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RotationPolicy {
+    /// The number of consecutive verifications which may fail because of the primary,
+    /// being unreachable or providing an invalid light block, before it is replaced.
+    ///
+    /// Verification is attempted again with the same primary until then.
+    /// Defaults to 1, ie. the primary is replaced as soon as it fails.
+    pub max_failures: usize,
+    /// The number of successful verifications after which the primary is swapped for
+    /// the best-scoring witness, if the latter scores better, the former primary
+    /// joining the pool of witnesses.
+    ///
+    /// Disabled by default.
+    pub rotate_every: Option<usize>,
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        Self {
+            max_failures: 1,
+            rotate_every: None,
+        }
+    }
+}
+
+/// Scores of the peers of a supervisor, shared with the telemetry of their instances.
+#[derive(Clone, Debug, Default)]
+struct Scores(Arc<Mutex<BTreeMap<PeerId, PeerScore>>>);
+
+impl Scores {
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<PeerId, PeerScore>> {
+        // Scores are always left in a consistent state, even if a thread panicked
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn get(&self, peer: &PeerId) -> PeerScore {
+        self.lock().get(peer).cloned().unwrap_or_default()
+    }
+
+    fn record_fetch(&self, peer: PeerId, latency: Duration, success: bool) {
+        self.lock()
+            .entry(peer)
+            .or_default()
+            .record_fetch(latency, success);
+    }
+
+    fn record_failure(&self, peer: PeerId) {
+        self.lock().entry(peer).or_default().record_failure();
+    }
+}
+
+/// Telemetry recording the fetches of a light client in the scores of its peer,
+/// before reporting them to the wrapped telemetry.
+struct ScoringTelemetry {
+    scores: Scores,
+    telemetry: Box<dyn Telemetry>,
+}
+
+impl Telemetry for ScoringTelemetry {
+    fn fetched(
+        &self,
+        peer: PeerId,
+        height: AtHeight,
+        latency: Duration,
+        result: Result<&LightBlock, &IoError>,
+    ) {
+        self.scores.record_fetch(peer, latency, result.is_ok());
+        self.telemetry.fetched(peer, height, latency, result);
+    }
+
+    fn verified(&self, peer: PeerId, light_block: &LightBlock, verdict: &Verdict) {
+        self.telemetry.verified(peer, light_block, verdict);
+    }
+
+    fn warned(&self, peer: PeerId, light_block: &LightBlock, warning: &VerificationWarning) {
+        self.telemetry.warned(peer, light_block, warning);
+    }
+
+    fn bisected(
+        &self,
+        peer: PeerId,
+        current_height: Height,
+        next_height: Height,
+        target_height: Height,
+    ) {
+        self.telemetry
+            .bisected(peer, current_height, next_height, target_height);
+    }
+}
+
+/// I/O recording the fetches made by the supervisor itself in the scores of the peer.
+struct ScoringIo<'a> {
+    peer: PeerId,
+    io: &'a dyn Io,
+    scores: &'a Scores,
+}
+
+impl Io for ScoringIo<'_> {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        let start = Instant::now();
+        let result = self.io.fetch_light_block(height);
+        self.scores
+            .record_fetch(self.peer, start.elapsed(), result.is_ok());
+
+        result
+    }
+}
+
+/// The supervisor verifies light blocks with a primary light client [`Instance`],
+/// and cross-checks each of them against a pool of witness instances, one per peer.
+///
+/// - If the primary is unreachable or provides an invalid light block, it is replaced
+///   by the best-scoring witness and verification is attempted again.
+/// - If a witness is unreachable, or provides a conflicting light block which fails
+///   verification, it is removed from the pool.
+/// - If a quorum of witnesses provide a conflicting light block which passes verification,
+///   the primary is replaced by the first of them and verification fails with
+///   [`ErrorDetail::AttackDetected`], carrying the evidence against the primary.
+/// - If a quorum of witnesses agree with the primary, the light block is trusted and
+///   the witnesses which provided a conflicting light block are removed from the pool.
+/// - Otherwise, verification fails with [`ErrorDetail::InsufficientWitnesses`].
+///
+/// The quorum is set with [`Supervisor::with_quorum`], and defaults to a single witness,
+/// see [`WitnessQuorum`] and [`WitnessQuorum::classify`].
+///
+/// The supervisor keeps a [`PeerScore`] for each peer, and replaces the primary by the
+/// best-scoring witness, following the [`RotationPolicy`] set with
+/// [`Supervisor::with_rotation_policy`].
+pub struct Supervisor {
+    primary: Instance,
+    witnesses: Vec<Instance>,
+    fork_detector: Box<dyn ForkDetector>,
+    quorum: WitnessQuorum,
+    required_witnesses: usize,
+    rotation_policy: RotationPolicy,
+    scores: Scores,
+    primary_failures: usize,
+    verifications_since_rotation: usize,
+}
+
+impl fmt::Debug for Supervisor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Supervisor")
+            .field("primary", &self.primary)
+            .field("witnesses", &self.witnesses)
+            .field("quorum", &self.quorum)
+            .field("required_witnesses", &self.required_witnesses)
+            .field("rotation_policy", &self.rotation_policy)
+            .finish()
+    }
+}
+
+impl Supervisor {
+    /// Constructs a new supervisor, failing if no witness is given.
+    pub fn new(
+        mut primary: Instance,
+        mut witnesses: Vec<Instance>,
+        fork_detector: impl ForkDetector + 'static,
+    ) -> Result<Self, Error> {
+        if witnesses.is_empty() {
+            return Err(Error::no_witnesses());
+        }
+
+        let scores = Scores::default();
+        for instance in std::iter::once(&mut primary).chain(&mut witnesses) {
+            let scores = scores.clone();
+            instance
+                .light_client
+                .wrap_telemetry(|telemetry| Box::new(ScoringTelemetry { scores, telemetry }));
+        }
+
+        Ok(Self {
+            primary,
+            witnesses,
+            fork_detector: Box::new(fork_detector),
+            quorum: WitnessQuorum::default(),
+            required_witnesses: 1,
+            rotation_policy: RotationPolicy::default(),
+            scores,
+            primary_failures: 0,
+            verifications_since_rotation: 0,
+        })
+    }
+
+    /// Sets the number of witnesses which must agree with the primary for
+    /// a light block to be trusted, which defaults to a single witness.
+    pub fn with_quorum(mut self, quorum: WitnessQuorum) -> Self {
+        self.quorum = quorum;
+        self
+    }
+
+    /// Sets the number of witnesses which must respond when cross-checking a light block,
+    /// whether they agree with the primary or not, for the light block to be trusted.
+    ///
+    /// This defaults to a single witness, and is checked on top of the quorum.
+    pub fn with_required_witnesses(mut self, required_witnesses: usize) -> Self {
+        self.required_witnesses = required_witnesses;
+        self
+    }
+
+    /// Sets when the primary is swapped for the best-scoring witness,
+    /// see [`RotationPolicy`].
+    pub fn with_rotation_policy(mut self, rotation_policy: RotationPolicy) -> Self {
+        self.rotation_policy = rotation_policy;
+        self
+    }
+
+    /// The current primary instance.
+    pub fn primary(&self) -> &Instance {
+        &self.primary
+    }
+
+    /// The witness instances left in the pool.
+    pub fn witnesses(&self) -> &[Instance] {
+        &self.witnesses
+    }
+
+    /// The score of the primary and of each witness left in the pool.
+    pub fn scores(&self) -> BTreeMap<PeerId, PeerScore> {
+        std::iter::once(&self.primary)
+            .chain(&self.witnesses)
+            .map(|instance| (*instance.peer_id(), self.scores.get(instance.peer_id())))
+            .collect()
+    }
+
+    /// Summary of the health of this supervisor, eg. to be exposed by a health check.
+    ///
+    /// This only reads the state of the primary, and does not perform any I/O.
+    pub fn status(&self) -> LightClientStatus {
+        let latest_trusted = self.primary.state.light_store.highest_trusted_or_verified();
+
+        LightClientStatus {
+            latest_trusted_height: latest_trusted.as_ref().map(LightBlock::height),
+            latest_trusted_time: latest_trusted.as_ref().map(LightBlock::time),
+            time_until_expiry: self.time_until_expiry(),
+            primary: *self.primary.peer_id(),
+            witnesses: self.witnesses.len(),
+            scores: self.scores(),
+            trust_anchor: self.primary.trust_anchor(),
+        }
+    }
+
+    /// Verify to the highest block of the primary, and cross-check it against the witnesses.
+    pub fn verify_to_highest(&mut self) -> Result<LightBlock, Error> {
+        self.verify(None)
+    }
+
+    /// Verify to the block at the given height, and cross-check it against the witnesses.
+    pub fn verify_to_target(&mut self, height: Height) -> Result<LightBlock, Error> {
+        self.verify(Some(height))
+    }
+
+    /// Time left until the latest trusted block of the primary expires.
+    ///
+    /// See [`LightClient::time_until_expiry`](crate::light_client::LightClient::time_until_expiry).
+    pub fn time_until_expiry(&self) -> Option<Duration> {
+        self.primary
+            .light_client
+            .time_until_expiry(&self.primary.state)
+    }
+
+    /// Verify to the highest block of the primary, and cross-check it against the witnesses,
+    /// if refreshing is enabled in the options of the primary and its latest trusted block
+    /// expires within the refresh margin, returning the newly trusted light block if so.
+    ///
+    /// See [`LightClient::refresh_if_expiring`](crate::light_client::LightClient::refresh_if_expiring).
+    pub fn refresh_if_expiring(&mut self) -> Result<Option<LightBlock>, Error> {
+        let Some(refresh_margin) = self.primary.light_client.options.refresh_margin else {
+            return Ok(None);
+        };
+
+        match self.time_until_expiry() {
+            Some(time_left) if time_left <= refresh_margin => self.verify_to_highest().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    fn verify(&mut self, height: Option<Height>) -> Result<LightBlock, Error> {
+        loop {
+            // The last block trusted by all instances, before verification
+            let common = self.primary.latest_trusted();
+
+            match self.verify_with_primary(height) {
+                Ok(verified_block) => {
+                    self.primary_failures = 0;
+                    let verified_block = self.cross_check(verified_block, common)?;
+                    self.rotate_if_due();
+                    return Ok(verified_block);
+                },
+                Err(e) if is_primary_faulty(&e) => {
+                    if matches!(e.detail(), ErrorDetail::InvalidLightBlock(_)) {
+                        self.scores.record_failure(*self.primary.peer_id());
+                    }
+
+                    self.primary_failures += 1;
+                    if self.primary_failures >= self.rotation_policy.max_failures {
+                        self.replace_primary()?;
+                    }
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn verify_with_primary(&mut self, height: Option<Height>) -> Result<LightBlock, Error> {
+        let primary = &mut self.primary;
+
+        let height = match height {
+            Some(height) => height,
+            None => ScoringIo {
+                peer: *primary.peer_id(),
+                io: primary.light_client.io(),
+                scores: &self.scores,
+            }
+            .fetch_light_block(AtHeight::Highest)
+            .map_err(Error::io)?
+            .height(),
+        };
+
+        primary
+            .light_client
+            .verify_to_target(height, &mut primary.state)
+    }
+
+    fn cross_check(
+        &mut self,
+        verified_block: LightBlock,
+        common: Option<LightBlock>,
+    ) -> Result<LightBlock, Error> {
+        let scoring_ios = self
+            .witnesses
+            .iter()
+            .map(|w| ScoringIo {
+                peer: *w.peer_id(),
+                io: w.light_client.io(),
+                scores: &self.scores,
+            })
+            .collect::<Vec<_>>();
+        let witnesses = scoring_ios
+            .iter()
+            .map(|io| -> (PeerId, &dyn Io) { (io.peer, io) })
+            .collect::<Vec<_>>();
+
+        let forks = match self.fork_detector.detect_forks(&verified_block, &witnesses) {
+            ForkDetection::NotDetected => Vec::new(),
+            ForkDetection::Detected(forks) => forks,
+        };
+
+        let mut responses = WitnessResponses {
+            agreeing: witnesses
+                .iter()
+                .map(|(peer, _)| *peer)
+                .filter(|peer| forks.iter().all(|fork| fork.peer() != *peer))
+                .collect(),
+            ..Default::default()
+        };
+        let mut evidence = None;
+
+        for fork in forks {
+            match fork {
+                Fork::Forked { peer, witness, .. } => {
+                    let instance = self.witness_mut(peer);
+                    let verified_witness_block = instance
+                        .light_client
+                        .verify_to_target(witness.height(), &mut instance.state);
+
+                    match verified_witness_block {
+                        Ok(witness_block) => {
+                            if evidence.is_none() {
+                                evidence = common.as_ref().map(|common| {
+                                    make_evidence(
+                                        verified_block.clone(),
+                                        witness_block,
+                                        common.clone(),
+                                    )
+                                });
+                            }
+                            responses.conflicting.push(peer);
+                        },
+                        Err(_) => responses.invalid.push(peer),
+                    }
+                },
+                Fork::Faulty { peer, error } if error.detail().is_invalid_response() => {
+                    responses.invalid.push(peer)
+                },
+                Fork::Faulty { peer, .. } => responses.unresponsive.push(peer),
+            }
+        }
+
+        let outcome = self.quorum.classify(&responses);
+        let responsive = responses.len() - responses.unresponsive.len();
+
+        for peer in &responses.invalid {
+            self.scores.record_failure(*peer);
+        }
+
+        let mut faulty_witnesses = [responses.invalid, responses.unresponsive].concat();
+        if let QuorumOutcome::WitnessesLied { witnesses } = &outcome {
+            faulty_witnesses.extend(witnesses);
+        }
+
+        self.witnesses
+            .retain(|w| !faulty_witnesses.contains(w.peer_id()));
+
+        match outcome {
+            QuorumOutcome::PrimaryLied { witnesses } => {
+                let faulty_primary = *self.primary.peer_id();
+                let index = self
+                    .witnesses
+                    .iter()
+                    .position(|w| *w.peer_id() == witnesses[0])
+                    .expect("witness which provided the conflicting block is in the pool");
+
+                self.primary = self.witnesses.remove(index);
+
+                return Err(Error::attack_detected(
+                    faulty_primary,
+                    evidence.into_iter().collect(),
+                ));
+            },
+            _ if self.witnesses.is_empty() => return Err(Error::no_witnesses_left()),
+            _ if responsive < self.required_witnesses => {
+                return Err(Error::not_enough_responsive_witnesses(
+                    responsive,
+                    self.required_witnesses,
+                ));
+            },
+            QuorumOutcome::InsufficientWitnesses { agreeing, required } => {
+                return Err(Error::insufficient_witnesses(agreeing, required));
+            },
+            QuorumOutcome::Confirmed | QuorumOutcome::WitnessesLied { .. } => {},
+        }
+
+        self.primary.trust_block(&verified_block);
+
+        // The witnesses left have provided a light block with the same header hash
+        for witness in &mut self.witnesses {
+            let mut witness_block = verified_block.clone();
+            witness_block.provider = *witness.peer_id();
+            witness.trust_block(&witness_block);
+        }
+
+        Ok(verified_block)
+    }
+
+    fn witness_mut(&mut self, peer: PeerId) -> &mut Instance {
+        self.witnesses
+            .iter_mut()
+            .find(|w| *w.peer_id() == peer)
+            .expect("fork detector only reports peers from the witness pool")
+    }
+
+    /// Replace the primary by the best-scoring witness, the first one among equals.
+    fn replace_primary(&mut self) -> Result<(), Error> {
+        let index = self.best_witness().ok_or_else(Error::no_witnesses_left)?;
+
+        self.primary = self.witnesses.remove(index);
+        self.primary_failures = 0;
+        Ok(())
+    }
+
+    /// Swap the primary for the best-scoring witness if the rotation period has elapsed
+    /// and the latter scores better, the primary and the witnesses trusting the same block.
+    fn rotate_if_due(&mut self) {
+        let Some(rotate_every) = self.rotation_policy.rotate_every else {
+            return;
+        };
+
+        self.verifications_since_rotation += 1;
+        if self.verifications_since_rotation < rotate_every {
+            return;
+        }
+        self.verifications_since_rotation = 0;
+
+        let Some(index) = self.best_witness() else {
+            return;
+        };
+        let primary_score = self.scores.get(self.primary.peer_id());
+        if self
+            .scores
+            .get(self.witnesses[index].peer_id())
+            .is_better_than(&primary_score)
+        {
+            let witness = self.witnesses.remove(index);
+            let former_primary = std::mem::replace(&mut self.primary, witness);
+            self.witnesses.push(former_primary);
+        }
+    }
+
+    /// The index of the best-scoring witness, the first one among equals.
+    fn best_witness(&self) -> Option<usize> {
+        let scores = self
+            .witnesses
+            .iter()
+            .map(|w| self.scores.get(w.peer_id()))
+            .collect::<Vec<_>>();
+
+        (0..scores.len()).reduce(|best, index| {
+            if scores[index].is_better_than(&scores[best]) {
+                index
+            } else {
+                best
+            }
+        })
+    }
+}
+
+/// Whether the primary should be replaced because of this verification error, ie. whether
+/// it could not be reached or provided invalid data, as opposed to errors which any other
+/// peer would report as well, such as a target height above the tip of the chain.
+fn is_primary_faulty(e: &Error) -> bool {
+    match e.detail() {
+        ErrorDetail::Io(e) => e.source.is_transient() || e.source.is_invalid_response(),
+        ErrorDetail::InvalidLightBlock(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(all(test, feature = "testgen"))]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use tendermint_testgen::{light_block::LightBlock as TestgenLightBlock, Generator};
+
+    use super::*;
+    use crate::{
+        components::{fork_detector::ProdForkDetector, io::IoErrorDetail},
+        light_client::LightClient,
+        state::State,
+        store::{memory::MemoryStore, LightStore},
+        tests::{test_options, MockClock, MockIo},
+        verifier::types::{Status, Time},
+    };
+
+    #[test]
+    fn supervisor_is_send() {
+        // Light clients sharing a clock can be supervised from another thread
+        fn assert_send<T: Send>() {}
+        assert_send::<Supervisor>();
+    }
+
+    fn light_block(height: u64, time: i64) -> LightBlock {
+        let time = Time::from_unix_timestamp(time, 0).unwrap();
+        let tm_lb = TestgenLightBlock::new_default_with_time_and_chain_id(
+            "test-chain".to_string(),
+            time,
+            height,
+        )
+        .generate()
+        .unwrap();
+
+        LightBlock::new(
+            tm_lb.signed_header,
+            tm_lb.validators,
+            tm_lb.next_validators,
+            tm_lb.provider,
+        )
+    }
+
+    /// A light block signed by a validator set unrelated to the trusted one.
+    fn untrusted_light_block(height: u64) -> LightBlock {
+        let validators = [tendermint_testgen::Validator::new("untrusted").voting_power(50)];
+        let header = tendermint_testgen::Header::new(&validators)
+            .height(height)
+            .chain_id("test-chain")
+            .time(Time::from_unix_timestamp(height as i64, 0).unwrap());
+        let tm_lb = TestgenLightBlock::new_default_with_header(header)
+            .generate()
+            .unwrap();
+
+        LightBlock::new(
+            tm_lb.signed_header,
+            tm_lb.validators,
+            tm_lb.next_validators,
+            tm_lb.provider,
+        )
+    }
+
+    fn peer_id(byte: u8) -> PeerId {
+        PeerId::new([byte; 20])
+    }
+
+    fn instance(peer: u8, light_blocks: Vec<LightBlock>) -> Instance {
+        instance_with_io(peer, light_blocks, |io| io)
+    }
+
+    /// An instance fetching the given light blocks with the I/O wrapping a `MockIo`.
+    fn instance_with_io<I: Io + 'static>(
+        peer: u8,
+        light_blocks: Vec<LightBlock>,
+        io: impl FnOnce(MockIo) -> I,
+    ) -> Instance {
+        let provided_by_peer = |mut lb: LightBlock| {
+            lb.provider = peer_id(peer);
+            lb
+        };
+        let light_blocks = light_blocks.into_iter().map(provided_by_peer).collect();
+
+        let mut light_store = MemoryStore::new();
+        light_store.insert(provided_by_peer(light_block(1, 1)), Status::Trusted);
+
+        let light_client = LightClient::builder()
+            .primary(peer_id(peer))
+            .options(test_options())
+            .with_clock(MockClock {
+                now: Time::from_unix_timestamp(10, 0).unwrap(),
+            })
+            .with_io(io(MockIo::new(light_blocks)))
+            .build()
+            .unwrap();
+
+        Instance::new(light_client, State::new(light_store))
+    }
+
+    fn honest(peer: u8) -> Instance {
+        instance(peer, vec![light_block(1, 1), light_block(3, 3)])
+    }
+
+    fn supervisor(primary: Instance, witnesses: Vec<Instance>) -> Supervisor {
+        Supervisor::new(primary, witnesses, ProdForkDetector::default()).unwrap()
+    }
+
+    fn peers(supervisor: &Supervisor) -> Vec<PeerId> {
+        supervisor
+            .witnesses()
+            .iter()
+            .map(|w| *w.peer_id())
+            .collect()
+    }
+
+    #[test]
+    fn requires_witnesses() {
+        let result = Supervisor::new(honest(1), vec![], ProdForkDetector::default());
+        assert!(matches!(
+            result.unwrap_err().detail(),
+            ErrorDetail::NoWitnesses(_)
+        ));
+    }
+
+    #[test]
+    fn trusts_block_confirmed_by_witnesses() {
+        let mut supervisor = supervisor(honest(1), vec![honest(2), honest(3)]);
+
+        let verified = supervisor.verify_to_highest().unwrap();
+
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+        assert_eq!(
+            supervisor.primary().latest_trusted(),
+            Some(verified.clone())
+        );
+        for witness in supervisor.witnesses() {
+            let trusted = witness.latest_trusted().unwrap();
+            assert_eq!(trusted.signed_header, verified.signed_header);
+            assert_eq!(trusted.provider, *witness.peer_id());
+        }
+    }
+
+    #[test]
+    fn reports_status() {
+        let mut supervisor = supervisor(honest(1), vec![honest(2), honest(3)]);
+        let trusting_period = supervisor.primary().light_client.options.trusting_period;
+
+        let status = supervisor.status();
+        assert_eq!(
+            status,
+            LightClientStatus {
+                latest_trusted_height: Some(1_u32.into()),
+                latest_trusted_time: Some(Time::from_unix_timestamp(1, 0).unwrap()),
+                time_until_expiry: Some(trusting_period - Duration::from_secs(9)),
+                primary: peer_id(1),
+                witnesses: 2,
+                scores: [1, 2, 3]
+                    .into_iter()
+                    .map(|peer| (peer_id(peer), PeerScore::default()))
+                    .collect(),
+                trust_anchor: None,
+            }
+        );
+
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(
+            serde_json::from_str::<LightClientStatus>(&json).unwrap(),
+            status
+        );
+
+        supervisor.verify_to_highest().unwrap();
+
+        let status = supervisor.status();
+        assert_eq!(status.latest_trusted_height, Some(3_u32.into()));
+        assert_eq!(
+            status.latest_trusted_time,
+            Some(Time::from_unix_timestamp(3, 0).unwrap())
+        );
+        assert_eq!(
+            status.time_until_expiry,
+            Some(trusting_period - Duration::from_secs(7))
+        );
+
+        // The primary fetched the highest block, then the one at height 3,
+        // which each witness provided to cross-check it
+        let scores = status.scores;
+        assert_eq!(scores[&peer_id(1)].successes, 2);
+        assert_eq!(scores[&peer_id(2)].successes, 1);
+        assert_eq!(scores[&peer_id(3)].successes, 1);
+        assert!(scores.values().all(|score| score.failures == 0));
+    }
+
+    #[test]
+    fn refreshes_trusted_block_close_to_expiry() {
+        let mut supervisor = supervisor(honest(1), vec![honest(2)]);
+        let trusting_period = supervisor.primary().light_client.options.trusting_period;
+
+        // The trusted block, from time 1, is 9 seconds old
+        assert_eq!(
+            supervisor.time_until_expiry(),
+            Some(trusting_period - Duration::from_secs(9))
+        );
+
+        // Refreshing is disabled by default
+        assert_eq!(supervisor.refresh_if_expiring().unwrap(), None);
+
+        supervisor.primary.light_client.options.refresh_margin = Some(Duration::from_secs(60));
+        assert_eq!(supervisor.refresh_if_expiring().unwrap(), None);
+
+        supervisor.primary.light_client.options.refresh_margin = Some(trusting_period);
+        let refreshed = supervisor.refresh_if_expiring().unwrap().unwrap();
+
+        assert_eq!(refreshed.signed_header, light_block(3, 3).signed_header);
+        assert_eq!(
+            supervisor.time_until_expiry(),
+            Some(trusting_period - Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn replaces_unreachable_primary() {
+        let mut supervisor = supervisor(flaky(1, |_| true), vec![honest(2), honest(3)]);
+
+        let verified = supervisor.verify_to_target(3_u32.into()).unwrap();
+
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(2));
+        assert_eq!(peers(&supervisor), vec![peer_id(3)]);
+    }
+
+    #[test]
+    fn keeps_primary_when_target_is_above_tip() {
+        let mut supervisor = supervisor(honest(1), vec![honest(2), honest(3)]);
+
+        // Every peer would fail to provide the block, the primary is not to blame
+        let error = supervisor.verify_to_target(5_u32.into()).unwrap_err();
+
+        match error.detail() {
+            ErrorDetail::Io(e) => assert!(matches!(e.source, IoErrorDetail::HeightNotFound(_))),
+            e => panic!("unexpected error: {e}"),
+        }
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(1));
+        assert_eq!(peers(&supervisor), vec![peer_id(2), peer_id(3)]);
+
+        let verified = supervisor.verify_to_target(3_u32.into()).unwrap();
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+    }
+
+    #[test]
+    fn removes_faulty_witnesses() {
+        let lying = instance(3, vec![light_block(1, 1), untrusted_light_block(3)]);
+        let unreachable = instance(4, vec![light_block(1, 1)]);
+        let mut supervisor = supervisor(honest(1), vec![honest(2), lying, unreachable]);
+
+        let verified = supervisor.verify_to_target(3_u32.into()).unwrap();
+
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(1));
+        assert_eq!(peers(&supervisor), vec![peer_id(2)]);
+    }
+
+    #[test]
+    fn replaces_forked_primary_and_returns_evidence() {
+        let forked = instance(1, vec![light_block(1, 1), light_block(3, 4)]);
+        let mut supervisor = supervisor(forked, vec![honest(2), honest(3)]);
+
+        let error = supervisor.verify_to_target(3_u32.into()).unwrap_err();
+
+        match error.detail() {
+            ErrorDetail::AttackDetected(e) => {
+                assert_eq!(e.primary, peer_id(1));
+                assert_eq!(e.evidence.len(), 1);
+                assert_eq!(
+                    e.evidence[0].conflicting_block.signed_header,
+                    light_block(3, 4).signed_header
+                );
+            },
+            e => panic!("unexpected error: {e}"),
+        }
+
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(2));
+        assert_eq!(peers(&supervisor), vec![peer_id(3)]);
+
+        // Verification goes on with the new primary
+        let verified = supervisor.verify_to_target(3_u32.into()).unwrap();
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+    }
+
+    #[test]
+    fn fails_when_no_witness_is_left() {
+        let unreachable = instance(2, vec![light_block(1, 1)]);
+        let mut supervisor = supervisor(honest(1), vec![unreachable]);
+
+        let error = supervisor.verify_to_target(3_u32.into()).unwrap_err();
+
+        assert!(matches!(error.detail(), ErrorDetail::NoWitnessesLeft(_)));
+        assert!(supervisor.witnesses().is_empty());
+    }
+
+    /// A witness which provides a block at height 3 forked from the one of the honest
+    /// peers, which passes verification.
+    fn forked(peer: u8) -> Instance {
+        instance(peer, vec![light_block(1, 1), light_block(3, 4)])
+    }
+
+    #[test]
+    fn majority_outvotes_forked_witness() {
+        let mut supervisor = supervisor(honest(1), vec![honest(2), forked(3), honest(4)])
+            .with_quorum(WitnessQuorum::Majority);
+
+        let verified = supervisor.verify_to_target(3_u32.into()).unwrap();
+
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(1));
+        assert_eq!(peers(&supervisor), vec![peer_id(2), peer_id(4)]);
+    }
+
+    #[test]
+    fn majority_of_forked_witnesses_replaces_primary() {
+        let mut supervisor = supervisor(honest(1), vec![forked(2), forked(3), honest(4)])
+            .with_quorum(WitnessQuorum::Majority);
+
+        let error = supervisor.verify_to_target(3_u32.into()).unwrap_err();
+
+        assert!(
+            matches!(error.detail(), ErrorDetail::AttackDetected(e) if e.primary == peer_id(1))
+        );
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(2));
+        assert_eq!(peers(&supervisor), vec![peer_id(3), peer_id(4)]);
+    }
+
+    #[test]
+    fn all_must_agree_rejects_single_forked_witness() {
+        let mut supervisor = supervisor(honest(1), vec![honest(2), forked(3)])
+            .with_quorum(WitnessQuorum::AllMustAgree);
+
+        let error = supervisor.verify_to_target(3_u32.into()).unwrap_err();
+
+        match error.detail() {
+            ErrorDetail::InsufficientWitnesses(e) => {
+                assert_eq!(e.agreeing, 1);
+                assert_eq!(e.required, 2);
+            },
+            e => panic!("unexpected error: {e}"),
+        }
+
+        // Nothing has been trusted, and no peer has been ruled out
+        let trusted = supervisor.primary().latest_trusted().unwrap();
+        assert_eq!(trusted.height().value(), 1);
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(1));
+        assert_eq!(peers(&supervisor), vec![peer_id(2), peer_id(3)]);
+    }
+
+    #[test]
+    fn at_least_requires_enough_responsive_witnesses() {
+        let unreachable = instance(3, vec![light_block(1, 1)]);
+        let mut lacking = supervisor(honest(1), vec![honest(2), unreachable])
+            .with_quorum(WitnessQuorum::AtLeast(2));
+
+        let error = lacking.verify_to_target(3_u32.into()).unwrap_err();
+
+        assert!(matches!(
+            error.detail(),
+            ErrorDetail::InsufficientWitnesses(e) if e.agreeing == 1 && e.required == 2
+        ));
+        assert_eq!(peers(&lacking), vec![peer_id(2)]);
+
+        let mut enough = supervisor(honest(1), vec![honest(2), honest(3)])
+            .with_quorum(WitnessQuorum::AtLeast(2));
+
+        let verified = enough.verify_to_target(3_u32.into()).unwrap();
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+    }
+
+    #[test]
+    fn requires_enough_responsive_witnesses() {
+        let unreachable = |id| instance(id, vec![light_block(1, 1)]);
+        let witnesses = || vec![honest(2), unreachable(3), unreachable(4)];
+
+        // A single witness agreeing with the primary is enough for the quorum
+        let mut lacking = supervisor(honest(1), witnesses()).with_required_witnesses(2);
+
+        let error = lacking.verify_to_target(3_u32.into()).unwrap_err();
+
+        assert!(matches!(
+            error.detail(),
+            ErrorDetail::NotEnoughResponsiveWitnesses(e) if e.responsive == 1 && e.required == 2
+        ));
+        let latest_trusted = lacking.primary().latest_trusted().unwrap();
+        assert_eq!(latest_trusted.height().value(), 1);
+
+        let mut enough = supervisor(honest(1), witnesses());
+
+        let verified = enough.verify_to_target(3_u32.into()).unwrap();
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+    }
+
+    /// I/O failing the fetches whose rank, starting from zero, matches the given predicate.
+    struct FlakyIo {
+        io: MockIo,
+        fetches: AtomicUsize,
+        fails: fn(usize) -> bool,
+    }
+
+    impl Io for FlakyIo {
+        fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+            if (self.fails)(self.fetches.fetch_add(1, Ordering::SeqCst)) {
+                return Err(IoError::rpc(tendermint_rpc::Error::timeout(
+                    Duration::from_secs(1),
+                )));
+            }
+
+            self.io.fetch_light_block(height)
+        }
+    }
+
+    fn flaky(peer: u8, fails: fn(usize) -> bool) -> Instance {
+        instance_with_io(peer, chain(), |io| FlakyIo {
+            io,
+            fetches: AtomicUsize::new(0),
+            fails,
+        })
+    }
+
+    /// I/O taking some time to fetch each light block.
+    struct SlowIo(MockIo);
+
+    impl Io for SlowIo {
+        fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+            std::thread::sleep(Duration::from_millis(20));
+            self.0.fetch_light_block(height)
+        }
+    }
+
+    fn slow(peer: u8) -> Instance {
+        instance_with_io(peer, chain(), SlowIo)
+    }
+
+    fn chain() -> Vec<LightBlock> {
+        (1..=3)
+            .map(|height| light_block(height, height as i64))
+            .collect()
+    }
+
+    #[test]
+    fn retries_flaky_primary_until_max_failures() {
+        // The second fetch of the primary fails, and the third one succeeds
+        let mut supervisor = supervisor(flaky(1, |fetch| fetch == 1), vec![instance(2, chain())])
+            .with_rotation_policy(RotationPolicy {
+                max_failures: 2,
+                ..Default::default()
+            });
+
+        supervisor.verify_to_target(2_u32.into()).unwrap();
+        let verified = supervisor.verify_to_target(3_u32.into()).unwrap();
+
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(1));
+
+        let score = &supervisor.scores()[&peer_id(1)];
+        assert_eq!((score.successes, score.failures), (2, 1));
+        assert!((score.error_rate() - 1.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn replaces_flaky_primary_by_best_scoring_witness() {
+        // All fetches of the primary fail after the first one
+        let mut supervisor = supervisor(
+            flaky(1, |fetch| fetch > 0),
+            vec![slow(2), instance(3, chain())],
+        )
+        .with_rotation_policy(RotationPolicy {
+            max_failures: 2,
+            ..Default::default()
+        });
+
+        supervisor.verify_to_target(2_u32.into()).unwrap();
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(1));
+
+        // The witnesses are equally reliable, but the first one is slower
+        let scores = supervisor.scores();
+        assert!(scores[&peer_id(3)].is_better_than(&scores[&peer_id(2)]));
+
+        let verified = supervisor.verify_to_target(3_u32.into()).unwrap();
+
+        assert_eq!(verified.signed_header, light_block(3, 3).signed_header);
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(3));
+        assert_eq!(peers(&supervisor), vec![peer_id(2)]);
+        assert!(!supervisor.status().scores.contains_key(&peer_id(1)));
+    }
+
+    #[test]
+    fn rotates_primary_periodically_to_better_witness() {
+        let mut supervisor = supervisor(
+            flaky(1, |fetch| fetch == 1),
+            vec![slow(2), instance(3, chain())],
+        )
+        .with_rotation_policy(RotationPolicy {
+            max_failures: 2,
+            rotate_every: Some(2),
+        });
+
+        supervisor.verify_to_target(2_u32.into()).unwrap();
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(1));
+
+        // The primary failed once while verifying the second block, unlike the witnesses
+        let verified = supervisor.verify_to_target(3_u32.into()).unwrap();
+
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(3));
+        assert_eq!(peers(&supervisor), vec![peer_id(2), peer_id(1)]);
+        assert_eq!(supervisor.primary().latest_trusted(), {
+            let mut trusted = verified;
+            trusted.provider = peer_id(3);
+            Some(trusted)
+        });
+    }
+
+    #[test]
+    fn keeps_best_scoring_primary() {
+        let mut supervisor =
+            supervisor(honest(1), vec![slow(2)]).with_rotation_policy(RotationPolicy {
+                rotate_every: Some(1),
+                ..Default::default()
+            });
+
+        supervisor.verify_to_highest().unwrap();
+
+        assert_eq!(*supervisor.primary().peer_id(), peer_id(1));
+        assert_eq!(peers(&supervisor), vec![peer_id(2)]);
+    }
+}
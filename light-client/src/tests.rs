@@ -1,18 +1,20 @@
 //! Utilities and datatypes for use in tests.
 
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
 
 #[cfg(feature = "rust-crypto")]
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use tendermint::{block::Height as HeightStr, evidence::Duration as DurationStr};
-use tendermint_rpc as rpc;
 
 use crate::{
     components::{
         clock::Clock,
-        io::{AtHeight, Io, IoError},
+        io::{AsyncIo, AtHeight, Io, IoError},
     },
     errors::Error,
     light_client::LightClient,
@@ -23,13 +25,13 @@ use crate::{
 #[cfg(feature = "rust-crypto")]
 use crate::verifier::{Verdict, Verifier};
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TestCases<LB> {
     pub batch_name: String,
     pub test_cases: Vec<TestCase<LB>>,
 }
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TestCase<LB> {
     pub description: String,
     pub initial: Initial,
@@ -45,7 +47,7 @@ pub struct Initial {
     pub now: Time,
 }
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct LightClientTest<LB> {
     pub description: String,
     pub trust_options: TrustOptions,
@@ -57,18 +59,18 @@ pub struct LightClientTest<LB> {
     pub expected_num_of_bisections: usize,
 }
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct WitnessProvider<LB> {
     pub value: Provider<LB>,
 }
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Provider<LB> {
     pub chain_id: String,
     pub lite_blocks: Vec<LB>,
 }
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TrustOptions {
     pub period: DurationStr,
     pub height: HeightStr,
@@ -117,14 +119,324 @@ impl Io for MockIo {
             AtHeight::At(height) => height,
         };
 
-        self.light_blocks.get(&height).cloned().ok_or_else(|| {
-            IoError::rpc(rpc::Error::response(
-                rpc::response_error::ResponseError::new((-32600).into(), None),
-            ))
-        })
+        self.light_blocks
+            .get(&height)
+            .cloned()
+            .ok_or_else(|| IoError::height_not_found(height))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncIo for MockIo {
+    async fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        Io::fetch_light_block(self, height)
     }
 }
 
+#[cfg(feature = "testgen")]
+pub use self::testgen::*;
+
+/// Synthetic chains generated with `tendermint-testgen`, and light clients verifying them.
+#[cfg(feature = "testgen")]
+mod testgen {
+    use std::time::Duration;
+
+    use tendermint_testgen::{
+        helpers::get_time,
+        light_block::{default_peer_id, TmLightBlock},
+        Generator, Header, LightBlock as TestgenLightBlock, LightChain, Validator,
+    };
+
+    use super::{MockClock, MockIo};
+    use crate::{
+        components::{
+            clock::Clock,
+            io::{AtHeight, Io},
+        },
+        errors::ErrorDetail,
+        light_client::{self, LightClient},
+        state::State,
+        store::{memory::MemoryStore, LightStore},
+        verifier::{
+            options::Options,
+            types::{LightBlock, Status},
+        },
+    };
+
+    /// The light blocks from height 1 to `length` of a chain whose validator set never changes.
+    pub fn stable_chain(length: u64) -> Vec<LightBlock> {
+        LightChain::default_with_length(length)
+            .light_blocks
+            .into_iter()
+            .map(|lb| to_light_block(lb.generate().unwrap()))
+            .collect()
+    }
+
+    /// The validators at the given height of a chain whose validator set is entirely
+    /// replaced at every height, so that no light block can be verified by skipping.
+    pub fn rotating_validators(height: u64) -> Vec<Validator> {
+        vec![Validator::new(&height.to_string()).voting_power(50)]
+    }
+
+    /// The light blocks from height 1 to `length` of a chain whose validator set is entirely
+    /// replaced at every height, see [`rotating_validators`] and [`generate_chain`].
+    pub fn rotating_chain(length: u64) -> Vec<LightBlock> {
+        generate_chain(length, rotating_validators, |header, _| header)
+    }
+
+    /// The light blocks from height 1 to `length` of the chain `test-chain`, signed by the
+    /// given validators at each height, the block at height `h` being dated `h` seconds
+    /// after [`get_time`]'s origin.
+    ///
+    /// The header of each block can be adjusted with `adjust`, given the light blocks
+    /// generated before it.
+    pub fn generate_chain(
+        length: u64,
+        validators: impl Fn(u64) -> Vec<Validator>,
+        mut adjust: impl FnMut(Header, &[LightBlock]) -> Header,
+    ) -> Vec<LightBlock> {
+        let mut light_blocks = Vec::new();
+
+        for height in 1..=length {
+            let header = Header::new(&validators(height))
+                .next_validators(&validators(height + 1))
+                .height(height)
+                .chain_id("test-chain")
+                .time(get_time(height).unwrap());
+            let header = adjust(header, &light_blocks);
+
+            let tm_lb = TestgenLightBlock::new_default_with_header(header)
+                .next_validators(&validators(height + 1))
+                .generate()
+                .unwrap();
+
+            light_blocks.push(to_light_block(tm_lb));
+        }
+
+        light_blocks
+    }
+
+    fn to_light_block(tm_lb: TmLightBlock) -> LightBlock {
+        LightBlock::new(
+            tm_lb.signed_header,
+            tm_lb.validators,
+            tm_lb.next_validators,
+            tm_lb.provider,
+        )
+    }
+
+    /// A clock reading one second after the last block of a chain of the given length,
+    /// as dated by [`generate_chain`].
+    pub fn clock_after(length: u64) -> MockClock {
+        MockClock {
+            now: get_time(length + 1).unwrap(),
+        }
+    }
+
+    /// Options with the default trust threshold, a trusting period of ten days
+    /// and a clock drift of ten seconds.
+    pub fn test_options() -> Options {
+        Options::new(
+            Default::default(),
+            Duration::from_secs(60 * 60 * 24 * 10),
+            Duration::from_secs(10),
+        )
+        .unwrap()
+    }
+
+    /// A state whose light store only holds the light block at the given height,
+    /// fetched from the given I/O, as trusted.
+    pub fn trusted_state(io: &impl Io, height: u64) -> State {
+        let trusted_block = io
+            .fetch_light_block(AtHeight::At(height.try_into().unwrap()))
+            .unwrap();
+
+        let mut light_store = MemoryStore::new();
+        light_store.insert(trusted_block, Status::Trusted);
+
+        State::new(light_store)
+    }
+
+    /// A builder for a light client with the given clock and I/O, whose primary is
+    /// the provider of the light blocks generated by `tendermint-testgen`, and with
+    /// [`test_options`]. Its other components can be set, and options overridden.
+    pub fn test_light_client_builder(
+        clock: impl Clock + 'static,
+        io: impl Io + 'static,
+    ) -> light_client::Builder {
+        LightClient::builder()
+            .primary(default_peer_id())
+            .options(test_options())
+            .with_clock(clock)
+            .with_io(io)
+    }
+
+    /// Verify the light block at `target_height` of the given chain, from the one at
+    /// `trusted_height`, with a light client built by [`test_light_client_builder`]
+    /// whose clock reads one second after the latest block of the chain.
+    pub fn verify_chain(
+        chain: Vec<LightBlock>,
+        trusted_height: u64,
+        target_height: u64,
+    ) -> Result<LightBlock, Box<ErrorDetail>> {
+        let latest_time = chain.iter().map(|lb| lb.time()).max().unwrap();
+        let clock = MockClock {
+            now: (latest_time + Duration::from_secs(1)).unwrap(),
+        };
+
+        let io = MockIo::new(chain);
+        let mut state = trusted_state(&io, trusted_height);
+        let light_client = test_light_client_builder(clock, io).build().unwrap();
+
+        light_client
+            .verify_to_target(target_height.try_into().unwrap(), &mut state)
+            .map_err(|e| Box::new(e.0))
+    }
+}
+
+/// I/O recording the light blocks fetched from the wrapped one, eg. to build
+/// test fixtures from a verification run, see [`record_bisection`].
+///
+/// Clones share the wrapped I/O and the recorded light blocks.
+pub struct RecordingIo<I> {
+    io: Arc<I>,
+    light_blocks: Arc<Mutex<BTreeMap<Height, LightBlock>>>,
+}
+
+impl<I> Clone for RecordingIo<I> {
+    fn clone(&self) -> Self {
+        Self {
+            io: self.io.clone(),
+            light_blocks: self.light_blocks.clone(),
+        }
+    }
+}
+
+impl<I> RecordingIo<I> {
+    pub fn new(io: I) -> Self {
+        Self {
+            io: Arc::new(io),
+            light_blocks: Default::default(),
+        }
+    }
+
+    /// The light blocks fetched so far, by increasing height.
+    pub fn light_blocks(&self) -> Vec<LightBlock> {
+        self.light_blocks
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+impl<I: Io> Io for RecordingIo<I> {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        let light_block = self.io.fetch_light_block(height)?;
+
+        self.light_blocks
+            .lock()
+            .unwrap()
+            .insert(light_block.height(), light_block.clone());
+
+        Ok(light_block)
+    }
+}
+
+/// Verify the light block at `height_to_verify` from the one at the trusted height,
+/// both fetched from the given I/O, eg. a `ProdIo` connected to a live chain, as of `now`,
+/// and record the run as a [`LightClientTest`].
+///
+/// The test case holds the light blocks fetched during verification, the number of
+/// bisection steps taken, and whether verification is expected to fail, so that it can
+/// be serialized as a JSON fixture for the light client tests, which replay it with
+/// the same options as this function.
+///
+/// Fails if a light block could not be fetched, in which case the run cannot be replayed.
+/// The light block at `height_to_verify` is always fetched. Panics if the trust options
+/// do not make for valid verification options.
+///
+/// The light client tests replay fixtures with the peer of
+/// `tendermint_testgen::light_block::default_peer_id` as primary, which must
+/// therefore be the provider of the light blocks fetched from the I/O.
+#[cfg(feature = "rust-crypto")]
+pub fn record_bisection(
+    description: impl ToString,
+    io: impl Io + 'static,
+    trust_options: TrustOptions,
+    height_to_verify: Height,
+    now: Time,
+) -> Result<LightClientTest<LightBlock>, Error> {
+    use crate::{
+        components::scheduler,
+        errors::ErrorDetail,
+        store::{memory::MemoryStore, LightStore},
+        verifier::{
+            options::{Options, DEFAULT_MAX_BISECTION_DEPTH},
+            types::Status,
+            ProdVerifier,
+        },
+    };
+
+    let io = RecordingIo::new(io);
+
+    let trusted_block = io
+        .fetch_light_block(AtHeight::At(trust_options.height))
+        .map_err(Error::io)?;
+    let chain_id = trusted_block.signed_header.header.chain_id.to_string();
+    let peer = trusted_block.provider;
+
+    let mut light_store = MemoryStore::new();
+    light_store.insert(trusted_block, Status::Trusted);
+    let mut state = State::new(light_store);
+
+    let options = Options {
+        trust_threshold: trust_options.trust_level,
+        trusting_period: trust_options.period.into(),
+        max_clock_drift: Duration::from_secs(10),
+        max_block_lag: None,
+        refresh_margin: None,
+        max_bisection_depth: DEFAULT_MAX_BISECTION_DEPTH,
+        absent_power_warning_threshold: None,
+    };
+
+    let light_client = LightClient::new(
+        peer,
+        options,
+        MockClock { now },
+        scheduler::basic_bisecting_schedule,
+        ProdVerifier::default(),
+        io.clone(),
+    )
+    .expect("the trust options must be valid");
+
+    let expected_output = match light_client.verify_to_target(height_to_verify, &mut state) {
+        Ok(_) => None,
+        Err(e) if matches!(e.detail(), ErrorDetail::Io(_)) => return Err(e),
+        Err(_) => Some("error".to_string()),
+    };
+
+    // The light block to verify is fetched when replaying the test case, even if
+    // verification failed before fetching it
+    io.fetch_light_block(AtHeight::At(height_to_verify))
+        .map_err(Error::io)?;
+
+    Ok(LightClientTest {
+        description: description.to_string(),
+        trust_options,
+        primary: Provider {
+            chain_id,
+            lite_blocks: io.light_blocks(),
+        },
+        witnesses: Vec::new(),
+        height_to_verify,
+        now,
+        expected_output,
+        expected_num_of_bisections: state.bisection_depth(),
+    })
+}
+
 #[cfg(feature = "rust-crypto")]
 pub fn verify_single(
     trusted_block: LightBlock,
@@ -134,14 +446,18 @@ pub fn verify_single(
     clock_drift: Duration,
     now: Time,
 ) -> Result<LightBlock, Verdict> {
-    use crate::verifier::options::Options;
+    use crate::verifier::options::{Options, DEFAULT_MAX_BISECTION_DEPTH};
 
     let verifier = crate::verifier::ProdVerifier::default();
 
     let options = Options {
         trust_threshold,
         trusting_period,
-        clock_drift,
+        max_clock_drift: clock_drift,
+        max_block_lag: None,
+        refresh_margin: None,
+        max_bisection_depth: DEFAULT_MAX_BISECTION_DEPTH,
+        absent_power_warning_threshold: None,
     };
 
     let result = verifier.verify_update_header(
@@ -166,3 +482,15 @@ pub fn verify_bisection(
         .verify_to_target(untrusted_height, state)
         .map(|_| state.get_trace(untrusted_height))
 }
+
+pub async fn verify_bisection_async(
+    untrusted_height: Height,
+    light_client: &LightClient,
+    state: &mut State,
+    io: &dyn AsyncIo,
+) -> Result<Vec<LightBlock>, Error> {
+    light_client
+        .verify_to_target_async(untrusted_height, state, io)
+        .await
+        .map(|_| state.get_trace(untrusted_height))
+}
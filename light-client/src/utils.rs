@@ -5,4 +5,9 @@ mod block_on;
 #[cfg(feature = "rpc-client")]
 pub use block_on::block_on;
 
+#[cfg(feature = "tokio")]
+mod timeout;
+#[cfg(feature = "tokio")]
+pub use timeout::timeout;
+
 pub mod std_ext;
@@ -16,12 +16,7 @@ where
             .build()
             .map_err(IoError::runtime)?;
 
-        if let Some(timeout) = timeout {
-            let task = async { tokio::time::timeout(timeout, f).await };
-            rt.block_on(task).map_err(|e| IoError::timeout(timeout, e))
-        } else {
-            Ok(rt.block_on(f))
-        }
+        rt.block_on(super::timeout(timeout, f))
     })
     .join()
     .unwrap()
@@ -0,0 +1,19 @@
+use std::{future::Future, time::Duration};
+
+use crate::components::io::IoError;
+
+/// Await the given future on the current runtime, with the given timeout.
+///
+/// Resolves to an `IoError::timeout` if the future does not complete in time.
+pub async fn timeout<F>(timeout: Option<Duration>, f: F) -> Result<F::Output, IoError>
+where
+    F: Future,
+{
+    if let Some(timeout) = timeout {
+        tokio::time::timeout(timeout, f)
+            .await
+            .map_err(|e| IoError::timeout(timeout, e))
+    } else {
+        Ok(f.await)
+    }
+}
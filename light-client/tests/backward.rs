@@ -9,7 +9,7 @@ use tendermint_light_client::{
         io::{AtHeight, Io},
         scheduler,
     },
-    errors::Error,
+    errors::{Error, ErrorDetail},
     light_client::LightClient,
     state::State,
     store::{memory::MemoryStore, LightStore},
@@ -50,11 +50,12 @@ fn make(chain: LightChain, trusted_height: Height) -> (LightClient, State) {
         now: tendermint_testgen::helpers::get_time(chain.light_blocks.len() as u64 + 1).unwrap(),
     };
 
-    let options = Options {
-        trust_threshold: Default::default(),
-        trusting_period: Duration::from_secs(60 * 60 * 24 * 10),
-        clock_drift: Duration::from_secs(10),
-    };
+    let options = Options::new(
+        Default::default(),
+        Duration::from_secs(60 * 60 * 24 * 10),
+        Duration::from_secs(10),
+    )
+    .unwrap();
 
     let light_blocks = chain
         .light_blocks
@@ -75,6 +76,7 @@ fn make(chain: LightChain, trusted_height: Height) -> (LightClient, State) {
     let state = State {
         light_store: Box::new(light_store),
         verification_trace: HashMap::new(),
+        bisection_trace: Vec::new(),
     };
 
     let verifier = ProdVerifier::default();
@@ -86,7 +88,8 @@ fn make(chain: LightChain, trusted_height: Height) -> (LightClient, State) {
         scheduler::basic_bisecting_schedule,
         verifier,
         io,
-    );
+    )
+    .unwrap();
 
     (light_client, state)
 }
@@ -256,3 +259,120 @@ proptest! {
         bad_test(tc)?;
     }
 }
+
+/// A client trusting the blocks at the given heights of a chain of the given length,
+/// whose light blocks may be tampered with beforehand.
+fn make_tampered(
+    length: u64,
+    trusted_heights: &[u64],
+    tamper: impl FnOnce(&mut Vec<LightBlock>),
+) -> (LightClient, State) {
+    let mut light_blocks: Vec<LightBlock> = LightChain::default_with_length(length)
+        .light_blocks
+        .into_iter()
+        .map(|lb| testgen_to_lb(lb.generate().unwrap()))
+        .collect();
+
+    let mut light_store = MemoryStore::new();
+    for height in trusted_heights {
+        light_store.insert(light_blocks[*height as usize - 1].clone(), Status::Trusted);
+    }
+
+    let state = State {
+        light_store: Box::new(light_store),
+        verification_trace: HashMap::new(),
+        bisection_trace: Vec::new(),
+    };
+
+    tamper(&mut light_blocks);
+
+    let options = Options::new(
+        Default::default(),
+        Duration::from_secs(60 * 60 * 24 * 10),
+        Duration::from_secs(10),
+    )
+    .unwrap();
+
+    let light_client = LightClient::new(
+        default_peer_id(),
+        options,
+        MockClock {
+            now: tendermint_testgen::helpers::get_time(length + 1).unwrap(),
+        },
+        scheduler::basic_bisecting_schedule,
+        ProdVerifier::default(),
+        MockIo::new(light_blocks),
+    )
+    .unwrap();
+
+    (light_client, state)
+}
+
+#[test]
+fn verify_backwards_walks_down_from_trusted_block_above_target() {
+    // Verifying to the target would skip forward from the block at height 2
+    let (light_client, mut state) = make_tampered(10, &[10, 2], |_| {});
+
+    let verified = light_client
+        .verify_backwards(5_u32.into(), &mut state)
+        .unwrap();
+
+    assert_eq!(verified.height().value(), 5);
+    for height in 5..=10_u32 {
+        assert!(state
+            .light_store
+            .get_trusted_or_verified(height.into())
+            .is_some());
+    }
+    assert!(state
+        .light_store
+        .get_trusted_or_verified(4_u32.into())
+        .is_none());
+    assert!(state
+        .light_store
+        .get_trusted_or_verified(3_u32.into())
+        .is_none());
+}
+
+#[test]
+fn verify_backwards_rejects_tampered_chain() {
+    let (light_client, mut state) = make_tampered(10, &[10], |light_blocks| {
+        let header = &mut light_blocks[6].signed_header.header;
+        header.app_hash = b"tampered".to_vec().try_into().unwrap();
+    });
+
+    let err = light_client
+        .verify_backwards(5_u32.into(), &mut state)
+        .unwrap_err();
+
+    // The link between the blocks at heights 8 and 7 breaks
+    assert!(matches!(
+        err.detail(),
+        ErrorDetail::InvalidAdjacentHeaders(_)
+    ));
+    assert!(state
+        .light_store
+        .get_trusted_or_verified(8_u32.into())
+        .is_some());
+    assert!(state
+        .light_store
+        .get_trusted_or_verified(7_u32.into())
+        .is_none());
+}
+
+#[test]
+fn verify_backwards_rejects_target_above_trusted_blocks() {
+    let (light_client, mut state) = make_tampered(10, &[4], |_| {});
+
+    let err = light_client
+        .verify_backwards(5_u32.into(), &mut state)
+        .unwrap_err();
+
+    match err.detail() {
+        ErrorDetail::TargetHigherThanTrustedState(e) => {
+            assert_eq!(e.target_height.value(), 5);
+            assert_eq!(e.trusted_height.value(), 4);
+        },
+        e => panic!("expected a target higher than the trusted state, got: {e:?}"),
+    }
+}
@@ -0,0 +1,87 @@
+//! Light blocks whose time is not strictly after the one of the trusted state are rejected.
+
+use tendermint_light_client::{
+    errors::ErrorDetail,
+    tests::{generate_chain, rotating_validators, verify_chain},
+    verifier::{errors::VerificationErrorDetail, types::LightBlock},
+};
+use tendermint_testgen::Validator;
+
+const CHAIN_LENGTH: u64 = 4;
+
+/// A chain verified sequentially, like `tests::rotating_chain`, with the given
+/// time (in seconds) at each height.
+fn chain(time: impl Fn(u64) -> u64) -> Vec<LightBlock> {
+    chain_with_validators(rotating_validators, time)
+}
+
+/// A chain whose validator set never changes, so that its last block is verified
+/// by skipping from the first one, with the given time (in seconds) at each height.
+fn stable_chain(time: impl Fn(u64) -> u64) -> Vec<LightBlock> {
+    chain_with_validators(|_| vec![Validator::new("a").voting_power(50)], time)
+}
+
+fn chain_with_validators(
+    validators: impl Fn(u64) -> Vec<Validator>,
+    time: impl Fn(u64) -> u64,
+) -> Vec<LightBlock> {
+    generate_chain(CHAIN_LENGTH, validators, |header, _| {
+        let height = header.height.unwrap();
+        header.time(tendermint_testgen::helpers::get_time(time(height)).unwrap())
+    })
+}
+
+fn assert_non_monotonic_bft_time(
+    result: Result<LightBlock, Box<ErrorDetail>>,
+    header_time: u64,
+    trusted_header_time: u64,
+) {
+    match result.map_err(|e| *e) {
+        Err(ErrorDetail::InvalidLightBlock(e)) => match e.source {
+            VerificationErrorDetail::NonMonotonicBftTime(e) => {
+                assert_eq!(
+                    e.header_bft_time,
+                    tendermint_testgen::helpers::get_time(header_time).unwrap()
+                );
+                assert_eq!(
+                    e.trusted_header_bft_time,
+                    tendermint_testgen::helpers::get_time(trusted_header_time).unwrap()
+                );
+            },
+            e => panic!("expected a non monotonic BFT time, got: {e:?}"),
+        },
+        result => panic!("expected a non monotonic BFT time, got: {result:?}"),
+    }
+}
+
+#[test]
+fn verifies_chain_with_increasing_time() {
+    let verified = verify_chain(chain(|height| 10 * height), 1, CHAIN_LENGTH).unwrap();
+    assert_eq!(verified.height().value(), CHAIN_LENGTH);
+}
+
+#[test]
+fn rejects_block_with_time_going_backwards() {
+    // The block at height 3 is older than the one at height 2, but not than the one at height 1
+    let chain = chain(|height| if height == 3 { 15 } else { 10 * height });
+    assert_non_monotonic_bft_time(verify_chain(chain, 1, CHAIN_LENGTH), 15, 20);
+}
+
+#[test]
+fn rejects_block_with_same_time_as_trusted_block() {
+    let chain = chain(|height| if height == 3 { 20 } else { 10 * height });
+    assert_non_monotonic_bft_time(verify_chain(chain, 1, CHAIN_LENGTH), 20, 20);
+}
+
+#[test]
+fn rejects_skipping_to_block_older_than_trusted_block() {
+    // Only the blocks at heights 1 and 4 are fetched, the latter being older than the former
+    let chain = stable_chain(|height| {
+        if height == CHAIN_LENGTH {
+            5
+        } else {
+            10 * height
+        }
+    });
+    assert_non_monotonic_bft_time(verify_chain(chain, 1, CHAIN_LENGTH), 5, 10);
+}
@@ -0,0 +1,55 @@
+//! Bisection is aborted once it takes more steps than allowed by the options.
+
+use tendermint_light_client::{
+    errors::ErrorDetail,
+    state::State,
+    tests::{
+        clock_after, rotating_chain, test_light_client_builder, test_options, trusted_state, MockIo,
+    },
+    verifier::{options::DEFAULT_MAX_BISECTION_DEPTH, types::LightBlock},
+};
+
+const CHAIN_LENGTH: u64 = 16;
+
+fn verify(max_bisection_depth: usize) -> (Result<LightBlock, Box<ErrorDetail>>, State) {
+    let io = MockIo::new(rotating_chain(CHAIN_LENGTH));
+    let mut state = trusted_state(&io, 1);
+
+    let mut options = test_options();
+    options.max_bisection_depth = max_bisection_depth;
+
+    let light_client = test_light_client_builder(clock_after(CHAIN_LENGTH), io)
+        .options(options)
+        .build()
+        .unwrap();
+
+    let result = light_client
+        .verify_to_target(CHAIN_LENGTH.try_into().unwrap(), &mut state)
+        .map_err(|e| Box::new(e.0));
+
+    (result, state)
+}
+
+#[test]
+fn default_depth_is_enough_for_rotating_chain() {
+    let (result, state) = verify(DEFAULT_MAX_BISECTION_DEPTH);
+
+    assert_eq!(result.unwrap().height().value(), CHAIN_LENGTH);
+    assert!(state.bisection_depth() > 8);
+}
+
+#[test]
+fn bisection_is_aborted_past_max_depth() {
+    let (result, state) = verify(8);
+
+    match result.map_err(|e| *e) {
+        Err(ErrorDetail::BisectionDepthExceeded(e)) => {
+            assert_eq!(e.target_height.value(), CHAIN_LENGTH);
+            assert_eq!(e.max_depth, 8);
+        },
+        result => panic!("expected the bisection depth to be exceeded, got: {result:?}"),
+    }
+
+    // Verification stopped right after the first step past the bound
+    assert_eq!(state.bisection_depth(), 9);
+}
@@ -0,0 +1,57 @@
+use tendermint_light_client::{
+    state::BisectionStep,
+    tests::{clock_after, rotating_chain, test_light_client_builder, trusted_state, MockIo},
+    verifier::Verdict,
+};
+
+const CHAIN_LENGTH: u64 = 4;
+
+#[test]
+fn bisection_trace_records_verification_steps() {
+    let io = MockIo::new(rotating_chain(CHAIN_LENGTH));
+    let mut state = trusted_state(&io, 1);
+
+    let light_client = test_light_client_builder(clock_after(CHAIN_LENGTH), io)
+        .build()
+        .unwrap();
+
+    let target_height = CHAIN_LENGTH.try_into().unwrap();
+    light_client
+        .verify_to_target(target_height, &mut state)
+        .unwrap();
+
+    let steps = state
+        .bisection_trace
+        .iter()
+        .map(|step| {
+            let verdict = match step.verdict {
+                Verdict::Success => "success",
+                Verdict::NotEnoughTrust(_) => "not_enough_trust",
+                Verdict::Invalid(_) => "invalid",
+            };
+            (
+                step.trusted_height.value(),
+                step.attempted_height.value(),
+                verdict,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        steps,
+        vec![
+            (1, 4, "not_enough_trust"),
+            (1, 3, "not_enough_trust"),
+            (1, 2, "success"),
+            (2, 4, "not_enough_trust"),
+            (2, 3, "success"),
+            (3, 4, "success"),
+        ]
+    );
+
+    // The light block at the target height is now taken from the light store
+    light_client
+        .verify_to_target(target_height, &mut state)
+        .unwrap();
+    assert_eq!(state.bisection_trace, Vec::<BisectionStep>::new());
+}
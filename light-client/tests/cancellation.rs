@@ -0,0 +1,88 @@
+//! Cancellation of an asynchronous verification, in the middle of bisection.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
+
+use futures::{channel::oneshot, executor::block_on};
+use tendermint_light_client::{
+    components::io::{AsyncIo, AtHeight, Io, IoError},
+    errors::ErrorDetail,
+    tests::{clock_after, rotating_chain, test_light_client_builder, trusted_state, MockIo},
+    verifier::types::{LightBlock, Status},
+};
+
+const CHAIN_LENGTH: u64 = 4;
+
+/// Asynchronous I/O whose fetch of the given rank never completes,
+/// and requests the cancellation of the verification instead.
+struct CancellingIo {
+    io: MockIo,
+    cancel_at: usize,
+    fetches: AtomicUsize,
+    cancel: Mutex<Option<oneshot::Sender<()>>>,
+}
+
+#[async_trait::async_trait]
+impl AsyncIo for CancellingIo {
+    async fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        if self.fetches.fetch_add(1, Ordering::SeqCst) + 1 == self.cancel_at {
+            if let Some(cancel) = self.cancel.lock().unwrap().take() {
+                cancel.send(()).unwrap();
+            }
+            futures::future::pending::<()>().await;
+        }
+
+        Io::fetch_light_block(&self.io, height)
+    }
+}
+
+#[test]
+fn cancelled_verification_leaves_store_consistent() {
+    let io = MockIo::new(rotating_chain(CHAIN_LENGTH));
+    let mut state = trusted_state(&io, 1);
+
+    let light_client = test_light_client_builder(clock_after(CHAIN_LENGTH), io.clone())
+        .build()
+        .unwrap();
+
+    // The target block is fetched first, then the one at height 2 as its validators differ
+    let (cancel, cancelled) = oneshot::channel();
+    let cancelling_io = CancellingIo {
+        io,
+        cancel_at: 2,
+        fetches: AtomicUsize::new(0),
+        cancel: Mutex::new(Some(cancel)),
+    };
+
+    let target_height = CHAIN_LENGTH.try_into().unwrap();
+    let err = block_on(light_client.verify_to_target_cancellable(
+        target_height,
+        &mut state,
+        &cancelling_io,
+        async move { cancelled.await.unwrap() },
+    ))
+    .unwrap_err();
+    assert!(matches!(err.detail(), ErrorDetail::Cancelled(_)));
+
+    // Only the blocks fetched before the cancellation were stored
+    let store = &state.light_store;
+    assert_eq!(
+        store
+            .highest_trusted_or_verified()
+            .unwrap()
+            .height()
+            .value(),
+        1
+    );
+    assert!(store.get(target_height, Status::Unverified).is_some());
+    assert!(store.get_non_failed(2_u32.into()).is_none());
+    assert_eq!(store.all(Status::Failed).count(), 0);
+
+    // Verification resumes from that state once no cancellation is requested anymore
+    let verified =
+        block_on(light_client.verify_to_target_async(target_height, &mut state, &cancelling_io))
+            .unwrap();
+    assert_eq!(verified.height(), target_height);
+}
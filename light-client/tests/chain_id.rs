@@ -0,0 +1,70 @@
+//! Light blocks from another chain than the one of the trusted state are rejected.
+
+use tendermint_light_client::{
+    errors::ErrorDetail,
+    tests::{generate_chain, verify_chain},
+    verifier::{errors::VerificationErrorDetail, types::LightBlock},
+};
+use tendermint_testgen::Validator;
+
+const CHAIN_LENGTH: u64 = 5;
+
+/// A chain of light blocks, with the given chain id at each height.
+fn chain(chain_id: impl Fn(u64) -> &'static str) -> Vec<LightBlock> {
+    generate_chain(
+        CHAIN_LENGTH,
+        |_| vec![Validator::new("a").voting_power(50)],
+        |header, _| {
+            let height = header.height.unwrap();
+            header.chain_id(chain_id(height))
+        },
+    )
+}
+
+fn assert_chain_id_mismatch(result: Result<LightBlock, Box<ErrorDetail>>) {
+    match result.map_err(|e| *e) {
+        Err(ErrorDetail::InvalidLightBlock(e)) => match e.source {
+            VerificationErrorDetail::ChainIdMismatch(e) => {
+                assert_eq!(e.got, "other-chain");
+                assert_eq!(e.expected, "test-chain");
+            },
+            e => panic!("expected a chain id mismatch, got: {e:?}"),
+        },
+        result => panic!("expected a chain id mismatch, got: {result:?}"),
+    }
+}
+
+#[test]
+fn verifies_blocks_from_trusted_chain() {
+    let verified = verify_chain(chain(|_| "test-chain"), 1, CHAIN_LENGTH).unwrap();
+    assert_eq!(verified.height().value(), CHAIN_LENGTH);
+}
+
+#[test]
+fn rejects_block_from_another_chain() {
+    let chain = chain(|height| {
+        if height == CHAIN_LENGTH {
+            "other-chain"
+        } else {
+            "test-chain"
+        }
+    });
+
+    assert_chain_id_mismatch(verify_chain(chain, 1, CHAIN_LENGTH));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn rejects_block_from_another_chain_backward() {
+    // Backward verification follows the `last_block_id` of each block
+    let chain = generate_chain(
+        CHAIN_LENGTH,
+        |_| vec![Validator::new("a").voting_power(50)],
+        |header, light_blocks| match light_blocks.last() {
+            None => header.chain_id("other-chain"),
+            Some(parent) => header.last_block_id_hash(parent.signed_header.header.hash()),
+        },
+    );
+
+    assert_chain_id_mismatch(verify_chain(chain, CHAIN_LENGTH, 1));
+}
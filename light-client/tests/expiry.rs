@@ -0,0 +1,210 @@
+//! Expiry and refresh of the trusted state, driven by a clock advanced by hand.
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use tendermint_light_client::{
+    components::{
+        clock::FixedClock,
+        io::{AtHeight, Io, IoError},
+    },
+    errors::ErrorDetail,
+    light_client::LightClient,
+    state::State,
+    store::memory::MemoryStore,
+    tests::{
+        rotating_chain, stable_chain, test_light_client_builder, test_options, trusted_state,
+        MockIo,
+    },
+    verifier::types::{LightBlock, Status},
+};
+
+const DAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+fn setup(clock: FixedClock) -> (LightClient, State) {
+    let io = MockIo::new(stable_chain(3));
+    let state = trusted_state(&io, 1);
+
+    let mut options = test_options();
+    options.refresh_margin = Some(DAY);
+
+    let light_client = test_light_client_builder(clock, io)
+        .options(options)
+        .build()
+        .unwrap();
+
+    (light_client, state)
+}
+
+#[test]
+fn refreshes_trusted_state_as_clock_advances() {
+    let clock = FixedClock::new(tendermint_testgen::helpers::get_time(1).unwrap());
+    let (mut light_client, mut state) = setup(clock.clone());
+
+    assert_eq!(light_client.time_until_expiry(&state), Some(10 * DAY));
+
+    clock.advance(8 * DAY);
+    assert_eq!(light_client.time_until_expiry(&state), Some(2 * DAY));
+    assert_eq!(light_client.refresh_if_expiring(&mut state).unwrap(), None);
+
+    clock.advance(DAY);
+    let refreshed = light_client
+        .refresh_if_expiring(&mut state)
+        .unwrap()
+        .unwrap();
+    assert_eq!(refreshed.height().value(), 3);
+
+    // The block at height 3 is 2 seconds younger than the one at height 1
+    assert_eq!(
+        light_client.time_until_expiry(&state),
+        Some(DAY + Duration::from_secs(2))
+    );
+}
+
+#[test]
+fn expired_trusted_state_cannot_be_refreshed() {
+    let clock = FixedClock::new(tendermint_testgen::helpers::get_time(1).unwrap());
+    let (mut light_client, mut state) = setup(clock.clone());
+
+    clock.advance(11 * DAY);
+    assert_eq!(light_client.time_until_expiry(&state), Some(Duration::ZERO));
+
+    let err = light_client.refresh_if_expiring(&mut state).unwrap_err();
+    assert!(matches!(
+        err.detail(),
+        ErrorDetail::TrustedStateOutsideTrustingPeriod(_)
+    ));
+}
+
+/// I/O advancing the clock by the given delay at each fetch, as a slow peer would.
+struct SlowIo {
+    io: MockIo,
+    clock: FixedClock,
+    delay: Duration,
+}
+
+impl Io for SlowIo {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        self.clock.advance(self.delay);
+        self.io.fetch_light_block(height)
+    }
+}
+
+/// Verify `tests::rotating_chain`, whose last block takes several bisection steps to reach.
+fn verify_rotating_chain(delay: Duration) -> Result<LightBlock, Box<ErrorDetail>> {
+    const CHAIN_LENGTH: u64 = 4;
+
+    let io = MockIo::new(rotating_chain(CHAIN_LENGTH));
+    let mut state = trusted_state(&io, 1);
+
+    let clock = FixedClock::new(tendermint_testgen::helpers::get_time(1).unwrap());
+    clock.advance(8 * DAY + DAY / 2);
+
+    let light_client = test_light_client_builder(clock.clone(), SlowIo { io, clock, delay })
+        .build()
+        .unwrap();
+
+    light_client
+        .verify_to_target(CHAIN_LENGTH.try_into().unwrap(), &mut state)
+        .map_err(|e| Box::new(e.0))
+}
+
+#[test]
+fn clock_is_read_at_each_verification_step() {
+    let verified = verify_rotating_chain(Duration::ZERO).unwrap();
+    assert_eq!(verified.height().value(), 4);
+
+    // The trusted state expires after the first two fetches, half-way through bisection
+    let err = verify_rotating_chain(DAY).unwrap_err();
+    assert!(matches!(
+        *err,
+        ErrorDetail::TrustedStateOutsideTrustingPeriod(_)
+    ));
+}
+
+/// I/O counting the light blocks fetched through it.
+#[derive(Clone)]
+struct CountingIo {
+    io: Arc<MockIo>,
+    fetches: Arc<AtomicUsize>,
+}
+
+impl Io for CountingIo {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        self.fetches.fetch_add(1, Ordering::SeqCst);
+        self.io.fetch_light_block(height)
+    }
+}
+
+#[test]
+fn already_verified_block_is_not_fetched_again() {
+    let io = CountingIo {
+        io: Arc::new(MockIo::new(stable_chain(3))),
+        fetches: Arc::new(AtomicUsize::new(0)),
+    };
+    let mut state = trusted_state(&io, 1);
+
+    let clock = FixedClock::new(tendermint_testgen::helpers::get_time(1).unwrap());
+    let light_client = test_light_client_builder(clock.clone(), io.clone())
+        .build()
+        .unwrap();
+
+    let verified = light_client
+        .verify_to_target(3_u32.into(), &mut state)
+        .unwrap();
+    let fetches = io.fetches.load(Ordering::SeqCst);
+
+    let cached = light_client
+        .verify_to_target(3_u32.into(), &mut state)
+        .unwrap();
+    assert_eq!(cached, verified);
+    assert_eq!(io.fetches.load(Ordering::SeqCst), fetches);
+
+    // Once expired, the verified block is not returned anymore
+    clock.advance(11 * DAY);
+    let err = light_client
+        .verify_to_target(3_u32.into(), &mut state)
+        .unwrap_err();
+    assert!(matches!(
+        err.detail(),
+        ErrorDetail::TrustedStateOutsideTrustingPeriod(_)
+    ));
+    assert_eq!(io.fetches.load(Ordering::SeqCst), fetches);
+}
+
+#[test]
+fn restored_snapshot_is_not_verified_again() {
+    let clock = FixedClock::new(tendermint_testgen::helpers::get_time(1).unwrap());
+    let (light_client, mut state) = setup(clock.clone());
+
+    let verified = light_client
+        .verify_to_target(3_u32.into(), &mut state)
+        .unwrap();
+
+    let json = serde_json::to_string(&light_client.snapshot(&state)).unwrap();
+    let snapshot = serde_json::from_str(&json).unwrap();
+    let mut restored = light_client.restore(snapshot, MemoryStore::new());
+
+    assert_eq!(restored.verification_trace, state.verification_trace);
+    assert_eq!(
+        light_client.time_until_expiry(&restored),
+        light_client.time_until_expiry(&state)
+    );
+    assert_eq!(
+        light_client
+            .verify_to_target(3_u32.into(), &mut restored)
+            .unwrap(),
+        verified
+    );
+
+    // Once expired, the restored light blocks are not trusted anymore
+    clock.advance(11 * DAY);
+    let restored = light_client.restore(light_client.snapshot(&state), MemoryStore::new());
+    assert_eq!(restored.light_store.highest_trusted_or_verified(), None);
+    assert_eq!(restored.light_store.all(Status::Unverified).count(), 2);
+}
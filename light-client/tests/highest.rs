@@ -0,0 +1,59 @@
+//! Verification to the highest block of a chain which keeps growing in the meantime.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tendermint_light_client::{
+    components::io::{AtHeight, Io, IoError},
+    errors::ErrorDetail,
+    tests::{clock_after, rotating_chain, test_light_client_builder, trusted_state, MockIo},
+    verifier::types::LightBlock,
+};
+
+const CHAIN_LENGTH: u64 = 8;
+
+/// I/O serving a chain whose tip grows by one block at each fetch,
+/// and which does not know about the blocks above its tip yet.
+struct GrowingIo {
+    io: MockIo,
+    tip: AtomicU64,
+}
+
+impl Io for GrowingIo {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        let tip = self.tip.fetch_add(1, Ordering::SeqCst);
+
+        match height {
+            AtHeight::Highest => self
+                .io
+                .fetch_light_block(AtHeight::At(tip.try_into().unwrap())),
+            AtHeight::At(height) if height.value() <= tip => {
+                self.io.fetch_light_block(height.into())
+            },
+            AtHeight::At(_) => Err(IoError::invalid_height()),
+        }
+    }
+}
+
+#[test]
+fn verifies_to_highest_block_at_the_time_of_the_query() -> Result<(), Box<ErrorDetail>> {
+    let io = MockIo::new(rotating_chain(CHAIN_LENGTH));
+    let mut state = trusted_state(&io, 1);
+
+    let io = GrowingIo {
+        io,
+        tip: AtomicU64::new(4),
+    };
+    let mut light_client = test_light_client_builder(clock_after(CHAIN_LENGTH), io)
+        .build()
+        .unwrap();
+
+    let verified = light_client
+        .verify_to_highest(&mut state)
+        .map_err(|e| Box::new(e.0))?;
+
+    // The chain has grown past height 4 during bisection
+    assert_eq!(verified.height().value(), 4);
+    assert!(state.bisection_trace.len() > 1);
+
+    Ok(())
+}
@@ -1,22 +1,24 @@
-use std::{collections::HashMap, time::Duration};
+use std::time::Duration;
 
 use tendermint_light_client::{
+    builder::{error::ErrorDetail, LightClientBuilder},
     components::{
-        io::{AtHeight, Io},
+        io::{AtHeight, Io, IoError},
         scheduler,
     },
     errors::Error,
-    light_client::LightClient,
+    light_client::{Builder, LightClient},
     state::State,
     store::{memory::MemoryStore, LightStore},
     tests::*,
     verifier::{
-        options::Options,
+        options::{Options, DEFAULT_MAX_BISECTION_DEPTH},
+        predicates::ProdPredicates,
         types::{LightBlock, Status},
         ProdVerifier,
     },
 };
-use tendermint_testgen::{light_block::default_peer_id, Tester};
+use tendermint_testgen::{light_block::default_peer_id, Tester, Validator};
 
 // Link to JSON test files repo:
 // https://github.com/informalsystems/conformance-tests
@@ -27,7 +29,7 @@ struct BisectionTestResult {
     new_states: Result<Vec<LightBlock>, Error>,
 }
 
-fn run_test(tc: LightClientTest<LightBlock>) -> BisectionTestResult {
+fn run_test(tc: LightClientTest<LightBlock>, asynchronous: bool) -> BisectionTestResult {
     let primary = default_peer_id();
     let untrusted_height = tc.height_to_verify;
     let trust_threshold = tc.trust_options.trust_level;
@@ -43,7 +45,11 @@ fn run_test(tc: LightClientTest<LightBlock>) -> BisectionTestResult {
     let options = Options {
         trust_threshold,
         trusting_period: trusting_period.into(),
-        clock_drift,
+        max_clock_drift: clock_drift,
+        max_block_lag: None,
+        refresh_margin: None,
+        max_bisection_depth: DEFAULT_MAX_BISECTION_DEPTH,
+        absent_power_warning_threshold: None,
     };
 
     let provider = tc.primary;
@@ -57,10 +63,7 @@ fn run_test(tc: LightClientTest<LightBlock>) -> BisectionTestResult {
     let mut light_store = MemoryStore::new();
     light_store.insert(trusted_state, Status::Trusted);
 
-    let mut state = State {
-        light_store: Box::new(light_store),
-        verification_trace: HashMap::new(),
-    };
+    let mut state = State::new(light_store);
 
     let verifier = ProdVerifier::default();
 
@@ -71,9 +74,19 @@ fn run_test(tc: LightClientTest<LightBlock>) -> BisectionTestResult {
         scheduler::basic_bisecting_schedule,
         verifier,
         io.clone(),
-    );
+    )
+    .unwrap();
 
-    let result = verify_bisection(untrusted_height, &mut light_client, &mut state);
+    let result = if asynchronous {
+        futures::executor::block_on(verify_bisection_async(
+            untrusted_height,
+            &light_client,
+            &mut state,
+            &io,
+        ))
+    } else {
+        verify_bisection(untrusted_height, &mut light_client, &mut state)
+    };
 
     let untrusted_light_block = io
         .fetch_light_block(AtHeight::At(untrusted_height))
@@ -86,12 +99,20 @@ fn run_test(tc: LightClientTest<LightBlock>) -> BisectionTestResult {
 }
 
 fn forward_test(tc: LightClientTest<LightBlock>) {
+    check_forward_test(tc, false)
+}
+
+fn forward_test_async(tc: LightClientTest<LightBlock>) {
+    check_forward_test(tc, true)
+}
+
+fn check_forward_test(tc: LightClientTest<LightBlock>, asynchronous: bool) {
     let expect_error = match &tc.expected_output {
         Some(eo) => eo.eq("error"),
         None => false,
     };
 
-    let test_result = run_test(tc);
+    let test_result = run_test(tc, asynchronous);
     let expected_state = test_result.untrusted_light_block;
 
     match test_result.new_states {
@@ -113,6 +134,199 @@ fn forward_test(tc: LightClientTest<LightBlock>) {
 fn run_tests() {
     let mut tester = Tester::new("light client verification", TEST_FILES_PATH);
     tester.add_test("forward verification with bisection", forward_test);
+    tester.add_test(
+        "asynchronous forward verification with bisection",
+        forward_test_async,
+    );
     tester.run_foreach_in_dir("bisection/single_peer");
     tester.finalize();
 }
+
+#[test]
+fn builder_requires_primary_options_and_io() {
+    let options = test_options();
+    let io = || |_: AtHeight| -> Result<LightBlock, IoError> { unreachable!() };
+
+    let missing = |builder: Builder| match builder.build().unwrap_err().detail() {
+        ErrorDetail::MissingComponent(e) => e.component.clone(),
+        e => panic!("unexpected error: {e}"),
+    };
+
+    assert_eq!(
+        missing(LightClient::builder().options(options).with_io(io())),
+        "primary"
+    );
+    assert_eq!(
+        missing(
+            LightClient::builder()
+                .primary(default_peer_id())
+                .with_io(io())
+        ),
+        "options"
+    );
+    assert_eq!(
+        missing(
+            LightClient::builder()
+                .primary(default_peer_id())
+                .options(options)
+        ),
+        "io"
+    );
+
+    let light_client = LightClient::builder()
+        .primary(default_peer_id())
+        .options(options)
+        .with_io(io())
+        .build()
+        .unwrap();
+
+    assert_eq!(light_client.peer, default_peer_id());
+    assert_eq!(light_client.options, options);
+}
+
+#[test]
+fn builder_rejects_invalid_options() {
+    let options = Options {
+        max_clock_drift: Duration::from_secs(60 * 60 * 24 * 11),
+        ..test_options()
+    };
+
+    let err = LightClient::builder()
+        .primary(default_peer_id())
+        .options(options)
+        .with_io(|_: AtHeight| -> Result<LightBlock, IoError> { unreachable!() })
+        .build()
+        .unwrap_err();
+
+    assert!(matches!(err.detail(), ErrorDetail::InvalidOptions(_)));
+
+    // Building the light client without the builder does not bypass the validation
+    let err = LightClient::new(
+        default_peer_id(),
+        options,
+        clock_after(1),
+        scheduler::basic_bisecting_schedule,
+        ProdVerifier::default(),
+        MockIo::new(rotating_chain(1)),
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(err.detail(), ErrorDetail::InvalidOptions(_)));
+
+    // Neither does building an instance
+    let err = LightClientBuilder::custom(
+        default_peer_id(),
+        options,
+        Box::new(MemoryStore::new()),
+        Box::new(MockIo::new(rotating_chain(1))),
+        Box::new(clock_after(1)),
+        Box::new(ProdVerifier::default()),
+        Box::new(scheduler::basic_bisecting_schedule),
+        Box::new(ProdPredicates),
+    )
+    .trust_light_block(rotating_chain(1).remove(0))
+    .err()
+    .unwrap();
+    assert!(matches!(err.detail(), ErrorDetail::InvalidOptions(_)));
+}
+
+const RECORDED_CHAIN_LENGTH: u64 = 4;
+
+/// A chain signed by the given validators, with the given chain id at each height.
+fn chain(
+    validators: impl Fn(u64) -> Vec<Validator>,
+    chain_id: impl Fn(u64) -> &'static str,
+) -> Vec<LightBlock> {
+    generate_chain(RECORDED_CHAIN_LENGTH, validators, |header, _| {
+        let height = header.height.unwrap();
+        header.chain_id(chain_id(height))
+    })
+}
+
+/// Record a bisection over the given chain as a JSON fixture, and parse it back.
+fn record(chain: Vec<LightBlock>, now: u64) -> LightClientTest<LightBlock> {
+    let trust_options = TrustOptions {
+        period: tendermint::evidence::Duration(Duration::from_secs(60 * 60 * 24 * 10)),
+        height: 1_u32.into(),
+        extra_heights: Vec::new(),
+        trust_level: Default::default(),
+    };
+
+    let test = record_bisection(
+        "Case: Trusted height=1, bisecting to verify height=4",
+        MockIo::new(chain),
+        trust_options,
+        RECORDED_CHAIN_LENGTH.try_into().unwrap(),
+        tendermint_testgen::helpers::get_time(now).unwrap(),
+    )
+    .unwrap();
+
+    let json = serde_json::to_string_pretty(&test).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+fn heights(test: &LightClientTest<LightBlock>) -> Vec<u64> {
+    test.primary
+        .lite_blocks
+        .iter()
+        .map(|lb| lb.height().value())
+        .collect()
+}
+
+#[test]
+fn recorded_verification_is_replayed() {
+    let chain = chain(
+        |_| vec![Validator::new("a").voting_power(50)],
+        |_| "test-chain",
+    );
+    let test = record(chain, RECORDED_CHAIN_LENGTH + 1);
+
+    assert_eq!(test.expected_output, None);
+    assert_eq!(test.expected_num_of_bisections, 0);
+    assert_eq!(test.primary.chain_id, "test-chain");
+    assert_eq!(heights(&test), vec![1, 4]);
+
+    // The verification trace ends with the verified block
+    let result = run_test(test, false);
+    let new_states = result.new_states.unwrap();
+    assert_eq!(new_states.last(), Some(&result.untrusted_light_block));
+}
+
+#[test]
+fn recorded_failing_bisection_is_replayed() {
+    // The validator set is entirely replaced at every height, so that the block at
+    // height 3, from another chain, is verified after bisecting once
+    let chain_id = |height| {
+        if height == 3 {
+            "other-chain"
+        } else {
+            "test-chain"
+        }
+    };
+    let test = record(
+        chain(rotating_validators, chain_id),
+        RECORDED_CHAIN_LENGTH + 1,
+    );
+
+    assert_eq!(test.expected_output.as_deref(), Some("error"));
+    assert_eq!(test.expected_num_of_bisections, 1);
+    assert_eq!(heights(&test), vec![1, 3, 4]);
+
+    forward_test(test);
+}
+
+#[test]
+fn recorded_expired_verification_is_replayed() {
+    let chain = chain(
+        |_| vec![Validator::new("a").voting_power(50)],
+        |_| "test-chain",
+    );
+    let test = record(chain, 60 * 60 * 24 * 11);
+
+    assert_eq!(test.expected_output.as_deref(), Some("error"));
+
+    // The block to verify is recorded even though it was not fetched to verify it
+    assert_eq!(heights(&test), vec![1, 4]);
+
+    forward_test(test);
+}
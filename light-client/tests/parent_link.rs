@@ -0,0 +1,50 @@
+//! Light blocks which are not the child of the adjacent trusted block are rejected.
+
+use tendermint_light_client::{
+    errors::ErrorDetail,
+    tests::{generate_chain, rotating_validators, verify_chain},
+    verifier::{errors::VerificationErrorDetail, types::LightBlock},
+};
+
+const CHAIN_LENGTH: u64 = 4;
+
+/// A chain verified sequentially, like `tests::rotating_chain`, whose blocks
+/// point at the hash of the block at the height returned by `parent`.
+fn chain(parent: impl Fn(u64) -> u64) -> Vec<LightBlock> {
+    generate_chain(
+        CHAIN_LENGTH,
+        rotating_validators,
+        |header, light_blocks| match header.height.unwrap() {
+            1 => header,
+            height => {
+                let parent = &light_blocks[parent(height) as usize - 1];
+                header.last_block_id_hash(parent.signed_header.header.hash())
+            },
+        },
+    )
+}
+
+#[test]
+fn verifies_linked_chain() {
+    let verified = verify_chain(chain(|height| height - 1), 1, CHAIN_LENGTH).unwrap();
+    assert_eq!(verified.height().value(), CHAIN_LENGTH);
+}
+
+#[test]
+fn rejects_block_pointing_at_wrong_parent() {
+    // The block at height 3 points at the block at height 1 rather than 2
+    let chain = chain(|height| if height == 3 { 1 } else { height - 1 });
+    let wrong_parent = chain[0].signed_header.header.hash();
+    let parent = chain[1].signed_header.header.hash();
+
+    match verify_chain(chain, 1, CHAIN_LENGTH).map_err(|e| *e) {
+        Err(ErrorDetail::InvalidLightBlock(e)) => match e.source {
+            VerificationErrorDetail::InvalidLastBlockId(e) => {
+                assert_eq!(e.last_block_id_hash, wrong_parent);
+                assert_eq!(e.trusted_header_hash, parent);
+            },
+            e => panic!("expected an invalid last block id, got: {e:?}"),
+        },
+        result => panic!("expected an invalid last block id, got: {result:?}"),
+    }
+}
@@ -0,0 +1,82 @@
+//! Verification resumes from the blocks verified before being interrupted,
+//! rather than from the initial trusted block.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use tendermint_light_client::{
+    components::io::{AtHeight, Io, IoError},
+    state::State,
+    store::{memory::MemoryStore, LightStore},
+    tests::{clock_after, rotating_chain, test_light_client_builder, test_options, MockIo},
+    verifier::types::{LightBlock, Status},
+};
+
+const CHAIN_LENGTH: u64 = 8;
+
+/// I/O counting the light blocks fetched through it.
+#[derive(Clone)]
+struct CountingIo {
+    io: Arc<MockIo>,
+    fetches: Arc<AtomicUsize>,
+}
+
+impl Io for CountingIo {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        self.fetches.fetch_add(1, Ordering::SeqCst);
+        self.io.fetch_light_block(height)
+    }
+}
+
+/// Verify up to the end of the chain, the light store holding the trusted block at
+/// height 1 along with the blocks verified at the given heights beforehand,
+/// returning the number of light blocks fetched along the way.
+fn light_blocks_fetched(verified_heights: &[u64]) -> usize {
+    let chain = rotating_chain(CHAIN_LENGTH);
+    let block = |height: u64| chain[height as usize - 1].clone();
+
+    let mut light_store = MemoryStore::new();
+    light_store.insert(block(1), Status::Trusted);
+    for &height in verified_heights {
+        light_store.insert(block(height), Status::Verified);
+    }
+
+    let mut state = State::new(light_store);
+
+    let io = CountingIo {
+        io: Arc::new(MockIo::new(chain)),
+        fetches: Arc::new(AtomicUsize::new(0)),
+    };
+
+    let mut options = test_options();
+    options.max_bisection_depth = usize::MAX;
+
+    let light_client = test_light_client_builder(clock_after(CHAIN_LENGTH), io.clone())
+        .options(options)
+        .build()
+        .unwrap();
+
+    let verified = light_client
+        .verify_to_target(CHAIN_LENGTH.try_into().unwrap(), &mut state)
+        .unwrap();
+    assert_eq!(verified.height().value(), CHAIN_LENGTH);
+
+    // Verification starts from the highest block verified beforehand
+    let first_step = &state.bisection_trace[0];
+    let resumed_from = verified_heights.iter().copied().max().unwrap_or(1);
+    assert_eq!(first_step.trusted_height.value(), resumed_from);
+
+    io.fetches.load(Ordering::SeqCst)
+}
+
+#[test]
+fn resumes_from_highest_verified_block() {
+    let from_scratch = light_blocks_fetched(&[]);
+    let resumed = light_blocks_fetched(&[2, 3, 4, 5]);
+
+    // The blocks verified before the interruption are not fetched again
+    assert_eq!(from_scratch, CHAIN_LENGTH as usize - 1);
+    assert_eq!(resumed, 3);
+}
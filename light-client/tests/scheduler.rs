@@ -0,0 +1,343 @@
+//! Compares the built-in scheduling strategies over synthetic chains, in terms of the
+//! number of verification steps and fetches each of them needs to reach the target height.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use tendermint_light_client::{
+    components::{
+        io::{AtHeight, Io, IoError},
+        scheduler::{self, PivotStrategy, Scheduler},
+    },
+    errors::ErrorDetail,
+    store::LightStore,
+    tests::{
+        clock_after, generate_chain, rotating_chain, stable_chain, test_light_client_builder,
+        test_options, trusted_state, MockIo,
+    },
+    verifier::types::{Height, LightBlock, Status, ValidatorSet},
+};
+use tendermint_testgen::Validator;
+
+const CHAIN_LENGTH: u64 = 32;
+
+/// A chain whose validator set never changes but over its last `changes` heights,
+/// where it is entirely replaced at every height.
+fn clustered_chain(changes: u64) -> Vec<LightBlock> {
+    let first_change = CHAIN_LENGTH - changes + 1;
+    let validators = |height: u64| {
+        let name = if height < first_change {
+            "stable".to_string()
+        } else {
+            height.to_string()
+        };
+        vec![Validator::new(&name).voting_power(50)]
+    };
+
+    generate_chain(CHAIN_LENGTH, validators, |header, _| header)
+}
+
+/// Verify from height 1 to the end of the chain with the given scheduler,
+/// returning the number of verification steps taken along the way.
+fn verify_with(chain: Vec<LightBlock>, scheduler: impl Scheduler + 'static) -> usize {
+    try_verify_with(chain, scheduler).expect("verification failed")
+}
+
+fn try_verify_with(
+    chain: Vec<LightBlock>,
+    scheduler: impl Scheduler + 'static,
+) -> Result<usize, Box<ErrorDetail>> {
+    try_verify_counting_fetches(chain, scheduler).map(|(steps, _)| steps)
+}
+
+/// I/O counting the light blocks fetched through it.
+struct CountingIo {
+    io: MockIo,
+    fetches: Arc<AtomicUsize>,
+}
+
+impl Io for CountingIo {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        self.fetches.fetch_add(1, Ordering::SeqCst);
+        self.io.fetch_light_block(height)
+    }
+}
+
+/// Verify from height 1 to the end of the chain with the given scheduler,
+/// returning the number of verification steps and of fetches along the way.
+fn try_verify_counting_fetches(
+    chain: Vec<LightBlock>,
+    scheduler: impl Scheduler + 'static,
+) -> Result<(usize, usize), Box<ErrorDetail>> {
+    let io = MockIo::new(chain);
+    let mut state = trusted_state(&io, 1);
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let io = CountingIo {
+        io,
+        fetches: fetches.clone(),
+    };
+
+    // Every verification step is followed by a call to the scheduler
+    let steps = Arc::new(AtomicUsize::new(0));
+    let counting_scheduler = {
+        let steps = steps.clone();
+        move |light_store: &dyn LightStore, current_height, target_height| {
+            steps.fetch_add(1, Ordering::SeqCst);
+            scheduler.schedule(light_store, current_height, target_height)
+        }
+    };
+
+    let mut options = test_options();
+
+    // Plain bisection over a chain rotating its validators at every height
+    // takes more steps than allowed by default
+    options.max_bisection_depth = usize::MAX;
+
+    let light_client = test_light_client_builder(clock_after(CHAIN_LENGTH), io)
+        .options(options)
+        .with_scheduler(counting_scheduler)
+        .build()
+        .unwrap();
+
+    let target_height = CHAIN_LENGTH.try_into().unwrap();
+    let verified = light_client
+        .verify_to_target(target_height, &mut state)
+        .map_err(|e| Box::new(e.0))?;
+
+    assert_eq!(verified.height(), target_height);
+
+    Ok((steps.load(Ordering::SeqCst), fetches.load(Ordering::SeqCst)))
+}
+
+/// Leave out the next validator set of every block but the trusted one at height 1,
+/// as if fetched with `FetchMode::HeaderAndVals`.
+fn without_next_validators(mut chain: Vec<LightBlock>) -> Vec<LightBlock> {
+    for light_block in chain.iter_mut().skip(1) {
+        light_block.next_validators = ValidatorSet::without_proposer(Vec::new());
+        assert!(!light_block.has_next_validators());
+    }
+    chain
+}
+
+#[test]
+fn strategies_over_stable_chain() {
+    // Skipping verification succeeds straight away, whatever the strategy.
+    assert_eq!(
+        verify_with(
+            stable_chain(CHAIN_LENGTH),
+            scheduler::basic_bisecting_schedule
+        ),
+        1
+    );
+    assert_eq!(
+        verify_with(stable_chain(CHAIN_LENGTH), scheduler::bisection_with_cap(2)),
+        1
+    );
+    assert_eq!(
+        verify_with(stable_chain(CHAIN_LENGTH), scheduler::sequential_schedule),
+        1
+    );
+    assert_eq!(
+        verify_with(stable_chain(CHAIN_LENGTH), scheduler::adaptive_schedule(2)),
+        1
+    );
+}
+
+#[test]
+fn strategies_over_rotating_chain() {
+    let bisection = verify_with(
+        rotating_chain(CHAIN_LENGTH),
+        scheduler::basic_bisecting_schedule,
+    );
+    let capped = verify_with(
+        rotating_chain(CHAIN_LENGTH),
+        scheduler::bisection_with_cap(2),
+    );
+    let sequential = verify_with(rotating_chain(CHAIN_LENGTH), scheduler::sequential_schedule);
+
+    // Every block in the chain has to be verified in turn, which sequential
+    // verification does after failing to skip to the target height once,
+    // whereas bisection keeps trying to verify blocks which cannot be yet.
+    assert_eq!(sequential, CHAIN_LENGTH as usize);
+    assert!(sequential < capped, "{sequential} >= {capped}");
+    assert!(capped < bisection, "{capped} >= {bisection}");
+}
+
+#[test]
+fn sequential_schedule_picks_next_height() {
+    let io = MockIo::new(stable_chain(CHAIN_LENGTH));
+    let store = trusted_state(&io, 4).light_store;
+    let h = |h: u64| -> Height { h.try_into().unwrap() };
+
+    // The current height has been verified
+    assert_eq!(scheduler::sequential_schedule(&*store, h(4), h(20)), h(5));
+    // The current height could not be verified
+    assert_eq!(scheduler::sequential_schedule(&*store, h(20), h(20)), h(5));
+    // The target height has been verified
+    assert_eq!(scheduler::sequential_schedule(&*store, h(4), h(4)), h(4));
+}
+
+#[test]
+fn bisection_with_cap_falls_back_to_sequential() {
+    let io = MockIo::new(stable_chain(CHAIN_LENGTH));
+    let store = trusted_state(&io, 1).light_store;
+    let h = |h: u64| -> Height { h.try_into().unwrap() };
+    let schedule = scheduler::bisection_with_cap(2);
+
+    // The current height has been verified
+    assert_eq!(schedule.schedule(&*store, h(1), h(17)), h(17));
+    // Bisect twice after failing to verify the target height...
+    assert_eq!(schedule.schedule(&*store, h(17), h(17)), h(9));
+    assert_eq!(schedule.schedule(&*store, h(9), h(17)), h(5));
+    // ...then verify sequentially
+    assert_eq!(schedule.schedule(&*store, h(5), h(17)), h(2));
+}
+
+#[test]
+fn strategies_without_next_validators() {
+    // Skipping from the trusted block, which has its next validators, still succeeds
+    assert_eq!(
+        verify_with(
+            without_next_validators(stable_chain(CHAIN_LENGTH)),
+            scheduler::basic_bisecting_schedule
+        ),
+        1
+    );
+
+    // Sequential verification does not need the next validators of the blocks it verifies
+    assert_eq!(
+        verify_with(
+            without_next_validators(rotating_chain(CHAIN_LENGTH)),
+            scheduler::sequential_schedule
+        ),
+        CHAIN_LENGTH as usize
+    );
+
+    // Whereas bisection skips from verified blocks after the first one
+    let err = try_verify_with(
+        without_next_validators(rotating_chain(CHAIN_LENGTH)),
+        scheduler::basic_bisecting_schedule,
+    )
+    .unwrap_err();
+
+    match *err {
+        ErrorDetail::MissingNextValidators(e) => {
+            assert_eq!(e.trusted_height.value(), 2);
+            assert_eq!(e.target_height.value(), CHAIN_LENGTH);
+        },
+        e => panic!("expected missing next validators, got: {e:?}"),
+    }
+}
+
+#[test]
+fn adaptive_schedule_over_rotating_chain() {
+    let (adaptive, fetches) = try_verify_counting_fetches(
+        rotating_chain(CHAIN_LENGTH),
+        scheduler::adaptive_schedule(2),
+    )
+    .unwrap();
+    let capped = verify_with(
+        rotating_chain(CHAIN_LENGTH),
+        scheduler::bisection_with_cap(2),
+    );
+
+    // After failing to skip twice, blocks are verified in turn, with only a few more
+    // attempts to skip once verification has gone past the blocks which failed to verify
+    let sequential = CHAIN_LENGTH as usize;
+    assert!(
+        adaptive <= sequential * 5 / 4,
+        "{adaptive} > {}",
+        sequential * 5 / 4
+    );
+    assert!(adaptive < capped, "{adaptive} >= {capped}");
+
+    // Every block but the trusted one is fetched exactly once
+    assert_eq!(fetches, CHAIN_LENGTH as usize - 1);
+}
+
+#[test]
+fn adaptive_schedule_falls_back_to_sequential() {
+    let io = MockIo::new(stable_chain(CHAIN_LENGTH));
+    let mut store = trusted_state(&io, 1).light_store;
+    let h = |h: u64| -> Height { h.try_into().unwrap() };
+    let schedule = scheduler::adaptive_schedule(2);
+
+    // Bisect after failing to verify the target height once...
+    store.insert(
+        io.fetch_light_block(AtHeight::At(h(17))).unwrap(),
+        Status::Unverified,
+    );
+    assert_eq!(schedule.schedule(&*store, h(17), h(17)), h(9));
+
+    // ...then verify sequentially after failing twice
+    store.insert(
+        io.fetch_light_block(AtHeight::At(h(9))).unwrap(),
+        Status::Unverified,
+    );
+    assert_eq!(schedule.schedule(&*store, h(9), h(17)), h(2));
+
+    // Even once a block has been verified
+    store.insert(
+        io.fetch_light_block(AtHeight::At(h(2))).unwrap(),
+        Status::Verified,
+    );
+    assert_eq!(schedule.schedule(&*store, h(2), h(17)), h(3));
+
+    // Until verification has gone past the blocks which failed to verify
+    store.insert(
+        io.fetch_light_block(AtHeight::At(h(9))).unwrap(),
+        Status::Verified,
+    );
+    assert_eq!(schedule.schedule(&*store, h(9), h(17)), h(17));
+}
+
+#[test]
+fn bisecting_schedule_picks_pivot() {
+    let io = MockIo::new(stable_chain(CHAIN_LENGTH));
+    let store = trusted_state(&io, 1).light_store;
+    let h = |h: u64| -> Height { h.try_into().unwrap() };
+
+    let midpoint = scheduler::bisecting_schedule(PivotStrategy::Midpoint);
+    let biased = scheduler::bisecting_schedule(PivotStrategy::BiasedTowardTarget(0.75));
+    let extreme = scheduler::bisecting_schedule(PivotStrategy::BiasedTowardTarget(1.0));
+
+    // The current height has been verified
+    assert_eq!(midpoint.schedule(&*store, h(1), h(17)), h(17));
+    assert_eq!(biased.schedule(&*store, h(1), h(17)), h(17));
+
+    // The current height could not be verified
+    assert_eq!(midpoint.schedule(&*store, h(17), h(17)), h(9));
+    assert_eq!(biased.schedule(&*store, h(17), h(17)), h(13));
+    assert_eq!(biased.schedule(&*store, h(3), h(17)), h(2));
+
+    // The picked height is always lower than the one which could not be verified
+    assert_eq!(extreme.schedule(&*store, h(17), h(17)), h(16));
+    assert_eq!(extreme.schedule(&*store, h(3), h(17)), h(2));
+}
+
+#[test]
+fn pivot_strategies_over_clustered_chain() {
+    let fetches = |strategy| {
+        try_verify_counting_fetches(clustered_chain(4), scheduler::bisecting_schedule(strategy))
+            .unwrap()
+            .1
+    };
+
+    let midpoint = fetches(PivotStrategy::Midpoint);
+    let biased = fetches(PivotStrategy::BiasedTowardTarget(0.75));
+    let extreme = fetches(PivotStrategy::BiasedTowardTarget(1.0));
+
+    assert_eq!(
+        midpoint,
+        try_verify_counting_fetches(clustered_chain(4), scheduler::basic_bisecting_schedule)
+            .unwrap()
+            .1
+    );
+
+    // Bisection reaches the blocks which changed the validator set in fewer jumps
+    // when biased towards the target height, so fewer blocks short of them are fetched.
+    assert!(biased < midpoint, "{biased} >= {midpoint}");
+    assert!(extreme <= biased, "{extreme} > {biased}");
+}
@@ -0,0 +1,116 @@
+//! Verification of chains whose validators sign with secp256k1 keys,
+//! possibly alongside validators signing with ed25519 keys.
+
+use tendermint::PublicKey;
+use tendermint_light_client::{
+    errors::ErrorDetail,
+    tests::{generate_chain, verify_chain},
+    verifier::{errors::VerificationErrorDetail, types::LightBlock},
+};
+use tendermint_testgen::Validator;
+
+const CHAIN_LENGTH: u64 = 5;
+
+fn secp256k1_validators() -> Vec<Validator> {
+    ["a", "b", "c"]
+        .iter()
+        .map(|id| Validator::new(id).voting_power(50).secp256k1(true))
+        .collect()
+}
+
+fn mixed_validators() -> Vec<Validator> {
+    vec![
+        Validator::new("a").voting_power(50).secp256k1(true),
+        Validator::new("b").voting_power(50),
+        Validator::new("c").voting_power(50).secp256k1(true),
+    ]
+}
+
+/// A chain of light blocks signed by the given validators,
+/// which hand over to the validators of the next height.
+fn chain(validators: impl Fn(u64) -> Vec<Validator>) -> Vec<LightBlock> {
+    generate_chain(CHAIN_LENGTH, validators, |header, _| header)
+}
+
+#[test]
+fn verifies_secp256k1_validator_set() {
+    let chain = chain(|_| secp256k1_validators());
+
+    assert!(chain[0]
+        .validators
+        .validators()
+        .iter()
+        .all(|v| matches!(v.pub_key, PublicKey::Secp256k1(_))));
+
+    let verified = verify_chain(chain, 1, CHAIN_LENGTH).unwrap();
+    assert_eq!(verified.height().value(), CHAIN_LENGTH);
+}
+
+#[test]
+fn verifies_mixed_validator_set() {
+    let verified = verify_chain(chain(|_| mixed_validators()), 1, CHAIN_LENGTH).unwrap();
+    assert_eq!(verified.height().value(), CHAIN_LENGTH);
+}
+
+#[test]
+fn verifies_handover_between_key_types() {
+    // Validators switch from ed25519 to secp256k1 keys half-way through the chain
+    let validators = |height| {
+        if height <= CHAIN_LENGTH / 2 {
+            ["a", "b", "c"]
+                .iter()
+                .map(|id| Validator::new(id).voting_power(50))
+                .collect()
+        } else {
+            secp256k1_validators()
+        }
+    };
+
+    let verified = verify_chain(chain(validators), 1, CHAIN_LENGTH).unwrap();
+    assert_eq!(verified.height().value(), CHAIN_LENGTH);
+}
+
+#[test]
+fn rejects_invalid_secp256k1_signature() {
+    let mut chain = chain(|_| secp256k1_validators());
+
+    // Sign the commit of the target block with the keys of another validator set
+    let forged_validators = |_| {
+        ["d", "e", "f"]
+            .iter()
+            .map(|id| Validator::new(id).voting_power(50).secp256k1(true))
+            .collect()
+    };
+    let forged = generate_chain(CHAIN_LENGTH, forged_validators, |header, _| header)
+        .pop()
+        .unwrap();
+
+    let target = chain.last_mut().unwrap();
+    for (sig, forged) in target
+        .signed_header
+        .commit
+        .signatures
+        .iter_mut()
+        .zip(forged.signed_header.commit.signatures)
+    {
+        if let (
+            tendermint::block::CommitSig::BlockIdFlagCommit { signature, .. },
+            tendermint::block::CommitSig::BlockIdFlagCommit {
+                signature: forged, ..
+            },
+        ) = (sig, forged)
+        {
+            *signature = forged;
+        }
+    }
+
+    match verify_chain(chain, 1, CHAIN_LENGTH).map_err(|e| *e) {
+        Err(ErrorDetail::InvalidLightBlock(e)) => {
+            assert!(matches!(
+                e.source,
+                VerificationErrorDetail::InvalidSignature(_)
+            ));
+        },
+        result => panic!("expected an invalid signature, got: {result:?}"),
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tendermint_light_client::{
+    components::{
+        fork_detector::ProdForkDetector,
+        io::{AtHeight, IoError},
+        telemetry::Telemetry,
+    },
+    instance::Instance,
+    light_client::LightClient,
+    state::State,
+    supervisor::Supervisor,
+    tests::{
+        clock_after, rotating_chain, test_light_client_builder, test_options, trusted_state, MockIo,
+    },
+    verifier::{
+        options::Options,
+        types::{Height, LightBlock, PeerId},
+        Verdict, VerificationWarning,
+    },
+};
+use tendermint_testgen::{
+    Commit, Generator, Header, LightBlock as TestgenLightBlock, Validator, Vote,
+};
+
+const CHAIN_LENGTH: u64 = 4;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Event {
+    Fetched(AtHeight, bool),
+    Verified(u64, &'static str),
+    Bisected(u64, u64),
+    Warned(u64, VerificationWarning),
+}
+
+#[derive(Clone, Default)]
+struct RecordingTelemetry {
+    events: Arc<Mutex<Vec<Event>>>,
+}
+
+impl Telemetry for RecordingTelemetry {
+    fn fetched(
+        &self,
+        _peer: PeerId,
+        height: AtHeight,
+        _latency: Duration,
+        result: Result<&LightBlock, &IoError>,
+    ) {
+        let event = Event::Fetched(height, result.is_ok());
+        self.events.lock().unwrap().push(event);
+    }
+
+    fn verified(&self, _peer: PeerId, light_block: &LightBlock, verdict: &Verdict) {
+        let verdict = match verdict {
+            Verdict::Success => "success",
+            Verdict::NotEnoughTrust(_) => "not_enough_trust",
+            Verdict::Invalid(_) => "invalid",
+        };
+
+        let event = Event::Verified(light_block.height().value(), verdict);
+        self.events.lock().unwrap().push(event);
+    }
+
+    fn bisected(
+        &self,
+        _peer: PeerId,
+        current_height: Height,
+        next_height: Height,
+        _target_height: Height,
+    ) {
+        let event = Event::Bisected(current_height.value(), next_height.value());
+        self.events.lock().unwrap().push(event);
+    }
+
+    fn warned(&self, _peer: PeerId, light_block: &LightBlock, warning: &VerificationWarning) {
+        let event = Event::Warned(light_block.height().value(), warning.clone());
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+fn setup(chain: Vec<LightBlock>, options: Options) -> (LightClient, State, RecordingTelemetry) {
+    let io = MockIo::new(chain);
+    let state = trusted_state(&io, 1);
+
+    let telemetry = RecordingTelemetry::default();
+
+    let light_client = test_light_client_builder(clock_after(CHAIN_LENGTH), io)
+        .options(options)
+        .with_telemetry(telemetry.clone())
+        .build()
+        .unwrap();
+
+    (light_client, state, telemetry)
+}
+
+#[test]
+fn telemetry_records_fetches_verifications_and_bisections() {
+    let (mut light_client, mut state, telemetry) =
+        setup(rotating_chain(CHAIN_LENGTH), test_options());
+
+    light_client.verify_to_highest(&mut state).unwrap();
+
+    let at = |height: u32| AtHeight::At(height.into());
+
+    assert_eq!(
+        *telemetry.events.lock().unwrap(),
+        vec![
+            Event::Fetched(AtHeight::Highest, true),
+            Event::Fetched(at(4), true),
+            Event::Verified(4, "not_enough_trust"),
+            Event::Bisected(4, 3),
+            Event::Fetched(at(3), true),
+            Event::Verified(3, "not_enough_trust"),
+            Event::Bisected(3, 2),
+            Event::Fetched(at(2), true),
+            Event::Verified(2, "success"),
+            // Light blocks fetched already are taken from the light store
+            Event::Verified(4, "not_enough_trust"),
+            Event::Bisected(4, 3),
+            Event::Verified(3, "success"),
+            Event::Verified(4, "success"),
+        ]
+    );
+}
+
+/// A chain with a stable validator set, one of which does not vote for the last block.
+fn chain_with_absent_validator() -> Vec<LightBlock> {
+    let validators = ["a", "b", "c", "d"].map(|id| Validator::new(id).voting_power(25));
+
+    (1..=CHAIN_LENGTH)
+        .map(|height| {
+            let header = Header::new(&validators)
+                .height(height)
+                .chain_id("test-chain")
+                .time(tendermint_testgen::helpers::get_time(height).unwrap());
+
+            let mut testgen_lb = TestgenLightBlock::new_default_with_header(header.clone());
+            if height == CHAIN_LENGTH {
+                let votes = validators[..3]
+                    .iter()
+                    .map(|validator| Vote::new(validator.clone(), header.clone()))
+                    .collect();
+                testgen_lb.commit = Some(Commit::new_with_votes(header, 1, votes));
+            }
+
+            let tm_lb = testgen_lb.generate().unwrap();
+            LightBlock::new(
+                tm_lb.signed_header,
+                tm_lb.validators,
+                tm_lb.next_validators,
+                tm_lb.provider,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn telemetry_records_warnings() {
+    let warning_options = Options {
+        absent_power_warning_threshold: Some(20),
+        ..test_options()
+    };
+    let (mut light_client, mut state, telemetry) =
+        setup(chain_with_absent_validator(), warning_options);
+
+    light_client.verify_to_highest(&mut state).unwrap();
+
+    assert_eq!(
+        *telemetry.events.lock().unwrap(),
+        vec![
+            Event::Fetched(AtHeight::Highest, true),
+            Event::Fetched(AtHeight::At(4_u32.into()), true),
+            Event::Verified(4, "success"),
+            Event::Warned(
+                4,
+                VerificationWarning::HighAbsentPower {
+                    height: 4_u32.into(),
+                    absent: 25,
+                    total: 100,
+                }
+            ),
+        ]
+    );
+
+    // Nothing is reported without a threshold
+    let (mut light_client, mut state, telemetry) =
+        setup(chain_with_absent_validator(), test_options());
+    light_client.verify_to_highest(&mut state).unwrap();
+    assert!(!telemetry
+        .events
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|event| matches!(event, Event::Warned(..))));
+}
+
+#[test]
+fn supervisor_forwards_warnings() {
+    let warning_options = Options {
+        absent_power_warning_threshold: Some(20),
+        ..test_options()
+    };
+    let (primary, primary_state, telemetry) = setup(chain_with_absent_validator(), warning_options);
+    let (witness, witness_state, _) = setup(chain_with_absent_validator(), test_options());
+
+    let mut supervisor = Supervisor::new(
+        Instance::new(primary, primary_state),
+        vec![Instance::new(witness, witness_state)],
+        ProdForkDetector::default(),
+    )
+    .unwrap();
+
+    supervisor.verify_to_highest().unwrap();
+
+    // The warning reaches the telemetry of the primary through the one of the supervisor
+    assert!(telemetry.events.lock().unwrap().contains(&Event::Warned(
+        4,
+        VerificationWarning::HighAbsentPower {
+            height: 4_u32.into(),
+            absent: 25,
+            total: 100,
+        }
+    )));
+}
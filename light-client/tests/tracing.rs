@@ -0,0 +1,81 @@
+//! Spans and events emitted by the light client with the `tracing` feature.
+
+#![cfg(feature = "tracing")]
+
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use tendermint_light_client::tests::{
+    clock_after, rotating_chain, test_light_client_builder, trusted_state, MockIo,
+};
+use tracing_subscriber::{filter::LevelFilter, fmt::format::FmtSpan, util::SubscriberInitExt};
+
+const CHAIN_LENGTH: u64 = 3;
+
+/// Buffer the formatted output of a subscriber is written to.
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn traces_verification() {
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let _guard = tracing_subscriber::fmt()
+        .with_max_level(LevelFilter::DEBUG)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish()
+        .set_default();
+
+    let io = MockIo::new(rotating_chain(CHAIN_LENGTH));
+    let mut state = trusted_state(&io, 1);
+
+    let light_client = test_light_client_builder(clock_after(CHAIN_LENGTH), io)
+        .build()
+        .unwrap();
+
+    light_client
+        .verify_to_target(CHAIN_LENGTH.try_into().unwrap(), &mut state)
+        .unwrap();
+
+    let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+
+    // Verifying the block at height 3 against the one at height 1 fails, so
+    // that the block at height 2 is fetched and verified first
+    let steps = lines
+        .iter()
+        .filter(|line| line.contains("verify_forward_step{") && line.contains("close"))
+        .collect::<Vec<_>>();
+    assert_eq!(steps.len(), 3);
+    assert!(steps[0].contains("trusted_height=1 height=3 verdict=NotEnoughTrust"));
+    assert!(steps[1].contains("trusted_height=1 height=2 verdict=Success"));
+    assert!(steps[2].contains("trusted_height=2 height=3 verdict=Success"));
+
+    let warnings = lines
+        .iter()
+        .filter(|line| line.contains("WARN"))
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("light block was not verified"));
+    assert!(warnings[0].contains("verify_to_target{"));
+
+    let fetches = lines
+        .iter()
+        .filter(|line| line.contains("fetch{") && line.contains("close"))
+        .count();
+    assert_eq!(fetches, 2);
+}
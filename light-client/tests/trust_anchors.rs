@@ -0,0 +1,119 @@
+//! Bootstrapping a light client from the newest of several trust anchors.
+
+use std::time::Duration;
+
+use tendermint::{block::Height, crypto::default::Sha256, Hash};
+use tendermint_light_client::{
+    builder::{
+        error::{Error, ErrorDetail},
+        LightClientBuilder, TrustAnchor,
+    },
+    components::{
+        io::{AtHeight, Io, IoError},
+        scheduler,
+    },
+    instance::Instance,
+    store::memory::MemoryStore,
+    tests::{stable_chain, test_options, MockClock, MockIo},
+    verifier::{predicates::ProdPredicates, types::LightBlock, ProdVerifier},
+};
+use tendermint_testgen::light_block::default_peer_id;
+
+fn anchor(light_block: &LightBlock) -> (Height, Hash) {
+    (
+        light_block.height(),
+        light_block.signed_header.header.hash_with::<Sha256>(),
+    )
+}
+
+/// I/O of a peer which cannot provide the light blocks at the given heights.
+struct PartiallyDownIo {
+    io: MockIo,
+    down_at: Vec<u64>,
+}
+
+impl Io for PartiallyDownIo {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        match height {
+            AtHeight::At(h) if self.down_at.contains(&h.value()) => Err(IoError::rpc(
+                tendermint_rpc::Error::timeout(Duration::from_secs(1)),
+            )),
+            _ => self.io.fetch_light_block(height),
+        }
+    }
+}
+
+/// Bootstrap a light client from the given anchors, with a peer which is down
+/// for the light blocks at the given heights.
+fn bootstrap(
+    chain: Vec<LightBlock>,
+    down_at: Vec<u64>,
+    anchors: Vec<(Height, Hash)>,
+) -> Result<Instance, Box<Error>> {
+    let builder = LightClientBuilder::custom(
+        default_peer_id(),
+        test_options(),
+        Box::new(MemoryStore::new()),
+        Box::new(PartiallyDownIo {
+            io: MockIo::new(chain),
+            down_at,
+        }),
+        Box::new(MockClock {
+            now: tendermint_testgen::helpers::get_time(10).unwrap(),
+        }),
+        Box::new(ProdVerifier::default()),
+        Box::new(scheduler::basic_bisecting_schedule),
+        Box::new(ProdPredicates),
+    );
+
+    Ok(builder
+        .trust_primary_at_any(anchors)
+        .map_err(Box::new)?
+        .build())
+}
+
+#[test]
+fn bootstraps_from_newest_anchor() {
+    let chain = stable_chain(3);
+    let anchors = vec![anchor(&chain[1]), anchor(&chain[2]), anchor(&chain[0])];
+
+    let instance = bootstrap(chain.clone(), vec![], anchors).unwrap();
+
+    assert_eq!(instance.latest_trusted(), Some(chain[2].clone()));
+    assert_eq!(
+        instance.trust_anchor(),
+        Some(TrustAnchor::from(anchor(&chain[2])))
+    );
+}
+
+#[test]
+fn falls_back_to_older_anchor_when_peer_is_down() {
+    let chain = stable_chain(3);
+    let anchors = vec![anchor(&chain[0]), anchor(&chain[1]), anchor(&chain[2])];
+
+    let instance = bootstrap(chain.clone(), vec![3], anchors.clone()).unwrap();
+    assert_eq!(instance.latest_trusted(), Some(chain[1].clone()));
+    assert_eq!(
+        instance.trust_anchor(),
+        Some(TrustAnchor::from(anchor(&chain[1])))
+    );
+
+    let err = bootstrap(chain, vec![1, 2, 3], anchors).unwrap_err();
+    assert!(matches!(err.detail(), ErrorDetail::Io(_)));
+}
+
+#[test]
+fn does_not_fall_back_on_hash_mismatch() {
+    let chain = stable_chain(3);
+    let (height, _) = anchor(&chain[2]);
+    let anchors = vec![anchor(&chain[1]), (height, anchor(&chain[0]).1)];
+
+    let err = bootstrap(chain, vec![], anchors).unwrap_err();
+    assert!(matches!(err.detail(), ErrorDetail::HashMismatch(_)));
+}
+
+#[test]
+fn requires_an_anchor() {
+    let err = bootstrap(stable_chain(3), vec![], vec![]).unwrap_err();
+    assert!(matches!(err.detail(), ErrorDetail::NoTrustAnchor(_)));
+}
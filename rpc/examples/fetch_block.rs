@@ -0,0 +1,69 @@
+//! Fetches a block and its commit from a node, concurrently, with the
+//! asynchronous HTTP client.
+//!
+//! ```text
+//! cargo run --example fetch_block --features http-client -- <rpc-address> [<height>]
+//! ```
+//!
+//! The latest block is fetched when no height is given.
+
+use std::{env, process};
+
+use tendermint::block::Height;
+use tendermint_rpc::{Client, HttpClient};
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (address, height) = match args.as_slice() {
+        [address] => (address, None),
+        [address, height] => {
+            let height: Height = height.parse().unwrap_or_else(|e| {
+                eprintln!("invalid height {height}: {e}");
+                process::exit(1)
+            });
+            (address, Some(height))
+        },
+        _ => {
+            eprintln!("usage: fetch_block <rpc-address> [<height>]");
+            process::exit(1)
+        },
+    };
+
+    let client = HttpClient::new(address.as_str()).unwrap_or_else(|e| {
+        eprintln!("invalid RPC address {address}: {e}");
+        process::exit(1)
+    });
+
+    let height = match height {
+        Some(height) => height,
+        None => match client.latest_commit().await {
+            Ok(commit) => commit.signed_header.header.height,
+            Err(e) => {
+                eprintln!("failed to fetch the latest commit: {e}");
+                process::exit(1)
+            },
+        },
+    };
+
+    // Both requests are in flight at the same time, on the same thread
+    let (block, commit) = tokio::try_join!(client.block(height), client.commit(height))
+        .unwrap_or_else(|e| {
+            eprintln!("failed to fetch the block at height {height}: {e}");
+            process::exit(1)
+        });
+
+    let signatures = commit
+        .signed_header
+        .commit
+        .signatures
+        .iter()
+        .filter(|sig| sig.is_commit())
+        .count();
+
+    println!("height:     {}", block.block.header.height);
+    println!("hash:       {}", block.block_id.hash);
+    println!("time:       {}", block.block.header.time);
+    println!("txs:        {}", block.block.data.len());
+    println!("signatures: {signatures}");
+}
@@ -0,0 +1,347 @@
+//! JSON-RPC batch requests.
+//!
+//! A [`Batch`] collects any number of requests, possibly of different types,
+//! to be sent to the remote endpoint in a single round-trip. Each request
+//! added to the batch yields a [`BatchHandle`], which is then used to retrieve
+//! the correctly typed result of that request from the [`BatchResponse`].
+//!
+//! ```rust,ignore
+//! use tendermint_rpc::{batch::Batch, endpoint, HttpClient};
+//!
+//! let client = HttpClient::new("http://127.0.0.1:26657").unwrap();
+//!
+//! let mut batch = Batch::new();
+//! let status = batch.add(endpoint::status::Request);
+//! let blocks = batch.add_all((1_u32..=50).map(|h| endpoint::block::Request::new(h.into())));
+//!
+//! let response = client.batch(batch).await.unwrap();
+//! println!("{:?}", response.get(&status).unwrap());
+//! for block in &blocks {
+//!     // Each request in the batch succeeds or fails independently
+//!     println!("{:?}", response.get(block));
+//! }
+//! ```
+
+use alloc::sync::Arc;
+use core::{fmt, marker::PhantomData};
+
+use serde::Deserialize;
+
+use crate::{
+    dialect::{Dialect, LatestDialect},
+    prelude::*,
+    request::Wrapper,
+    response, Error, Id, IdGenerator, SimpleRequest, UuidGenerator,
+};
+
+/// A JSON-RPC batch request, ie. a list of requests to be sent together.
+pub struct Batch<S: Dialect = LatestDialect> {
+    id_generator: Arc<dyn IdGenerator>,
+    ids: Vec<Id>,
+    requests: Vec<serde_json::Value>,
+    _dialect: PhantomData<S>,
+}
+
+impl<S: Dialect> fmt::Debug for Batch<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Batch")
+            .field("requests", &self.requests)
+            .finish()
+    }
+}
+
+impl<S: Dialect> Default for Batch<S> {
+    fn default() -> Self {
+        Self::with_id_generator(Arc::new(UuidGenerator))
+    }
+}
+
+impl<S: Dialect> Batch<S> {
+    /// Create an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty batch, whose requests get their IDs from the given
+    /// generator rather than random UUIDs.
+    pub fn with_id_generator(id_generator: Arc<dyn IdGenerator>) -> Self {
+        Self {
+            id_generator,
+            ids: Vec::new(),
+            requests: Vec::new(),
+            _dialect: PhantomData,
+        }
+    }
+
+    /// Add the given request to the batch, returning a handle to retrieve
+    /// its result from the [`BatchResponse`].
+    pub fn add<R>(&mut self, request: R) -> BatchHandle<R>
+    where
+        R: SimpleRequest<S>,
+    {
+        let index = self.ids.len();
+        let wrapper = Wrapper::new_with_id(self.id_generator.next_id(), request);
+
+        self.ids.push(wrapper.id().clone());
+        self.requests
+            .push(serde_json::to_value(&wrapper).expect("requests serialize to JSON"));
+
+        BatchHandle {
+            index,
+            _request: PhantomData,
+        }
+    }
+
+    /// Add all the given requests to the batch, returning the handles to
+    /// retrieve their results, in the same order as the requests.
+    pub fn add_all<R, I>(&mut self, requests: I) -> Vec<BatchHandle<R>>
+    where
+        R: SimpleRequest<S>,
+        I: IntoIterator<Item = R>,
+    {
+        requests.into_iter().map(|r| self.add(r)).collect()
+    }
+
+    /// The ID of the request with the given handle.
+    pub fn id<R>(&self, handle: &BatchHandle<R>) -> Option<&Id> {
+        self.ids.get(handle.index)
+    }
+
+    /// The number of requests in this batch.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether this batch contains no requests.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Serialize this batch as a JSON array of requests.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.requests).expect("requests serialize to JSON")
+    }
+
+    /// Parse the JSON-RPC response to this batch.
+    ///
+    /// Responses are correlated to the requests by their ID, so the remote
+    /// endpoint is free to respond in any order. A numerical ID matches a
+    /// string ID holding the same number, see [`Id::matches`]. Fails if the response is not
+    /// a batch response, eg. when the batch was rejected as a whole.
+    pub fn parse_response(self, response: impl AsRef<[u8]>) -> Result<BatchResponse<S>, Error> {
+        let responses: Vec<serde_json::Value> =
+            match serde_json::from_slice(response.as_ref()).map_err(Error::serde)? {
+                serde_json::Value::Array(responses) => responses,
+                single => {
+                    // A single response object means the request failed as a whole
+                    let wrapper: response::Wrapper<serde_json::Value> =
+                        serde_json::from_value(single).map_err(Error::serde)?;
+                    return Err(wrapper.into_error().unwrap_or_else(Error::malformed_json));
+                },
+            };
+
+        let mut results = vec![None; self.ids.len()];
+
+        for response in responses {
+            let id = response
+                .get("id")
+                .map(Id::deserialize)
+                .transpose()
+                .map_err(Error::serde)?;
+
+            let index = id.and_then(|id| self.ids.iter().position(|i| i.matches(&id)));
+
+            if let Some(index) = index {
+                results[index] = Some(response);
+            }
+        }
+
+        Ok(BatchResponse {
+            ids: self.ids,
+            results,
+            _dialect: PhantomData,
+        })
+    }
+}
+
+/// A handle to the result of a request within a [`Batch`].
+#[derive(Debug)]
+pub struct BatchHandle<R> {
+    index: usize,
+    _request: PhantomData<fn() -> R>,
+}
+
+impl<R> Clone for BatchHandle<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for BatchHandle<R> {}
+
+impl<R> BatchHandle<R> {
+    /// The position of the request within its batch.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// The response to a [`Batch`], holding the results of each request.
+pub struct BatchResponse<S: Dialect = LatestDialect> {
+    ids: Vec<Id>,
+    results: Vec<Option<serde_json::Value>>,
+    _dialect: PhantomData<S>,
+}
+
+impl<S: Dialect> fmt::Debug for BatchResponse<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchResponse")
+            .field("ids", &self.ids)
+            .field("results", &self.results)
+            .finish()
+    }
+}
+
+impl<S: Dialect> Clone for BatchResponse<S> {
+    fn clone(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+            results: self.results.clone(),
+            _dialect: PhantomData,
+        }
+    }
+}
+
+impl<S: Dialect> BatchResponse<S> {
+    /// Get the result of the request with the given handle.
+    ///
+    /// Fails with the error returned by the remote endpoint for this request,
+    /// or if the remote endpoint did not respond to this request at all.
+    pub fn get<R>(&self, handle: &BatchHandle<R>) -> Result<R::Output, Error>
+    where
+        R: SimpleRequest<S>,
+    {
+        let result = self
+            .results
+            .get(handle.index)
+            .cloned()
+            .flatten()
+            .ok_or_else(Error::mismatch_response)?;
+
+        let wrapper: response::Wrapper<R::Response> =
+            serde_json::from_value(result).map_err(Error::serde)?;
+
+        wrapper.into_result().map(Into::into)
+    }
+
+    /// The ID of the request with the given handle, eg. to log it along
+    /// with its result.
+    pub fn id<R>(&self, handle: &BatchHandle<R>) -> Option<&Id> {
+        self.ids.get(handle.index)
+    }
+
+    /// The number of requests in the batch.
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Whether the batch contained no requests.
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{endpoint, error::ErrorDetail, SequentialGenerator};
+
+    fn request_ids(batch: &Batch) -> Vec<serde_json::Value> {
+        let requests: Vec<serde_json::Value> = serde_json::from_str(&batch.to_json()).unwrap();
+        requests.into_iter().map(|r| r["id"].clone()).collect()
+    }
+
+    #[test]
+    fn serializes_as_json_array() {
+        let mut batch: Batch = Batch::new();
+        batch.add(endpoint::health::Request);
+        batch.add(endpoint::net_info::Request);
+
+        let requests: Vec<serde_json::Value> = serde_json::from_str(&batch.to_json()).unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0]["method"], "health");
+        assert_eq!(requests[1]["method"], "net_info");
+        assert_ne!(requests[0]["id"], requests[1]["id"]);
+    }
+
+    #[test]
+    fn correlates_out_of_order_and_partial_results() {
+        let mut batch: Batch = Batch::new();
+        let health = batch.add(endpoint::health::Request);
+        let abci_info = batch.add(endpoint::abci_info::Request);
+        let missing = batch.add(endpoint::health::Request);
+
+        let ids = request_ids(&batch);
+        let body = json!([
+            {
+                "jsonrpc": "2.0",
+                "id": ids[1],
+                "error": { "code": -32603, "message": "Internal error", "data": "oops" },
+            },
+            { "jsonrpc": "2.0", "id": ids[0], "result": {} },
+        ]);
+
+        let response = batch.parse_response(body.to_string()).unwrap();
+        assert_eq!(response.len(), 3);
+
+        assert!(response.get(&health).is_ok());
+        assert!(matches!(
+            response.get(&abci_info).unwrap_err().detail(),
+            ErrorDetail::Response(_)
+        ));
+        assert!(matches!(
+            response.get(&missing).unwrap_err().detail(),
+            ErrorDetail::MismatchResponse(_)
+        ));
+    }
+
+    #[test]
+    fn correlates_ids_echoed_with_another_type() {
+        let mut batch: Batch =
+            Batch::with_id_generator(Arc::new(SequentialGenerator::starting_at(1)));
+        let health = batch.add(endpoint::health::Request);
+        let net_info = batch.add(endpoint::net_info::Request);
+        assert_eq!(request_ids(&batch), vec![json!(1), json!(2)]);
+        assert_eq!(batch.id(&net_info), Some(&Id::Num(2)));
+
+        let body = json!([
+            { "jsonrpc": "2.0", "id": "2", "error": { "code": -32603, "message": "Internal error", "data": "" } },
+            { "jsonrpc": "2.0", "id": "1", "result": {} },
+        ]);
+
+        let response = batch.parse_response(body.to_string()).unwrap();
+        assert!(response.get(&health).is_ok());
+        assert!(matches!(
+            response.get(&net_info).unwrap_err().detail(),
+            ErrorDetail::Response(_)
+        ));
+        assert_eq!(response.id(&health), Some(&Id::Num(1)));
+    }
+
+    #[test]
+    fn fails_when_batch_is_rejected() {
+        let mut batch: Batch = Batch::new();
+        batch.add(endpoint::health::Request);
+
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": null,
+            "error": { "code": -32700, "message": "Parse error", "data": "" },
+        });
+
+        let error = batch.parse_response(body.to_string()).unwrap_err();
+        assert!(matches!(error.detail(), ErrorDetail::Response(_)));
+    }
+}
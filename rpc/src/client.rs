@@ -54,6 +54,17 @@ use crate::{
     Error, Order, SimpleRequest,
 };
 
+/// A stream of the ABCI results of blocks, along with their height,
+/// as returned by [`Client::block_results_stream`].
+#[cfg(any(
+    feature = "http-client",
+    feature = "websocket-client",
+    feature = "mock-client"
+))]
+pub type BlockResultsStream<'a> = core::pin::Pin<
+    Box<dyn futures::Stream<Item = (Height, Result<block_results::Response, Error>)> + 'a>,
+>;
+
 /// Provides lightweight access to the Tendermint RPC. It gives access to all
 /// endpoints with the exception of the event subscription-related ones.
 ///
@@ -70,6 +81,10 @@ pub trait Client {
     }
 
     /// `/abci_query`: query the ABCI application
+    ///
+    /// The data to query can be arbitrary bytes, and the query is made at the
+    /// latest height if none is given. If `prove` is set, the response carries
+    /// a Merkle proof which can be checked with [`abci_query::AbciQuery::verify_with`].
     async fn abci_query<V>(
         &self,
         path: Option<String>,
@@ -137,6 +152,33 @@ pub trait Client {
         self.perform(block_results::Request::default()).await
     }
 
+    /// `/block_results`: get ABCI results for the blocks within the given range
+    /// of heights, as a stream fetching them lazily, at most `concurrency` at a time.
+    ///
+    /// The results are yielded in order of height, along with that height.
+    /// Failing to fetch the results of a block does not end the stream.
+    #[cfg(any(
+        feature = "http-client",
+        feature = "websocket-client",
+        feature = "mock-client"
+    ))]
+    fn block_results_stream(
+        &self,
+        heights: core::ops::RangeInclusive<Height>,
+        concurrency: usize,
+    ) -> BlockResultsStream<'_>
+    where
+        Self: Sync,
+    {
+        use futures::StreamExt;
+
+        Box::pin(
+            futures::stream::iter(Height::range(heights))
+                .map(move |height| async move { (height, self.block_results(height).await) })
+                .buffered(concurrency.max(1)),
+        )
+    }
+
     /// `/block_search`: search for blocks by BeginBlock and EndBlock events.
     async fn block_search(
         &self,
@@ -189,6 +231,15 @@ pub trait Client {
         self.perform(broadcast::tx_commit::Request::new(tx)).await
     }
 
+    /// `/check_tx`: check a transaction with the application, without adding
+    /// it to the mempool, returning the response from `CheckTx`.
+    async fn check_tx<T>(&self, tx: T) -> Result<check_tx::Response, Error>
+    where
+        T: Into<Vec<u8>> + Send,
+    {
+        self.perform(check_tx::Request::new(tx)).await
+    }
+
     /// `/commit`: get block commit at a given height.
     async fn commit<H>(&self, height: H) -> Result<commit::Response, Error>
     where
@@ -214,6 +265,10 @@ pub trait Client {
 
     // TODO(thane): Simplify once validators endpoint removes pagination.
     /// `/validators`: get validators a given height.
+    ///
+    /// With [`Paging::All`], all pages are fetched and concatenated into a single
+    /// response, failing if their validators do not add up to the total reported
+    /// by the node.
     async fn validators<H>(&self, height: H, paging: Paging) -> Result<validators::Response, Error>
     where
         H: Into<Height> + Send,
@@ -247,14 +302,26 @@ pub trait Client {
                             Some(per_page),
                         ))
                         .await?;
+                    let received = response.validators.len();
                     validators.extend(response.validators);
-                    if validators.len() as i32 == response.total {
+
+                    let total = usize::try_from(response.total).ok();
+                    if total == Some(validators.len()) {
                         return Ok(validators::Response::new(
                             response.block_height,
                             validators,
                             response.total,
                         ));
                     }
+
+                    // The pages cannot add up to the total anymore
+                    if received == 0 || total.is_none_or(|total| validators.len() > total) {
+                        return Err(Error::validators_count_mismatch(
+                            response.total,
+                            validators.len(),
+                        ));
+                    }
+
                     page_num += 1;
                 }
             },
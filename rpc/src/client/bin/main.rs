@@ -125,6 +125,12 @@ enum ClientRequest {
         /// The transaction to broadcast.
         tx: String,
     },
+    /// Check a transaction with the ABCI app, without adding it to the
+    /// mempool.
+    CheckTx {
+        /// The transaction to check.
+        tx: String,
+    },
     /// Get the commit for the given height.
     Commit { height: u32 },
     /// Get consensus parameters for a specific height.
@@ -387,6 +393,11 @@ where
             serde_json::to_string_pretty(&client.broadcast_tx_sync(tx).await?)
                 .map_err(Error::serde)?
         },
+        ClientRequest::CheckTx { tx } => {
+            // NOTE: this prints out the response in the 0.38+ format,
+            // regardless of the actual protocol version.
+            serde_json::to_string_pretty(&client.check_tx(tx).await?).map_err(Error::serde)?
+        },
         ClientRequest::ConsensusParams { height } => {
             serde_json::to_string_pretty(&client.consensus_params(height).await?)
                 .map_err(Error::serde)?
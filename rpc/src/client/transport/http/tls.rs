@@ -0,0 +1,110 @@
+//! TLS configuration of the HTTP client, and classification of TLS errors.
+
+use std::{error::Error as StdError, sync::Arc, time::SystemTime};
+
+use rustls::{
+    client::{ServerCertVerified, ServerCertVerifier},
+    Certificate, CertificateError, ClientConfig, ServerName,
+};
+use sha2::{Digest, Sha256};
+use subtle_encoding::hex;
+
+use crate::{prelude::*, Error};
+
+/// The SHA-256 fingerprint of a DER-encoded certificate.
+pub type CertificateFingerprint = [u8; 32];
+
+/// Compute the SHA-256 fingerprint of the given DER-encoded certificate,
+/// as eg. displayed by `openssl x509 -noout -fingerprint -sha256`.
+pub fn certificate_fingerprint(der: &[u8]) -> CertificateFingerprint {
+    Sha256::digest(der).into()
+}
+
+/// Build a TLS configuration which only trusts server certificates
+/// with one of the given fingerprints.
+pub(super) fn pinned_config(pins: Vec<CertificateFingerprint>) -> ClientConfig {
+    ClientConfig::builder()
+        .with_safe_defaults()
+        .with_custom_certificate_verifier(Arc::new(PinnedVerifier { pins }))
+        .with_no_client_auth()
+}
+
+/// Convert an error raised by `reqwest` into an RPC error, surfacing
+/// certificate verification failures as [`Error::tls_certificate`].
+pub(super) fn http_error(e: reqwest::Error) -> Error {
+    match certificate_error(&e) {
+        Some(reason) => Error::tls_certificate(reason),
+        None => Error::http(e),
+    }
+}
+
+/// Look for a certificate verification failure among the causes of the given error.
+///
+/// Note that the TLS error is wrapped in (possibly nested) I/O errors, whose `source`
+/// skips over the wrapped error, which is thus looked up explicitly.
+fn certificate_error(e: &(dyn StdError + 'static)) -> Option<String> {
+    let mut source = Some(e);
+
+    while let Some(mut e) = source {
+        while let Some(inner) = e.downcast_ref::<std::io::Error>().and_then(|e| e.get_ref()) {
+            e = inner;
+        }
+
+        if let Some(rustls::Error::InvalidCertificate(e)) = e.downcast_ref::<rustls::Error>() {
+            return Some(match e {
+                CertificateError::Other(e) => e.to_string(),
+                e => format!("{e:?}"),
+            });
+        }
+
+        source = e.source();
+    }
+
+    None
+}
+
+/// A server certificate whose fingerprint is not pinned.
+#[derive(Debug)]
+struct UnpinnedCertificate(CertificateFingerprint);
+
+impl core::fmt::Display for UnpinnedCertificate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let fingerprint = String::from_utf8(hex::encode_upper(self.0)).unwrap();
+        write!(
+            f,
+            "certificate with fingerprint {fingerprint} is not pinned"
+        )
+    }
+}
+
+impl StdError for UnpinnedCertificate {}
+
+/// Trusts the server certificate if and only if its fingerprint is pinned.
+///
+/// The signatures of the handshake are still checked against the certificate
+/// by the default implementations, so that the server has to hold its private key.
+struct PinnedVerifier {
+    pins: Vec<CertificateFingerprint>,
+}
+
+impl ServerCertVerifier for PinnedVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let fingerprint = certificate_fingerprint(&end_entity.0);
+
+        if self.pins.contains(&fingerprint) {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(rustls::Error::InvalidCertificate(CertificateError::Other(
+                Arc::new(UnpinnedCertificate(fingerprint)),
+            )))
+        }
+    }
+}
@@ -245,11 +245,11 @@ mod test {
     use std::path::PathBuf;
 
     use futures::StreamExt;
-    use tendermint::{block::Height, chain::Id};
+    use tendermint::{block::Height, chain::Id, validator, PublicKey};
     use tokio::fs;
 
     use super::*;
-    use crate::query::EventType;
+    use crate::{endpoint::validators, error::ErrorDetail, query::EventType, Paging};
 
     async fn read_json_fixture(version: &str, name: &str) -> String {
         fs::read_to_string(
@@ -262,6 +262,184 @@ mod test {
         .unwrap()
     }
 
+    /// Responds to requests for the results of blocks at even heights with the
+    /// given block results, and fails at odd heights.
+    struct EvenHeightsMatcher {
+        block_results: String,
+    }
+
+    impl MockRequestMatcher for EvenHeightsMatcher {
+        fn response_for<R, S>(&self, request: R) -> Option<Result<R::Response, Error>>
+        where
+            R: Request<S>,
+            S: Dialect,
+        {
+            let request: serde_json::Value = serde_json::from_str(&request.into_json()).unwrap();
+            let height: u64 = request["params"]["height"].as_str()?.parse().unwrap();
+
+            Some(if height.is_multiple_of(2) {
+                R::Response::from_string(&self.block_results)
+            } else {
+                Err(Error::server(format!("no results at height {height}")))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn block_results_stream() {
+        let matcher = EvenHeightsMatcher {
+            block_results: read_json_fixture("v0_38", "block_results_at_height_10").await,
+        };
+        let (client, driver) = MockClient::new(matcher);
+        let driver_hdl = tokio::spawn(async move { driver.run().await });
+
+        let results = client
+            .block_results_stream(Height::from(1_u32)..=Height::from(6_u32), 3)
+            .collect::<Vec<_>>()
+            .await;
+
+        let heights = results
+            .iter()
+            .map(|(height, result)| (height.value(), result.is_ok()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            heights,
+            vec![
+                (1, false),
+                (2, true),
+                (3, false),
+                (4, true),
+                (5, false),
+                (6, true)
+            ]
+        );
+
+        client.close();
+        driver_hdl.await.unwrap().unwrap();
+    }
+
+    /// Responds to requests for validators with pages of the given validators,
+    /// along with the given total number of validators.
+    struct PagedValidatorsMatcher {
+        validators: Vec<validator::Info>,
+        per_page: usize,
+        total: i32,
+    }
+
+    impl PagedValidatorsMatcher {
+        fn new(count: u8, per_page: usize, total: i32) -> Self {
+            let validators = (0..count)
+                .map(|i| {
+                    let pub_key = PublicKey::from_raw_ed25519(&[i; 32]).unwrap();
+                    validator::Info::new(pub_key, (u32::from(i) + 1).into())
+                })
+                .collect();
+
+            Self {
+                validators,
+                per_page,
+                total,
+            }
+        }
+    }
+
+    impl MockRequestMatcher for PagedValidatorsMatcher {
+        fn response_for<R, S>(&self, request: R) -> Option<Result<R::Response, Error>>
+        where
+            R: Request<S>,
+            S: Dialect,
+        {
+            let request: serde_json::Value = serde_json::from_str(&request.into_json()).unwrap();
+            let page: usize = request["params"]["page"].as_str()?.parse().unwrap();
+
+            let validators = self
+                .validators
+                .iter()
+                .skip((page - 1) * self.per_page)
+                .take(self.per_page)
+                .cloned()
+                .collect();
+
+            let response = validators::Response::new(Height::from(1_u32), validators, self.total);
+            let json = serde_json::json!({ "jsonrpc": "2.0", "id": "", "result": response });
+
+            Some(R::Response::from_string(json.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn consensus_params_match_genesis() {
+        let matcher = MockRequestMethodMatcher::default()
+            .map(
+                Method::ConsensusParams,
+                Ok(read_json_fixture("v0_38", "consensus_params").await),
+            )
+            .map(
+                Method::Genesis,
+                Ok(read_json_fixture("v0_38", "genesis").await),
+            );
+        let (client, driver) = MockClient::new(matcher);
+        let driver_hdl = tokio::spawn(async move { driver.run().await });
+
+        let response = client.consensus_params(Height::from(10_u32)).await.unwrap();
+        assert_eq!(response.block_height, Height::from(10_u32));
+
+        // The parameters have not changed since genesis
+        let genesis = client.genesis::<Option<serde_json::Value>>().await.unwrap();
+        assert_eq!(response.consensus_params, genesis.consensus_params);
+
+        let evidence = &response.consensus_params.evidence;
+        assert_eq!(evidence.max_age_num_blocks, 100_000);
+        assert_eq!(
+            evidence.max_age_duration.0,
+            core::time::Duration::from_secs(48 * 60 * 60)
+        );
+
+        client.close();
+        driver_hdl.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn validators_from_all_pages() {
+        let matcher = PagedValidatorsMatcher::new(7, 3, 7);
+        let expected = matcher.validators.clone();
+        let (client, driver) = MockClient::new(matcher);
+        let driver_hdl = tokio::spawn(async move { driver.run().await });
+
+        let response = client
+            .validators(Height::from(1_u32), Paging::All)
+            .await
+            .unwrap();
+        assert_eq!(response.validators, expected);
+        assert_eq!(response.total, 7);
+
+        client.close();
+        driver_hdl.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn validators_not_adding_up_to_total() {
+        // The pages run out before the total is reached, or exceed it
+        for (count, total) in [(7, 8), (7, 5)] {
+            let matcher = PagedValidatorsMatcher::new(count, 3, total);
+            let (client, driver) = MockClient::new(matcher);
+            let driver_hdl = tokio::spawn(async move { driver.run().await });
+
+            let err = client
+                .validators(Height::from(1_u32), Paging::All)
+                .await
+                .unwrap_err();
+
+            match err.detail() {
+                ErrorDetail::ValidatorsCountMismatch(e) => assert_eq!(e.total, total),
+                e => panic!("expected a validators count mismatch, got: {e:?}"),
+            }
+
+            client.close();
+            driver_hdl.await.unwrap().unwrap();
+        }
+    }
+
     mod v0_34 {
         use super::*;
         use crate::event::v0_34::DeEvent;
@@ -83,12 +83,32 @@ impl SubscriptionRouter {
         }
 
         if subs_for_query.is_empty() {
+            // Stop tracking the query altogether, so that the router does not
+            // keep growing as subscriptions come and go.
+            self.subscriptions.remove(&query);
             PublishResult::AllDisconnected(query)
         } else {
             PublishResult::Success
         }
     }
 
+    /// Publishes the given error to all subscriptions, returning the queries
+    /// for which there are still subscribers afterwards.
+    #[cfg_attr(not(feature = "websocket-client"), allow(dead_code))]
+    pub fn publish_error_to_all(&mut self, err: Error) -> Vec<SubscriptionQuery> {
+        let queries: Vec<SubscriptionQuery> = self.subscriptions.keys().cloned().collect();
+
+        queries
+            .into_iter()
+            .filter(|query| {
+                matches!(
+                    self.publish(query.clone(), Err(err.clone())),
+                    PublishResult::Success
+                )
+            })
+            .collect()
+    }
+
     /// Immediately add a new subscription to the router without waiting for
     /// confirmation.
     pub fn add(&mut self, id: impl ToString, query: impl ToString, tx: SubscriptionTx) {
@@ -274,5 +294,36 @@ mod test {
             let subs3_ev = must_recv(&mut subs3_event_rx, 500).await.unwrap();
             assert_eq!(ev, subs3_ev);
         }
+
+        #[tokio::test]
+        async fn router_forgets_query_once_all_subscribers_dropped() {
+            let mut router = SubscriptionRouter::default();
+
+            let (subs1_event_tx, subs1_event_rx) = unbounded();
+            let (subs2_event_tx, mut subs2_event_rx) = unbounded();
+            router.add(uuid_str(), "query1", subs1_event_tx);
+            router.add(uuid_str(), "query2", subs2_event_tx);
+
+            // Dropping the receiving end of a subscription disconnects it
+            drop(subs1_event_rx);
+
+            let mut ev = read_event("subscribe_newblock_0").await;
+            ev.query = "query1".into();
+            let result = router.publish_event(ev.clone());
+            assert!(matches!(result, PublishResult::AllDisconnected(q) if q == "query1"));
+            assert!(!router.subscriptions.contains_key("query1"));
+
+            // Subsequent events for that query have nowhere to go
+            let result = router.publish_event(ev.clone());
+            assert!(matches!(result, PublishResult::NoSubscribers));
+
+            // Other queries are unaffected
+            ev.query = "query2".into();
+            assert!(matches!(
+                router.publish_event(ev.clone()),
+                PublishResult::Success
+            ));
+            assert_eq!(ev, must_recv(&mut subs2_event_rx, 500).await.unwrap());
+        }
     }
 }
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use async_tungstenite::{
     tokio::ConnectStream,
     tungstenite::{
+        self,
         protocol::{frame::coding::CloseCode, CloseFrame},
         Message,
     },
@@ -81,6 +82,19 @@ pub use async_tungstenite::tungstenite::protocol::WebSocketConfig;
 ///
 /// This is not configurable at present.
 ///
+/// ### Reconnection
+///
+/// By default, the driver terminates as soon as the connection is lost. If a
+/// [`ReconnectPolicy`] is given via [`Builder::reconnect`], the driver instead
+/// attempts to reconnect to the same endpoint with bounded exponential backoff,
+/// and re-issues the subscription requests for all active queries.
+///
+/// Since events may have been missed in the meantime, every active
+/// subscription first receives an error describing the disconnection, after
+/// which it carries on producing events as usual. If reconnecting fails after
+/// the configured number of attempts, the driver terminates which ends all
+/// subscriptions.
+///
 /// ### Keep-Alive
 ///
 /// The WebSocket client implements a keep-alive mechanism whereby it sends a
@@ -145,6 +159,7 @@ pub struct Builder {
     url: WebSocketClientUrl,
     compat: CompatMode,
     transport_config: Option<WebSocketConfig>,
+    reconnect: Option<ReconnectPolicy>,
 }
 
 impl Builder {
@@ -162,16 +177,31 @@ impl Builder {
         self
     }
 
+    /// Automatically reconnect when the connection is lost, with the given policy.
+    ///
+    /// The default is not to reconnect.
+    pub fn reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
+
     /// Try to create a client with the options specified for this builder.
     pub async fn build(self) -> Result<(WebSocketClient, WebSocketClientDriver), Error> {
         let url = self.url.0;
         let compat = self.compat;
-        let (inner, driver) = if url.is_secure() {
-            sealed::WebSocketClient::new_secure(url, compat, self.transport_config).await?
+        let (inner, mut driver) = if url.is_secure() {
+            sealed::WebSocketClient::new_secure(url.clone(), compat, self.transport_config).await?
         } else {
-            sealed::WebSocketClient::new_unsecure(url, compat, self.transport_config).await?
+            sealed::WebSocketClient::new_unsecure(url.clone(), compat, self.transport_config)
+                .await?
         };
 
+        driver.reconnect = self.reconnect.map(|policy| Reconnect {
+            url,
+            config: self.transport_config,
+            policy,
+        });
+
         Ok((WebSocketClient { inner, compat }, driver))
     }
 }
@@ -213,6 +243,7 @@ impl WebSocketClient {
             url,
             compat: Default::default(),
             transport_config: Default::default(),
+            reconnect: None,
         }
     }
 
@@ -366,6 +397,40 @@ impl SubscriptionClient for WebSocketClient {
     }
 }
 
+/// Policy governing how the [`WebSocketClientDriver`] reconnects after
+/// losing its connection. See [`Builder::reconnect`].
+///
+/// The delay before the `n`-th attempt is `base_delay * 2^(n - 1)`, capped at `max_delay`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Delay before the first reconnection attempt
+    pub base_delay: Duration,
+    /// Maximum delay between two reconnection attempts
+    pub max_delay: Duration,
+    /// Number of failed attempts after which the driver gives up
+    pub max_attempts: u32,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// The delay to wait for before the given attempt, starting at 1.
+    fn delay(&self, attempt: u32) -> Duration {
+        2_u32
+            .checked_pow(attempt.saturating_sub(1))
+            .and_then(|factor| self.base_delay.checked_mul(factor))
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
 /// A URL limited to use with WebSocket clients.
 ///
 /// Facilitates useful type conversions and inferences.
@@ -434,8 +499,11 @@ impl From<WebSocketClientUrl> for Url {
 
 mod sealed {
     use async_tungstenite::{
-        tokio::{connect_async_with_config, connect_async_with_tls_connector_and_config},
+        tokio::{
+            connect_async_with_config, connect_async_with_tls_connector_and_config, ConnectStream,
+        },
         tungstenite::client::IntoClientRequest,
+        WebSocketStream,
     };
     use tracing::debug;
 
@@ -493,11 +561,7 @@ mod sealed {
             compat: CompatMode,
             config: Option<WebSocketConfig>,
         ) -> Result<(Self, WebSocketClientDriver), Error> {
-            debug!("Connecting to unsecure WebSocket endpoint: {}", url);
-
-            let (stream, _response) = connect_async_with_config(url, config)
-                .await
-                .map_err(Error::tungstenite)?;
+            let stream = connect_unsecure(url, config).await?;
 
             let (cmd_tx, cmd_rx) = unbounded();
             let driver = WebSocketClientDriver::new(stream, cmd_rx, compat);
@@ -525,14 +589,7 @@ mod sealed {
             compat: CompatMode,
             config: Option<WebSocketConfig>,
         ) -> Result<(Self, WebSocketClientDriver), Error> {
-            debug!("Connecting to secure WebSocket endpoint: {}", url);
-
-            // Not supplying a connector means async_tungstenite will create the
-            // connector for us.
-            let (stream, _response) =
-                connect_async_with_tls_connector_and_config(url, None, config)
-                    .await
-                    .map_err(Error::tungstenite)?;
+            let stream = connect_secure(url, config).await?;
 
             let (cmd_tx, cmd_rx) = unbounded();
             let driver = WebSocketClientDriver::new(stream, cmd_rx, compat);
@@ -545,6 +602,36 @@ mod sealed {
         }
     }
 
+    /// Open a WebSocket connection over an unsecure connection.
+    pub async fn connect_unsecure(
+        url: Url,
+        config: Option<WebSocketConfig>,
+    ) -> Result<WebSocketStream<ConnectStream>, Error> {
+        debug!("Connecting to unsecure WebSocket endpoint: {}", url);
+
+        let (stream, _response) = connect_async_with_config(url, config)
+            .await
+            .map_err(Error::tungstenite)?;
+
+        Ok(stream)
+    }
+
+    /// Open a WebSocket connection over a secure connection.
+    pub async fn connect_secure(
+        url: Url,
+        config: Option<WebSocketConfig>,
+    ) -> Result<WebSocketStream<ConnectStream>, Error> {
+        debug!("Connecting to secure WebSocket endpoint: {}", url);
+
+        // Not supplying a connector means async_tungstenite will create the
+        // connector for us.
+        let (stream, _response) = connect_async_with_tls_connector_and_config(url, None, config)
+            .await
+            .map_err(Error::tungstenite)?;
+
+        Ok(stream)
+    }
+
     impl<C> AsyncTungsteniteClient<C> {
         fn send_cmd(&self, cmd: DriverCommand) -> Result<(), Error> {
             self.cmd_tx.send(cmd)
@@ -756,6 +843,27 @@ struct SimpleRequestCommand {
     response_tx: ChannelTx<Result<String, Error>>,
 }
 
+// What the driver needs to know in order to reconnect.
+#[derive(Debug, Clone)]
+struct Reconnect {
+    // The endpoint to reconnect to.
+    url: Url,
+    // Low-level WebSocket configuration to reconnect with.
+    config: Option<WebSocketConfig>,
+    // How to go about reconnecting.
+    policy: ReconnectPolicy,
+}
+
+impl Reconnect {
+    async fn connect(&self) -> Result<WebSocketStream<ConnectStream>, Error> {
+        if self.url.is_secure() {
+            sealed::connect_secure(self.url.clone(), self.config).await
+        } else {
+            sealed::connect_unsecure(self.url.clone(), self.config).await
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct GenericJsonResponse(serde_json::Value);
 
@@ -777,6 +885,8 @@ pub struct WebSocketClientDriver {
     pending_commands: HashMap<SubscriptionId, DriverCommand>,
     // The compatibility mode directing how to parse subscription events.
     compat: CompatMode,
+    // Whether and how to reconnect when the connection is lost.
+    reconnect: Option<Reconnect>,
 }
 
 impl WebSocketClientDriver {
@@ -791,6 +901,7 @@ impl WebSocketClientDriver {
             cmd_rx,
             pending_commands: HashMap::new(),
             compat,
+            reconnect: None,
         }
     }
 
@@ -823,33 +934,114 @@ impl WebSocketClientDriver {
         tokio::pin!(recv_timeout);
 
         loop {
-            tokio::select! {
-                Some(res) = self.stream.next() => match res {
-                    Ok(msg) => {
+            let disconnected = tokio::select! {
+                res = self.stream.next() => match res {
+                    Some(Ok(msg)) => {
                         // Reset the receive timeout every time we successfully
                         // receive a message from the remote endpoint.
                         recv_timeout.as_mut().reset(Instant::now().add(RECV_TIMEOUT));
-                        self.handle_incoming_msg(msg).await?
+                        self.handle_incoming_msg(msg).await?;
+                        None
                     },
-                    Err(e) => return Err(
+                    Some(Err(e)) => Some(
                         Error::web_socket(
                             "failed to read from WebSocket connection".to_string(),
                             e
                         ),
                     ),
+                    None => Some(
+                        Error::web_socket(
+                            "WebSocket connection closed by the remote endpoint".to_string(),
+                            tungstenite::Error::ConnectionClosed,
+                        ),
+                    ),
                 },
                 Some(cmd) = self.cmd_rx.recv() => match cmd {
-                    DriverCommand::Subscribe(subs_cmd) => self.subscribe(subs_cmd).await?,
-                    DriverCommand::Unsubscribe(unsubs_cmd) => self.unsubscribe(unsubs_cmd).await?,
-                    DriverCommand::SimpleRequest(req_cmd) => self.simple_request(req_cmd).await?,
+                    DriverCommand::Subscribe(subs_cmd) => self.subscribe(subs_cmd).await.err(),
+                    DriverCommand::Unsubscribe(unsubs_cmd) => self.unsubscribe(unsubs_cmd).await.err(),
+                    DriverCommand::SimpleRequest(req_cmd) => self.simple_request(req_cmd).await.err(),
                     DriverCommand::Terminate => return self.close().await,
                 },
-                _ = ping_interval.tick() => self.ping().await?,
-                _ = &mut recv_timeout => {
-                    return Err(Error::web_socket_timeout(RECV_TIMEOUT));
-                }
+                _ = ping_interval.tick() => self.ping().await.err(),
+                _ = &mut recv_timeout => Some(Error::web_socket_timeout(RECV_TIMEOUT)),
+            };
+
+            if let Some(e) = disconnected {
+                self.reconnect(e).await?;
+                recv_timeout
+                    .as_mut()
+                    .reset(Instant::now().add(RECV_TIMEOUT));
+            }
+        }
+    }
+
+    /// Attempts to recover from the given error, which caused the connection
+    /// to be lost, by reconnecting according to the reconnect policy and
+    /// re-subscribing to all active queries.
+    ///
+    /// Returns the given error if reconnecting is disabled, or the last
+    /// connection error if all attempts failed.
+    async fn reconnect(&mut self, err: Error) -> Result<(), Error> {
+        let reconnect = match &self.reconnect {
+            Some(reconnect) => reconnect.clone(),
+            None => return Err(err),
+        };
+
+        error!("WebSocket connection lost, reconnecting: {}", err);
+
+        // Let subscribers know that they may have missed events. Queries
+        // whose subscribers have all disconnected need not be resumed.
+        let queries = self.router.publish_error_to_all(err.clone());
+
+        // Requests in flight will never be answered. Subscribe requests are
+        // re-issued once reconnected, and unsubscribe requests are moot since
+        // the new connection starts without any subscriptions.
+        let mut pending_subscribes = Vec::new();
+        for (_, cmd) in core::mem::take(&mut self.pending_commands) {
+            match cmd {
+                DriverCommand::Subscribe(cmd) => pending_subscribes.push(cmd),
+                DriverCommand::Unsubscribe(cmd) => {
+                    let _ = cmd.response_tx.send(Ok(()));
+                },
+                DriverCommand::SimpleRequest(cmd) => {
+                    let _ = cmd.response_tx.send(Err(err.clone()));
+                },
+                DriverCommand::Terminate => {},
+            }
+        }
+
+        let mut attempt = 0;
+        self.stream = loop {
+            attempt += 1;
+            tokio::time::sleep(reconnect.policy.delay(attempt)).await;
+
+            match reconnect.connect().await {
+                Ok(stream) => break stream,
+                Err(e) if attempt >= reconnect.policy.max_attempts => {
+                    error!("Giving up reconnecting after {} attempts: {}", attempt, e);
+                    for cmd in pending_subscribes {
+                        let _ = cmd.response_tx.send(Err(e.clone()));
+                    }
+                    return Err(e);
+                },
+                Err(e) => debug!("Reconnection attempt {} failed: {}", attempt, e),
             }
+        };
+
+        debug!("Reconnected after {} attempt(s)", attempt);
+
+        // We do not keep track of these requests: whatever the outcome, events
+        // for these queries are routed by query rather than by request ID.
+        for query in queries {
+            self.send_request(Wrapper::new(subscribe::Request::new(query)))
+                .await?;
         }
+
+        for cmd in pending_subscribes {
+            self.subscribe(cmd).await?;
+        }
+
+        Ok(())
     }
 
     async fn send_request<R>(&mut self, wrapper: Wrapper<R>) -> Result<(), Error>
@@ -1069,6 +1261,7 @@ mod test {
         driver_hdl: JoinHandle<Result<(), Error>>,
         terminate_tx: ChannelTx<Result<(), Error>>,
         event_tx: ChannelTx<Event>,
+        disconnect_tx: ChannelTx<()>,
     }
 
     // A setting telling which of the CometBFT server versions to emulate
@@ -1091,13 +1284,16 @@ mod test {
             };
             let (terminate_tx, terminate_rx) = unbounded();
             let (event_tx, event_rx) = unbounded();
-            let driver = TestServerDriver::new(listener, version, event_rx, terminate_rx);
+            let (disconnect_tx, disconnect_rx) = unbounded();
+            let driver =
+                TestServerDriver::new(listener, version, event_rx, terminate_rx, disconnect_rx);
             let driver_hdl = tokio::spawn(async move { driver.run().await });
             Self {
                 node_addr,
                 driver_hdl,
                 terminate_tx,
                 event_tx,
+                disconnect_tx,
             }
         }
 
@@ -1105,6 +1301,11 @@ mod test {
             self.event_tx.send(ev)
         }
 
+        // Closes all the connections, while still accepting new ones.
+        fn disconnect_clients(&mut self) -> Result<(), Error> {
+            self.disconnect_tx.send(())
+        }
+
         async fn terminate(self) -> Result<(), Error> {
             self.terminate_tx.send(Ok(())).unwrap();
             self.driver_hdl.await.unwrap()
@@ -1117,6 +1318,7 @@ mod test {
         version: TestRpcVersion,
         event_rx: ChannelRx<Event>,
         terminate_rx: ChannelRx<Result<(), Error>>,
+        disconnect_rx: ChannelRx<()>,
         handlers: Vec<TestServerHandler>,
     }
 
@@ -1126,12 +1328,14 @@ mod test {
             version: TestRpcVersion,
             event_rx: ChannelRx<Event>,
             terminate_rx: ChannelRx<Result<(), Error>>,
+            disconnect_rx: ChannelRx<()>,
         ) -> Self {
             Self {
                 listener,
                 version,
                 event_rx,
                 terminate_rx,
+                disconnect_rx,
                 handlers: Vec::new(),
             }
         }
@@ -1144,6 +1348,7 @@ mod test {
                         let (stream, _) = res.unwrap();
                         self.handle_incoming(stream).await
                     }
+                    Some(()) = self.disconnect_rx.recv() => self.terminate().await,
                     Some(res) = self.terminate_rx.recv() => {
                         self.terminate().await;
                         return res;
@@ -1513,6 +1718,77 @@ mod test {
                 );
             }
         }
+
+        fn reconnect_policy(max_attempts: u32) -> ReconnectPolicy {
+            ReconnectPolicy {
+                base_delay: Duration::from_millis(10),
+                max_delay: Duration::from_millis(50),
+                max_attempts,
+            }
+        }
+
+        #[tokio::test]
+        async fn websocket_client_resubscribes_after_reconnecting() {
+            let event1 = read_event("subscribe_newblock_0").await;
+            let event2 = read_event("subscribe_newblock_1").await;
+
+            let mut server = TestServer::new("127.0.0.1:0", TestRpcVersion::V0_37).await;
+            let url = server.node_addr.clone().try_into().unwrap();
+            let (client, driver) = WebSocketClient::builder(url)
+                .compat_mode(CompatMode::V0_37)
+                .reconnect(reconnect_policy(20))
+                .build()
+                .await
+                .unwrap();
+            let driver_handle = tokio::spawn(async move { driver.run().await });
+
+            let mut subs = client.subscribe(EventType::NewBlock.into()).await.unwrap();
+
+            server.publish_event(event1.clone()).unwrap();
+            assert_eq!(event1, subs.next().await.unwrap().unwrap());
+
+            // Subscribers are told about the gap in events
+            server.disconnect_clients().unwrap();
+            assert!(subs.next().await.unwrap().is_err());
+
+            // Once resubscribed, events flow again over the new connection
+            let mut resumed = None;
+            for _ in 0..50 {
+                server.publish_event(event2.clone()).unwrap();
+                if let Ok(Some(res)) =
+                    tokio::time::timeout(Duration::from_millis(100), subs.next()).await
+                {
+                    resumed = Some(res.unwrap());
+                    break;
+                }
+            }
+            assert_eq!(Some(event2), resumed);
+
+            client.close().unwrap();
+            server.terminate().await.unwrap();
+            let _ = driver_handle.await.unwrap();
+        }
+
+        #[tokio::test]
+        async fn websocket_client_gives_up_reconnecting() {
+            let server = TestServer::new("127.0.0.1:0", TestRpcVersion::V0_37).await;
+            let url = server.node_addr.clone().try_into().unwrap();
+            let (client, driver) = WebSocketClient::builder(url)
+                .compat_mode(CompatMode::V0_37)
+                .reconnect(reconnect_policy(2))
+                .build()
+                .await
+                .unwrap();
+            let driver_handle = tokio::spawn(async move { driver.run().await });
+
+            let mut subs = client.subscribe(EventType::NewBlock.into()).await.unwrap();
+
+            // The server going away for good ends the subscription
+            server.terminate().await.unwrap();
+            assert!(subs.next().await.unwrap().is_err());
+            assert!(subs.next().await.is_none());
+            assert!(driver_handle.await.unwrap().is_err());
+        }
     }
 
     mod v0_38 {
@@ -8,6 +8,7 @@ pub mod block_results;
 pub mod block_search;
 pub mod blockchain;
 pub mod broadcast;
+pub mod check_tx;
 pub mod commit;
 pub mod consensus_params;
 pub mod consensus_state;
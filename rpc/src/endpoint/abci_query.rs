@@ -1,7 +1,14 @@
 //! `/abci_query` endpoint JSON-RPC wrapper
 
 use serde::{Deserialize, Serialize};
-use tendermint::{abci::Code, block, merkle::proof::ProofOps, serializers};
+use tendermint::{
+    abci::Code,
+    block,
+    crypto::Sha256,
+    hash::Algorithm,
+    merkle::{proof::ProofOps, MerkleHash},
+    serializers, AppHash, Hash,
+};
 
 use crate::prelude::*;
 use crate::{dialect::Dialect, request::RequestMessage};
@@ -101,3 +108,127 @@ pub struct AbciQuery {
     #[serde(default = "String::new")]
     pub codespace: String,
 }
+
+impl AbciQuery {
+    /// Verifies the proof that the returned value is stored under the given key path
+    /// in the application state whose Merkle root is `app_hash`, using hashers
+    /// provided by a crypto provider, eg. `tendermint::crypto::default::Sha256`.
+    ///
+    /// The key path goes from the outermost key to the innermost one, eg. the name
+    /// of a store then the key within that store, see [`ProofOps::verify_with`].
+    /// Its last key must be the requested one: the response is rejected otherwise,
+    /// even with a valid proof, as it would prove the value of another key.
+    ///
+    /// The query must have been made with `prove` set. The app hash of the state
+    /// at `height` is found in the header of the next block, which should itself
+    /// have been verified beforehand, eg. with the light client.
+    pub fn verify_with<H, K>(
+        &self,
+        app_hash: &AppHash,
+        key_path: &[K],
+    ) -> Result<(), tendermint::Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+        K: AsRef<[u8]>,
+    {
+        let requested = key_path.last().map_or(&[][..], AsRef::as_ref);
+        if self.key != requested {
+            return Err(tendermint::Error::query_key_mismatch(
+                requested.to_vec(),
+                self.key.clone(),
+            ));
+        }
+
+        let proof = self.proof.as_ref().ok_or_else(|| {
+            tendermint::Error::invalid_proof("no proof in the query response".to_string())
+        })?;
+
+        let root = Hash::from_bytes(Algorithm::Sha256, app_hash.as_bytes())?;
+
+        proof.verify_with::<H, K>(root, key_path, &self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint::{crypto::default::Sha256, error::ErrorDetail};
+
+    use super::*;
+    use crate::Response as _;
+
+    const RESPONSE: &str = r#"{
+        "jsonrpc": "2.0",
+        "id": "",
+        "result": {
+            "response": {
+                "code": 0,
+                "log": "",
+                "info": "",
+                "index": "0",
+                "key": "YnJhdm8=",
+                "value": "Mg==",
+                "proofOps": {
+                    "ops": [
+                        {
+                            "type": "simple:v",
+                            "key": "YnJhdm8=",
+                            "data": "CgVicmF2bxKMAQgFEAEaIJuUyE/WYkyg969B0A/jpU7NWGBsEXo3SDrzqxq3oIt/IiAAKBhW1DGL6oH4fbkF603XJSgjTQ0eSTLrDP1OnuJUtCIg3rC+ttWf4lnQWkQEuAiHF49QgFdp3UGJ+Fj9NNtJfcIiIL/wTltER59t8QdQY1Kl8JsDVxDa+oJs8o2d+JGEc1Ho"
+                        },
+                        {
+                            "type": "simple:v",
+                            "key": "bWFpbg==",
+                            "data": "CgRtYWluEmoIAxABGiD/zAaLZ8X0wVmO94MK266XHVAz9XvizFaWvAcXAdW22SIgoUwDFt6P7rT0sVSsIxf30MlltSEo4mUXmbD4hiInr7wiIHzdUDMb8zL2Hz2cyWPS0RlpMCjaPb4tBYNY2Wmhph3Y"
+                        }
+                    ]
+                },
+                "height": "42",
+                "codespace": ""
+            }
+        }
+    }"#;
+
+    const APP_HASH: &str = "A119851F798D1344B8C59975CDB0F561F9D4461A491FFC9507DD5F88007029DC";
+
+    #[test]
+    fn verify_query_response() {
+        let query = Response::from_string(RESPONSE).unwrap().response;
+        let app_hash = AppHash::from_hex_upper(APP_HASH).unwrap();
+
+        let key_path = ["main", "bravo"];
+        query
+            .verify_with::<Sha256, _>(&app_hash, &key_path)
+            .unwrap();
+
+        let mut forged = query.clone();
+        forged.value = b"3".to_vec();
+        let err = forged
+            .verify_with::<Sha256, _>(&app_hash, &key_path)
+            .unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::InvalidProof(_)));
+
+        let mut unproven = query;
+        unproven.proof = None;
+        let err = unproven
+            .verify_with::<Sha256, _>(&app_hash, &key_path)
+            .unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::InvalidProof(_)));
+    }
+
+    #[test]
+    fn verify_rejects_response_for_another_key() {
+        // A valid proof for `bravo` returned to a query for `alpha`
+        let query = Response::from_string(RESPONSE).unwrap().response;
+        let app_hash = AppHash::from_hex_upper(APP_HASH).unwrap();
+
+        let err = query
+            .verify_with::<Sha256, _>(&app_hash, &["main", "alpha"])
+            .unwrap_err();
+        match err.detail() {
+            ErrorDetail::QueryKeyMismatch(e) => {
+                assert_eq!(e.requested, b"alpha");
+                assert_eq!(e.returned, b"bravo");
+            },
+            _ => panic!("expected a query key mismatch, got: {err}"),
+        }
+    }
+}
@@ -57,10 +57,17 @@ where
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Response {
     /// `CheckTx` result
+    ///
+    /// If its code is not OK, the transaction was rejected from the mempool,
+    /// and was neither committed nor executed.
     pub check_tx: abci::response::CheckTx,
 
     /// Result of executing the transaction.
     ///
+    /// This is left empty, with an OK code, if the transaction was rejected
+    /// by `CheckTx`. Otherwise, a non-OK code means that the transaction was
+    /// committed but failed to execute.
+    ///
     /// The JSON field carrying this data is named `deliver_tx` in
     /// CometBFT versions before 0.38.
     #[serde(alias = "deliver_tx")]
@@ -149,3 +156,55 @@ pub mod v0_37 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tendermint::abci::Code;
+
+    use super::*;
+    use crate::Response as _;
+
+    fn rejected_response(deliver_tx_field: &str) -> String {
+        format!(
+            r#"{{
+                "jsonrpc": "2.0",
+                "id": "",
+                "result": {{
+                    "check_tx": {{
+                        "code": 1,
+                        "codespace": "",
+                        "data": null,
+                        "events": [],
+                        "gas_used": "0",
+                        "gas_wanted": "0",
+                        "info": "",
+                        "log": "invalid transaction"
+                    }},
+                    "{deliver_tx_field}": {{}},
+                    "hash": "D63F9C23791E610410B576D8C27BB5AEAC93CC1A58522428A7B32A1276085860",
+                    "height": "0"
+                }}
+            }}"#
+        )
+    }
+
+    fn assert_rejected(response: Response) {
+        assert_eq!(response.check_tx.code, Code::Err(1.try_into().unwrap()));
+        assert_eq!(response.check_tx.log, "invalid transaction");
+        assert_eq!(response.tx_result, abci::types::ExecTxResult::default());
+        assert_eq!(response.height.value(), 0);
+    }
+
+    #[test]
+    fn deserialize_rejected_tx() {
+        let response = Response::from_string(rejected_response("tx_result")).unwrap();
+        assert_rejected(response);
+    }
+
+    #[test]
+    fn deserialize_rejected_tx_v0_34() {
+        let response =
+            v0_34::DialectResponse::from_string(rejected_response("deliver_tx")).unwrap();
+        assert_rejected(response.into());
+    }
+}
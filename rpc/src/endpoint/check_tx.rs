@@ -0,0 +1,91 @@
+//! `/check_tx`: checks a transaction with the application, without adding it
+//! to the mempool.
+
+use serde::{Deserialize, Serialize};
+
+use tendermint::abci;
+
+use crate::dialect::{self, Dialect};
+use crate::{prelude::*, request::RequestMessage, serializers};
+
+/// `/check_tx`: checks a transaction with the application, without adding it
+/// to the mempool.
+///
+/// The transaction is neither broadcast nor committed, whatever the result.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Request {
+    /// Transaction to check
+    #[serde(with = "serializers::bytes::base64string")]
+    pub tx: Vec<u8>,
+}
+
+impl Request {
+    /// Create a new transaction check RPC request
+    pub fn new(tx: impl Into<Vec<u8>>) -> Request {
+        Request { tx: tx.into() }
+    }
+}
+
+impl RequestMessage for Request {
+    fn method(&self) -> crate::Method {
+        crate::Method::CheckTx
+    }
+}
+
+impl crate::Request<dialect::v0_34::Dialect> for Request {
+    type Response = self::v0_34::DialectResponse;
+}
+
+impl crate::Request<dialect::v0_37::Dialect> for Request {
+    type Response = Response;
+}
+
+impl crate::Request<dialect::v0_38::Dialect> for Request {
+    type Response = Response;
+}
+
+impl<S: Dialect> crate::SimpleRequest<S> for Request
+where
+    Self: crate::Request<S>,
+    Response: From<Self::Response>,
+{
+    type Output = Response;
+}
+
+/// Response from `/check_tx`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Response {
+    /// `CheckTx` result
+    ///
+    /// If its code is not OK, the transaction would be rejected from the mempool.
+    pub check_tx: abci::response::CheckTx,
+}
+
+impl crate::Response for Response {}
+
+/// Serialization for /check_tx endpoint format in Tendermint 0.34
+pub mod v0_34 {
+    use super::Response;
+    use crate::dialect;
+    use crate::dialect::v0_34::Event;
+    use serde::{Deserialize, Serialize};
+
+    /// RPC dialect helper for serialization of the response.
+    #[derive(Debug, Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct DialectResponse {
+        /// `CheckTx` result
+        pub check_tx: dialect::CheckTx<Event>,
+    }
+
+    impl crate::Response for DialectResponse {}
+
+    impl From<DialectResponse> for Response {
+        fn from(msg: DialectResponse) -> Self {
+            Self {
+                check_tx: msg.check_tx.into(),
+            }
+        }
+    }
+}
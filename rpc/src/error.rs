@@ -49,6 +49,22 @@ define_error! {
             [ ReqwestError ]
             | _ | { "Invalid proxy configuration" },
 
+        InvalidHeader
+            {
+                name: String
+            }
+            | e | {
+                format_args!("invalid HTTP header: {}", e.name)
+            },
+
+        TlsCertificate
+            {
+                reason: String
+            }
+            | e | {
+                format_args!("TLS certificate verification failed: {}", e.reason)
+            },
+
         InvalidParams
             {
                 message: String
@@ -91,6 +107,14 @@ define_error! {
                 format_args!("HTTP request failed with non-200 status code: {}", e.status)
             },
 
+        ResponseTooLarge
+            {
+                max_size: usize,
+            }
+            | e | {
+                format_args!("HTTP response body exceeds the maximum size of {} bytes", e.max_size)
+            },
+
         Parse
             {
                 reason: String
@@ -183,6 +207,16 @@ define_error! {
         MalformedJson
             | _ | { "server returned malformatted JSON (no 'result' or 'error')" },
 
+        ValidatorsCountMismatch
+            {
+                total: i32,
+                received: usize,
+            }
+            | e | {
+                format_args!("server reported {} validators in total, but returned {} across all pages",
+                    e.total, e.received)
+            },
+
         UnsupportedScheme
             {
                 scheme: String
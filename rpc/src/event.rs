@@ -2,10 +2,39 @@
 
 use alloc::collections::BTreeMap as HashMap;
 
-use tendermint::{abci, block, Block};
+use serde::{de::Error as _, Deserialize, Deserializer};
+use tendermint::{abci, block, validator, Block};
 
 use crate::{prelude::*, query::EventType};
 
+/// The types of events whose data is deserialized into a dedicated [`EventData`] variant.
+const KNOWN_EVENT_TYPES: &[&str] = &["NewBlock", "Tx", "ValidatorSetUpdates", "GenericJsonEvent"];
+
+/// Deserialize the data of an event with `T` if the type of the event is known,
+/// or keep it as JSON with `generic` otherwise, instead of failing.
+fn deserialize_event_data<'de, D, T>(
+    deserializer: D,
+    generic: impl FnOnce(serde_json::Value) -> T,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+
+    let known = value
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .map(|ty| ty.strip_prefix("tendermint/event/").unwrap_or(ty))
+        .is_some_and(|ty| KNOWN_EVENT_TYPES.contains(&ty));
+
+    if known {
+        serde_json::from_value(value).map_err(D::Error::custom)
+    } else {
+        Ok(generic(value))
+    }
+}
+
 /// An incoming event produced by a [`Subscription`].
 ///
 /// [`Subscription`]: ../struct.Subscription.html
@@ -27,6 +56,7 @@ impl Event {
         match self.data {
             EventData::NewBlock { .. } => Some(EventType::NewBlock),
             EventData::Tx { .. } => Some(EventType::Tx),
+            EventData::ValidatorSetUpdates { .. } => Some(EventType::ValidatorSetUpdates),
             _ => None,
         }
     }
@@ -53,6 +83,11 @@ pub enum EventData {
     Tx {
         tx_result: TxInfo,
     },
+    /// Updates of the validator set, to take effect at the next height but one.
+    ValidatorSetUpdates {
+        validator_updates: Vec<validator::Info>,
+    },
+    /// Data of events of other types, as JSON.
     GenericJsonEvent(serde_json::Value),
 }
 
@@ -81,14 +116,15 @@ pub mod v0_34 {
     use crate::prelude::*;
     use crate::{dialect, serializers, Response};
     use alloc::collections::BTreeMap as HashMap;
-    use serde::{Deserialize, Serialize};
-    use tendermint::Block;
+    use serde::{Deserialize, Deserializer, Serialize};
+    use tendermint::{validator, Block};
 
     #[derive(Serialize, Deserialize, Debug)]
     pub struct DialectEvent {
         /// The query that produced the event.
         pub query: String,
         /// The data associated with the event.
+        #[serde(deserialize_with = "deserialize_event_data")]
         pub data: DialectEventData,
         /// Event type and attributes map.
         pub events: Option<HashMap<String, Vec<String>>>,
@@ -134,9 +170,20 @@ pub mod v0_34 {
             #[serde(rename = "TxResult")]
             tx_result: DialectTxInfo,
         },
+        #[serde(alias = "tendermint/event/ValidatorSetUpdates")]
+        ValidatorSetUpdates {
+            validator_updates: Vec<validator::Info>,
+        },
         GenericJsonEvent(serde_json::Value),
     }
 
+    fn deserialize_event_data<'de, D>(deserializer: D) -> Result<DialectEventData, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize_event_data(deserializer, DialectEventData::GenericJsonEvent)
+    }
+
     impl From<DialectEventData> for EventData {
         fn from(msg: DialectEventData) -> Self {
             match msg {
@@ -152,6 +199,9 @@ pub mod v0_34 {
                 DialectEventData::Tx { tx_result } => EventData::Tx {
                     tx_result: tx_result.into(),
                 },
+                DialectEventData::ValidatorSetUpdates { validator_updates } => {
+                    EventData::ValidatorSetUpdates { validator_updates }
+                },
                 DialectEventData::GenericJsonEvent(v) => EventData::GenericJsonEvent(v),
             }
         }
@@ -183,6 +233,9 @@ pub mod v0_34 {
                 EventData::Tx { tx_result } => DialectEventData::Tx {
                     tx_result: tx_result.into(),
                 },
+                EventData::ValidatorSetUpdates { validator_updates } => {
+                    DialectEventData::ValidatorSetUpdates { validator_updates }
+                },
                 EventData::GenericJsonEvent(v) => DialectEventData::GenericJsonEvent(v),
             }
         }
@@ -257,15 +310,16 @@ mod latest {
     use crate::prelude::*;
     use crate::{serializers, Response};
     use alloc::collections::BTreeMap as HashMap;
-    use serde::{Deserialize, Serialize};
+    use serde::{Deserialize, Deserializer, Serialize};
     use tendermint::abci::Event as RpcEvent;
-    use tendermint::{abci, block, Block};
+    use tendermint::{abci, block, validator, Block};
 
     #[derive(Deserialize, Debug)]
     pub struct DeEvent {
         /// The query that produced the event.
         pub query: String,
         /// The data associated with the event.
+        #[serde(deserialize_with = "deserialize_event_data")]
         pub data: DeEventData,
         /// Event type and attributes map.
         pub events: Option<HashMap<String, Vec<String>>>,
@@ -305,9 +359,20 @@ mod latest {
             #[serde(rename = "TxResult")]
             tx_result: DialectTxInfo,
         },
+        #[serde(alias = "tendermint/event/ValidatorSetUpdates")]
+        ValidatorSetUpdates {
+            validator_updates: Vec<validator::Info>,
+        },
         GenericJsonEvent(serde_json::Value),
     }
 
+    fn deserialize_event_data<'de, D>(deserializer: D) -> Result<DeEventData, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize_event_data(deserializer, DeEventData::GenericJsonEvent)
+    }
+
     impl From<DeEventData> for EventData {
         fn from(msg: DeEventData) -> Self {
             match msg {
@@ -336,6 +401,9 @@ mod latest {
                 DeEventData::Tx { tx_result } => EventData::Tx {
                     tx_result: tx_result.into(),
                 },
+                DeEventData::ValidatorSetUpdates { validator_updates } => {
+                    EventData::ValidatorSetUpdates { validator_updates }
+                },
                 DeEventData::GenericJsonEvent(v) => EventData::GenericJsonEvent(v),
             }
         }
@@ -410,7 +478,7 @@ pub mod v0_37 {
     use crate::prelude::*;
     use alloc::collections::BTreeMap as HashMap;
     use serde::Serialize;
-    use tendermint::{abci, Block};
+    use tendermint::{abci, validator, Block};
 
     pub use super::latest::*;
 
@@ -448,6 +516,10 @@ pub mod v0_37 {
             #[serde(rename = "TxResult")]
             tx_result: DialectTxInfo,
         },
+        #[serde(alias = "tendermint/event/ValidatorSetUpdates")]
+        ValidatorSetUpdates {
+            validator_updates: Vec<validator::Info>,
+        },
         GenericJsonEvent(serde_json::Value),
     }
 
@@ -477,6 +549,9 @@ pub mod v0_37 {
                 EventData::Tx { tx_result } => SerEventData::Tx {
                     tx_result: tx_result.into(),
                 },
+                EventData::ValidatorSetUpdates { validator_updates } => {
+                    SerEventData::ValidatorSetUpdates { validator_updates }
+                },
                 EventData::GenericJsonEvent(v) => SerEventData::GenericJsonEvent(v),
             }
         }
@@ -489,7 +564,7 @@ pub mod v0_38 {
     use crate::prelude::*;
     use alloc::collections::BTreeMap as HashMap;
     use serde::Serialize;
-    use tendermint::{abci, block, Block};
+    use tendermint::{abci, block, validator, Block};
 
     pub use super::latest::*;
 
@@ -527,6 +602,10 @@ pub mod v0_38 {
             #[serde(rename = "TxResult")]
             tx_result: DialectTxInfo,
         },
+        #[serde(alias = "tendermint/event/ValidatorSetUpdates")]
+        ValidatorSetUpdates {
+            validator_updates: Vec<validator::Info>,
+        },
         GenericJsonEvent(serde_json::Value),
     }
 
@@ -556,8 +635,89 @@ pub mod v0_38 {
                 EventData::Tx { tx_result } => SerEventData::Tx {
                     tx_result: tx_result.into(),
                 },
+                EventData::ValidatorSetUpdates { validator_updates } => {
+                    SerEventData::ValidatorSetUpdates { validator_updates }
+                },
                 EventData::GenericJsonEvent(v) => SerEventData::GenericJsonEvent(v),
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event_json(data: &str) -> String {
+        format!(r#"{{"query": "tm.event = 'NewEvent'", "data": {data}, "events": null}}"#)
+    }
+
+    #[test]
+    fn validator_set_updates() {
+        let json = event_json(
+            r#"{
+                "type": "tendermint/event/ValidatorSetUpdates",
+                "value": {
+                    "validator_updates": [{
+                        "address": "DD8A65495B6240145764A74E78CF203D51510371",
+                        "pub_key": {
+                            "type": "tendermint/PubKeyEd25519",
+                            "value": "OYpM2RXHEO1/R3jJRhAbjY8JhvjTBbiNJKBStEKu12s="
+                        },
+                        "voting_power": "10",
+                        "proposer_priority": "0"
+                    }]
+                }
+            }"#,
+        );
+
+        for event in [
+            serde_json::from_str::<v0_34::DeEvent>(&json)
+                .unwrap()
+                .into(),
+            serde_json::from_str::<v0_37::DeEvent>(&json)
+                .unwrap()
+                .into(),
+            serde_json::from_str::<v0_38::DeEvent>(&json)
+                .unwrap()
+                .into(),
+        ] {
+            let event: Event = event;
+            assert_eq!(event.event_type(), Some(EventType::ValidatorSetUpdates));
+
+            match event.data {
+                EventData::ValidatorSetUpdates { validator_updates } => {
+                    assert_eq!(validator_updates.len(), 1);
+                    assert_eq!(validator_updates[0].power(), 10);
+                },
+                data => panic!("expected validator set updates, got: {data:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_event_type_is_kept_as_json() {
+        let json = event_json(r#"{"type": "tendermint/event/NewRound", "value": {"round": 1}}"#);
+
+        let event: Event = serde_json::from_str::<v0_38::DeEvent>(&json)
+            .unwrap()
+            .into();
+        assert_eq!(event.event_type(), None);
+
+        match event.data {
+            EventData::GenericJsonEvent(value) => {
+                assert_eq!(value["type"], "tendermint/event/NewRound");
+                assert_eq!(value["value"]["round"], 1);
+            },
+            data => panic!("expected a generic event, got: {data:?}"),
+        }
+    }
+
+    #[test]
+    fn malformed_event_of_known_type_is_rejected() {
+        let json = event_json(r#"{"type": "tendermint/event/Tx", "value": {"round": 1}}"#);
+
+        assert!(serde_json::from_str::<v0_34::DeEvent>(&json).is_err());
+        assert!(serde_json::from_str::<v0_38::DeEvent>(&json).is_err());
+    }
+}
@@ -1,6 +1,9 @@
 //! JSON-RPC IDs
 
-use core::fmt;
+use core::{
+    fmt,
+    sync::atomic::{AtomicI64, Ordering},
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -23,6 +26,59 @@ impl Id {
     pub fn uuid_v4() -> Self {
         Self::Str(uuid_str())
     }
+
+    /// Whether this ID identifies the same request as the given one.
+    ///
+    /// Some servers echo a string ID back as a number, or vice versa, so a
+    /// numerical ID matches a string ID holding its decimal representation.
+    pub fn matches(&self, other: &Id) -> bool {
+        match (self, other) {
+            (Id::Num(n), Id::Str(s)) | (Id::Str(s), Id::Num(n)) => *s == n.to_string(),
+            _ => self == other,
+        }
+    }
+}
+
+/// Source of the IDs given to outgoing JSON-RPC requests.
+///
+/// This allows, for instance, deriving IDs from a tracing context so that
+/// requests can be correlated with the remote endpoint's logs.
+pub trait IdGenerator: fmt::Debug + Send + Sync {
+    /// The ID to use for the next request.
+    fn next_id(&self) -> Id;
+}
+
+/// Generates random [UUIDv4] string IDs. This is the default.
+///
+/// [UUIDv4]: https://en.wikipedia.org/wiki/Universally_unique_identifier#Version_4_(random)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UuidGenerator;
+
+impl IdGenerator for UuidGenerator {
+    fn next_id(&self) -> Id {
+        Id::uuid_v4()
+    }
+}
+
+/// Generates numerical IDs, incremented by one at each request.
+#[derive(Debug, Default)]
+pub struct SequentialGenerator {
+    next: AtomicI64,
+}
+
+impl SequentialGenerator {
+    /// Create a generator whose first ID is the given number.
+    pub fn starting_at(first: i64) -> Self {
+        Self {
+            next: AtomicI64::new(first),
+        }
+    }
+}
+
+impl IdGenerator for SequentialGenerator {
+    fn next_id(&self) -> Id {
+        Id::Num(self.next.fetch_add(1, Ordering::Relaxed))
+    }
 }
 
 impl fmt::Display for Id {
@@ -61,6 +117,24 @@ mod tests {
         serialization_roundtrip::<Id>(null);
     }
 
+    #[test]
+    fn numerical_and_string_ids_match() {
+        assert!(Id::Num(42).matches(&Id::Str("42".to_string())));
+        assert!(Id::Str("42".to_string()).matches(&Id::Num(42)));
+        assert!(Id::Num(42).matches(&Id::Num(42)));
+        assert!(!Id::Num(42).matches(&Id::Str("042x".to_string())));
+        assert!(!Id::Num(42).matches(&Id::Num(43)));
+        assert!(!Id::Str(String::new()).matches(&Id::None));
+    }
+
+    #[test]
+    fn sequential_ids_are_incremented() {
+        let generator = SequentialGenerator::starting_at(7);
+        assert_eq!(generator.next_id(), Id::Num(7));
+        assert_eq!(generator.next_id(), Id::Num(8));
+        assert_ne!(UuidGenerator.next_id(), UuidGenerator.next_id());
+    }
+
     fn serialization_roundtrip<T>(json_data: &str)
     where
         T: Debug + PartialEq + Serialize + DeserializeOwned,
@@ -15,6 +15,12 @@
 //!   including general RPC functionality as well as [`event::Event`] subscription functionality.
 //!   Can be used over secure (`wss://`) and unsecure (`ws://`) connections.
 //!
+//! All of these clients are asynchronous: the methods of the [`Client`] trait return
+//! futures, which are meant to be driven by a Tokio runtime, without blocking a thread
+//! for each request in flight. The request and response types of the [`endpoint`]s
+//! are shared by all transports, so both features can be enabled, and both clients
+//! used, in the same binary. See `examples/fetch_block.rs` for an example.
+//!
 //! ### Mock Clients
 //!
 //! Mock clients are included when either of the `http-client` or
@@ -47,6 +53,7 @@ pub use client::{MockClient, MockRequestMatcher, MockRequestMethodMatcher};
 #[cfg(feature = "websocket-client")]
 pub use client::{WebSocketClient, WebSocketClientDriver, WebSocketClientUrl, WebSocketConfig};
 
+pub mod batch;
 pub mod dialect;
 pub mod endpoint;
 pub mod error;
@@ -65,7 +72,7 @@ mod utils;
 mod version;
 
 pub use error::Error;
-pub use id::Id;
+pub use id::{Id, IdGenerator, SequentialGenerator, UuidGenerator};
 pub use method::Method;
 pub use order::Order;
 pub use paging::{PageNumber, Paging, PerPage};
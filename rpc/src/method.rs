@@ -44,6 +44,9 @@ pub enum Method {
     /// Broadcast transaction commit
     BroadcastTxCommit,
 
+    /// Check transaction without adding it to the mempool
+    CheckTx,
+
     /// Get commit info for a block
     Commit,
 
@@ -108,6 +111,7 @@ impl Method {
             Method::BroadcastTxAsync => "broadcast_tx_async",
             Method::BroadcastTxSync => "broadcast_tx_sync",
             Method::BroadcastTxCommit => "broadcast_tx_commit",
+            Method::CheckTx => "check_tx",
             Method::Commit => "commit",
             Method::ConsensusParams => "consensus_params",
             Method::ConsensusState => "consensus_state",
@@ -145,6 +149,7 @@ impl FromStr for Method {
             "broadcast_tx_async" => Method::BroadcastTxAsync,
             "broadcast_tx_sync" => Method::BroadcastTxSync,
             "broadcast_tx_commit" => Method::BroadcastTxCommit,
+            "check_tx" => Method::CheckTx,
             "commit" => Method::Commit,
             "consensus_params" => Method::ConsensusParams,
             "consensus_state" => Method::ConsensusState,
@@ -216,8 +216,9 @@ peg::parser! {
         // At least some whitespace.
         rule __() = quiet!{[' ']+}
 
-        rule string() -> &'input str
-            = "'" s:$([^'\'']*) "'" { s }
+        // Backslashes and single quotes may be escaped with a backslash.
+        rule string() -> String
+            = "'" s:$(("\\" ['\\' | '\''] / [^'\''])*) "'" { unescape(s) }
 
         rule unsigned() -> u64
             = s:$(['0'..='9']+) {?
@@ -265,7 +266,7 @@ peg::parser! {
             }
 
         rule string_op() -> Operand
-            = s:string() { Operand::String(s.to_owned()) }
+            = s:string() { Operand::String(s) }
 
         rule unsigned_op() -> Operand
             = u:unsigned() { Operand::Unsigned(u) }
@@ -312,7 +313,7 @@ peg::parser! {
             = t:tag() _ ">" _ op:operand() { Condition::gt(t.to_owned(), op) }
 
         rule contains() -> Condition
-            = t:tag() __ "CONTAINS" __ op:string() { Condition::contains(t.to_owned(), op.to_owned()) }
+            = t:tag() __ "CONTAINS" __ op:string() { Condition::contains(t.to_owned(), op) }
 
         rule exists() -> Condition
             = t:tag() __ "EXISTS" { Condition::exists(t.to_owned()) }
@@ -397,6 +398,7 @@ where
 pub enum EventType {
     NewBlock,
     Tx,
+    ValidatorSetUpdates,
 }
 
 impl fmt::Display for EventType {
@@ -404,6 +406,7 @@ impl fmt::Display for EventType {
         match self {
             EventType::NewBlock => write!(f, "NewBlock"),
             EventType::Tx => write!(f, "Tx"),
+            EventType::ValidatorSetUpdates => write!(f, "ValidatorSetUpdates"),
         }
     }
 }
@@ -415,6 +418,7 @@ impl FromStr for EventType {
         match s {
             "NewBlock" => Ok(Self::NewBlock),
             "Tx" => Ok(Self::Tx),
+            "ValidatorSetUpdates" => Ok(Self::ValidatorSetUpdates),
             invalid => Err(Error::unrecognized_event_type(invalid.to_string())),
         }
     }
@@ -659,6 +663,22 @@ fn escape(s: &str) -> String {
     format!("'{result}'")
 }
 
+/// Remove the backslashes escaping backslashes and single quotes within the given string.
+fn unescape(s: &str) -> String {
+    let mut result = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match chars.peek() {
+            Some(&escaped @ ('\\' | '\'')) if ch == '\\' => {
+                result.push(escaped);
+                chars.next();
+            },
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod test {
     use time::macros::{date, datetime};
@@ -754,6 +774,15 @@ mod test {
             "tm.event = 'Tx' AND tx.height <= 100 AND meta.attr CONTAINS 'some-substring'",
             query.to_string()
         );
+
+        // Values are escaped, so that they cannot add conditions to the query
+        let query =
+            Query::gt("tx.height", 5_u64).and_eq("transfer.recipient", "x' OR tx.height > '0");
+        assert_eq!(
+            "tx.height > 5 AND transfer.recipient = 'x\\' OR tx.height > \\'0'",
+            query.to_string()
+        );
+        assert_eq!(query.to_string().parse::<Query>().unwrap(), query);
     }
 
     #[test]
@@ -803,6 +832,28 @@ mod test {
         );
     }
 
+    #[test]
+    fn query_escaped_string_term_parsing() {
+        let query = Query::from_str("key = '\\'value\\''").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![Condition::eq("key".to_owned(), "'value'".into())]
+        );
+
+        let query = Query::from_str("key = '\\\\\\'value\\''").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![Condition::eq("key".to_owned(), "\\'value'".into())]
+        );
+
+        // Other backslashes are kept as is
+        let query = Query::from_str("key CONTAINS 'a\\b'").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![Condition::contains("key".to_owned(), "a\\b".to_owned())]
+        );
+    }
+
     #[test]
     fn query_unsigned_term_parsing() {
         let query = Query::from_str("tm.event = 'Tx' AND tx.height = 10").unwrap();
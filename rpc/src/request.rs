@@ -73,7 +73,8 @@ where
         Self::new_with_id(Id::uuid_v4(), request)
     }
 
-    pub(crate) fn new_with_id(id: Id, request: R) -> Self {
+    /// Create a new request wrapper from the given request, with the given ID.
+    pub fn new_with_id(id: Id, request: R) -> Self {
         Self {
             jsonrpc: Version::current(),
             id,
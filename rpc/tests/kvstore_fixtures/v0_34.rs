@@ -121,6 +121,15 @@ fn outgoing_fixtures() {
                     base64::decode("c3luYy1rZXk9dmFsdWU=").unwrap()
                 );
             },
+            "check_tx" => {
+                let wrapped =
+                    serde_json::from_str::<RequestWrapper<endpoint::check_tx::Request>>(&content)
+                        .unwrap();
+                assert_eq!(
+                    wrapped.params().tx,
+                    base64::decode("Y2hlY2sta2V5PXZhbHVl").unwrap()
+                );
+            },
             "commit_at_height_10" => {
                 let wrapped =
                     serde_json::from_str::<RequestWrapper<endpoint::commit::Request>>(&content)
@@ -566,6 +575,20 @@ fn incoming_fixtures() {
                 );
                 assert!(result.log.is_empty());
             },
+            "check_tx" => {
+                let result: endpoint::check_tx::Response =
+                    endpoint::check_tx::v0_34::DialectResponse::from_string(content)
+                        .unwrap()
+                        .into();
+                assert_eq!(result.check_tx.code, abci::Code::Ok);
+                assert!(result.check_tx.codespace.is_empty());
+                assert!(result.check_tx.data.is_empty());
+                assert!(result.check_tx.events.is_empty());
+                assert_eq!(result.check_tx.gas_used, 0);
+                assert_eq!(result.check_tx.gas_wanted, 1);
+                assert!(result.check_tx.info.is_empty());
+                assert!(result.check_tx.log.is_empty());
+            },
             "commit_at_height_10" => {
                 let result = endpoint::commit::Response::from_string(content).unwrap();
                 assert!(!result.signed_header.commit.block_id.hash.is_empty());
@@ -440,6 +440,19 @@ fn incoming_fixtures() {
                 assert_eq!(result.total_count as usize, result.blocks.len());
                 for response in result.blocks {
                     assert!(response.block.header.height.value() > 1);
+                    assert_eq!(
+                        response.block.header.data_hash,
+                        Some(tendermint::tx::data_hash(&response.block.data))
+                    );
+
+                    // The kvstore application returns an empty result for the
+                    // single transaction of the block at height 44
+                    if response.block.header.height.value() == 45 {
+                        assert_eq!(
+                            response.block.header.last_results_hash,
+                            Some(abci::types::results_hash(&[Default::default()]))
+                        );
+                    }
                 }
             },
             "block_search_evidence" => {
@@ -448,6 +461,11 @@ fn incoming_fixtures() {
 
                 // Test a few selected attributes of the results.
                 for block in result.blocks {
+                    assert_eq!(
+                        block.block.header.evidence_hash,
+                        Some(block.block.evidence.hash())
+                    );
+
                     let evidence = block.block.evidence.iter().next().unwrap();
 
                     use tendermint::vote;
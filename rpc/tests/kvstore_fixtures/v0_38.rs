@@ -122,6 +122,15 @@ fn outgoing_fixtures() {
                     base64::decode("c3luYy1rZXk9dmFsdWU=").unwrap()
                 );
             },
+            "check_tx" => {
+                let wrapped =
+                    serde_json::from_str::<RequestWrapper<endpoint::check_tx::Request>>(&content)
+                        .unwrap();
+                assert_eq!(
+                    wrapped.params().tx,
+                    base64::decode("Y2hlY2sta2V5PXZhbHVl").unwrap()
+                );
+            },
             "commit_at_height_10" => {
                 let wrapped =
                     serde_json::from_str::<RequestWrapper<endpoint::commit::Request>>(&content)
@@ -451,6 +460,11 @@ fn incoming_fixtures() {
 
                 // Test a few selected attributes of the results.
                 for block in result.blocks {
+                    assert_eq!(
+                        block.block.header.evidence_hash,
+                        Some(block.block.evidence.hash())
+                    );
+
                     let evidence = block.block.evidence.iter().next().unwrap();
 
                     use tendermint::vote;
@@ -565,8 +579,19 @@ fn incoming_fixtures() {
                 );
                 assert!(result.log.is_empty());
             },
+            "check_tx" => {
+                let result = endpoint::check_tx::Response::from_string(content).unwrap();
+                assert_eq!(result.check_tx.code, abci::Code::Ok);
+                assert!(result.check_tx.codespace.is_empty());
+                assert!(result.check_tx.data.is_empty());
+                assert!(result.check_tx.events.is_empty());
+                assert_eq!(result.check_tx.gas_used, 0);
+                assert_eq!(result.check_tx.gas_wanted, 1);
+                assert!(result.check_tx.info.is_empty());
+                assert!(result.check_tx.log.is_empty());
+            },
             "commit_at_height_10" => {
-                let result = endpoint::commit::Response::from_string(content).unwrap();
+                let result = endpoint::commit::Response::from_string(&content).unwrap();
                 assert!(!result.signed_header.commit.block_id.hash.is_empty());
                 assert_eq!(result.signed_header.commit.height.value(), 10);
                 assert_eq!(result.signed_header.commit.round.value(), 0);
@@ -575,6 +600,11 @@ fn incoming_fixtures() {
                 assert!(result.signed_header.commit.signatures[0]
                     .validator_address()
                     .is_some());
+                let fixture: serde_json::Value = serde_json::from_str(&content).unwrap();
+                assert_eq!(
+                    serde_json::to_value(&result.signed_header.commit).unwrap(),
+                    fixture["result"]["signed_header"]["commit"]
+                );
                 assert_eq!(result.signed_header.header.app_hash.as_bytes(), [0u8; 8]);
                 assert_eq!(result.signed_header.header.chain_id.as_str(), CHAIN_ID);
                 assert!(!result.signed_header.header.consensus_hash.is_empty());
@@ -630,6 +660,15 @@ fn incoming_fixtures() {
                     result.consensus_params.validator.pub_key_types,
                     vec![public_key::Algorithm::Ed25519]
                 );
+                // The consensus hash in the headers of the blocks of the same chain
+                assert_eq!(
+                    result.consensus_params.hash(),
+                    Hash::from_hex_upper(
+                        Algorithm::Sha256,
+                        "048091BC7DDC283F77BFBF91D73C44DA58C3DF8A9CBC867405D8B7F3DAADA22F"
+                    )
+                    .unwrap()
+                );
             },
             "consensus_state" => {
                 assert!(endpoint::consensus_state::Response::from_string(content).is_ok());
@@ -0,0 +1,112 @@
+//! Limits on the size of the responses accepted by the HTTP client.
+
+#![cfg(feature = "http-client")]
+
+use tendermint_rpc::{
+    dialect::LatestDialect, endpoint::abci_info, error::ErrorDetail, Client, HttpClient,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+const ABCI_INFO: &str = include_str!("kvstore_fixtures/v0_38/incoming/abci_info.json");
+
+/// How the body of a response is sent by the server.
+#[derive(Clone, Copy)]
+enum Framing {
+    /// With its length given upfront in the `content-length` header.
+    ContentLength,
+    /// In chunks, without any length given upfront.
+    Chunked,
+}
+
+/// Serve a single HTTP connection on a local port, answering with the given
+/// body, and return the URL of the server.
+async fn serve_once(body: Vec<u8>, framing: Framing) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = [0; 4096];
+        let _ = stream.read(&mut buf).await;
+
+        let response = match framing {
+            Framing::ContentLength => {
+                let mut response =
+                    format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n", body.len())
+                        .into_bytes();
+                response.extend_from_slice(&body);
+                response
+            },
+            Framing::Chunked => {
+                let mut response =
+                    b"HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\n\r\n".to_vec();
+                for chunk in body.chunks(1024) {
+                    response.extend_from_slice(format!("{:x}\r\n", chunk.len()).as_bytes());
+                    response.extend_from_slice(chunk);
+                    response.extend_from_slice(b"\r\n");
+                }
+                response.extend_from_slice(b"0\r\n\r\n");
+                response
+            },
+        };
+
+        // The client may hang up before the whole response was written
+        let _ = stream.write_all(&response).await;
+        let _ = stream.shutdown().await;
+    });
+
+    format!("http://127.0.0.1:{port}")
+}
+
+async fn client(body: Vec<u8>, framing: Framing, max_response_size: usize) -> HttpClient {
+    let url = serve_once(body, framing).await;
+    HttpClient::builder(url.as_str().try_into().unwrap())
+        .max_response_size(max_response_size)
+        .build()
+        .unwrap()
+}
+
+fn assert_too_large(detail: &ErrorDetail, max_size: usize) {
+    match detail {
+        ErrorDetail::ResponseTooLarge(e) => assert_eq!(e.max_size, max_size),
+        e => panic!("expected a response too large error, got: {e:?}"),
+    }
+}
+
+#[tokio::test]
+async fn accepts_response_within_limit() {
+    for framing in [Framing::ContentLength, Framing::Chunked] {
+        let body = ABCI_INFO.as_bytes().to_vec();
+        let client = client(body, framing, ABCI_INFO.len()).await;
+
+        let response = client.abci_info().await.unwrap();
+        assert_eq!(response.data, "{\"size\":0}");
+    }
+}
+
+#[tokio::test]
+async fn rejects_oversized_response() {
+    // Well-formed JSON, whose parsing is never attempted
+    let body = format!("{{\"data\":\"{}\"}}", "a".repeat(1024 * 1024)).into_bytes();
+
+    for framing in [Framing::ContentLength, Framing::Chunked] {
+        let client = client(body.clone(), framing, 64 * 1024).await;
+
+        let err = client.abci_info().await.unwrap_err();
+        assert_too_large(err.detail(), 64 * 1024);
+    }
+}
+
+#[tokio::test]
+async fn rejects_oversized_batch_response() {
+    let client = client(ABCI_INFO.as_bytes().to_vec(), Framing::Chunked, 16).await;
+
+    let mut batch = client.new_batch::<LatestDialect>();
+    batch.add(abci_info::Request);
+
+    let err = client.batch(batch).await.unwrap_err();
+    assert_too_large(err.detail(), 16);
+}
@@ -0,0 +1,73 @@
+//! Timestamps captured from real nodes survive a round trip through `Time`
+//! byte for byte, as they must for the sign bytes of votes to be reproduced.
+
+use std::{fs, path::PathBuf};
+
+use serde_json::Value;
+use tendermint::Time;
+use walkdir::WalkDir;
+
+fn find_fixtures() -> Vec<PathBuf> {
+    WalkDir::new(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests"))
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| {
+            e.file_type().is_file()
+                && e.path().extension().is_some()
+                && e.path().extension().unwrap() == "json"
+        })
+        .map(|e| e.into_path())
+        .collect::<Vec<PathBuf>>()
+}
+
+/// Names of the fields holding a timestamp in the fixtures.
+const TIMESTAMP_FIELDS: &[&str] = &[
+    "time",
+    "timestamp",
+    "genesis_time",
+    "start_time",
+    "earliest_block_time",
+    "latest_block_time",
+];
+
+/// Collect the values of the fields holding a timestamp.
+fn collect_timestamps(value: &Value, timestamps: &mut Vec<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                match value {
+                    Value::String(s) if TIMESTAMP_FIELDS.contains(&key.as_str()) => {
+                        timestamps.push(s.clone())
+                    },
+                    _ => collect_timestamps(value, timestamps),
+                }
+            }
+        },
+        Value::Array(values) => values
+            .iter()
+            .for_each(|v| collect_timestamps(v, timestamps)),
+        _ => {},
+    }
+}
+
+#[test]
+fn fixture_timestamps_round_trip() {
+    let mut timestamps = Vec::new();
+    for json_file in find_fixtures() {
+        let content = fs::read_to_string(&json_file).unwrap();
+        let value: Value = serde_json::from_str(&content).unwrap();
+        collect_timestamps(&value, &mut timestamps);
+    }
+    assert!(!timestamps.is_empty());
+
+    for timestamp in timestamps {
+        let time: Time = timestamp
+            .parse()
+            .unwrap_or_else(|e| panic!("failed to parse {timestamp}: {e}"));
+        assert_eq!(time.to_string(), timestamp);
+
+        let json = serde_json::to_string(&time).unwrap();
+        assert_eq!(json, format!("\"{timestamp}\""));
+        assert_eq!(serde_json::from_str::<Time>(&json).unwrap(), time);
+    }
+}
@@ -0,0 +1,128 @@
+//! Verification of the certificate of nodes served over HTTPS.
+
+#![cfg(feature = "http-client")]
+
+use std::{io::BufReader, sync::Arc};
+
+use tendermint_rpc::{
+    client::http::{certificate_fingerprint, CertificateFingerprint},
+    error::ErrorDetail,
+    Client, Error, HttpClient,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use tokio_rustls::{rustls, TlsAcceptor};
+
+const CERTIFICATE: &[u8] = include_bytes!("tls_fixtures/localhost.crt");
+const PRIVATE_KEY: &[u8] = include_bytes!("tls_fixtures/localhost.key");
+
+fn certificate_der() -> Vec<u8> {
+    rustls_pemfile::certs(&mut BufReader::new(CERTIFICATE))
+        .unwrap()
+        .remove(0)
+}
+
+/// Serve a single HTTPS connection on a local port with the self-signed certificate,
+/// answering with an internal server error, and return the URL of the server.
+async fn serve_once() -> String {
+    let key = rustls_pemfile::pkcs8_private_keys(&mut BufReader::new(PRIVATE_KEY))
+        .unwrap()
+        .remove(0);
+
+    let config = rustls::ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(
+            vec![rustls::Certificate(certificate_der())],
+            rustls::PrivateKey(key),
+        )
+        .unwrap();
+
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        if let Ok(mut stream) = acceptor.accept(stream).await {
+            let mut buf = [0; 4096];
+            let _ = stream.read(&mut buf).await;
+            let _ = stream
+                .write_all(b"HTTP/1.1 500 Internal Server Error\r\ncontent-length: 0\r\n\r\n")
+                .await;
+            let _ = stream.shutdown().await;
+        }
+    });
+
+    format!("https://localhost:{port}")
+}
+
+async fn abci_info(client: HttpClient) -> ErrorDetail {
+    let Error(detail, _) = client.abci_info().await.unwrap_err();
+    detail
+}
+
+fn fingerprint() -> CertificateFingerprint {
+    certificate_fingerprint(&certificate_der())
+}
+
+#[tokio::test]
+async fn rejects_unknown_certificate() {
+    let url = serve_once().await;
+    let client = HttpClient::new(url.as_str()).unwrap();
+
+    match abci_info(client).await {
+        ErrorDetail::TlsCertificate(e) => assert_eq!(e.reason, "UnknownIssuer"),
+        e => panic!("expected a certificate error, got: {e:?}"),
+    }
+}
+
+#[tokio::test]
+async fn trusts_custom_root_certificate() {
+    let url = serve_once().await;
+    let client = HttpClient::builder(url.as_str().try_into().unwrap())
+        .add_root_certificate(reqwest::Certificate::from_pem(CERTIFICATE).unwrap())
+        .build()
+        .unwrap();
+
+    // The TLS handshake succeeded, and the request reached the server
+    assert!(matches!(
+        abci_info(client).await,
+        ErrorDetail::HttpRequestFailed(_)
+    ));
+}
+
+#[tokio::test]
+async fn trusts_pinned_certificate() {
+    let url = serve_once().await;
+    let client = HttpClient::builder(url.as_str().try_into().unwrap())
+        .pin_certificate(fingerprint())
+        .build()
+        .unwrap();
+
+    assert!(matches!(
+        abci_info(client).await,
+        ErrorDetail::HttpRequestFailed(_)
+    ));
+}
+
+#[tokio::test]
+async fn rejects_unpinned_certificate() {
+    let url = serve_once().await;
+    let client = HttpClient::builder(url.as_str().try_into().unwrap())
+        .add_root_certificate(reqwest::Certificate::from_pem(CERTIFICATE).unwrap())
+        .pin_certificate([0; 32])
+        .build()
+        .unwrap();
+
+    match abci_info(client).await {
+        ErrorDetail::TlsCertificate(e) => assert!(
+            e.reason.contains("is not pinned"),
+            "unexpected reason: {}",
+            e.reason
+        ),
+        e => panic!("expected a certificate error, got: {e:?}"),
+    }
+}
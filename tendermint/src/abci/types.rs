@@ -6,13 +6,15 @@
 //! [ABCI documentation](https://docs.tendermint.com/master/spec/abci/abci.html#data-types)
 
 use bytes::Bytes;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 
 use super::{Code, Event};
 use crate::{
     block::{self, BlockIdFlag},
+    merkle::{self, MerkleHash},
     prelude::*,
-    serializers, vote, Signature, Time,
+    serializers, vote, Hash, Signature, Time,
 };
 
 /// A validator address with voting power.
@@ -238,6 +240,39 @@ pub struct ExecTxResult {
     pub codespace: String,
 }
 
+/// Compute the hash of the results of executing the transactions of a block,
+/// as committed to by the `last_results_hash` of the header of the next block.
+#[cfg(feature = "rust-crypto")]
+pub fn results_hash(results: &[ExecTxResult]) -> Hash {
+    results_hash_with::<crate::crypto::default::Sha256>(results)
+}
+
+/// Hash the results of executing the transactions of a block with a Merkle
+/// hasher provided by a crypto provider.
+///
+/// Only the deterministic fields of each result are hashed, ie. the code,
+/// the data, and the amounts of gas wanted and used.
+pub fn results_hash_with<H>(results: &[ExecTxResult]) -> Hash
+where
+    H: MerkleHash + Default,
+{
+    let results_bytes: Vec<Vec<u8>> = results
+        .iter()
+        .map(|result| {
+            tendermint_proto::v0_38::abci::ExecTxResult {
+                code: result.code.into(),
+                data: result.data.clone(),
+                gas_wanted: result.gas_wanted,
+                gas_used: result.gas_used,
+                ..Default::default()
+            }
+            .encode_to_vec()
+        })
+        .collect();
+
+    Hash::Sha256(merkle::simple_hash_from_byte_vectors::<H>(&results_bytes))
+}
+
 // =============================================================================
 // Protobuf conversions
 // =============================================================================
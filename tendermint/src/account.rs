@@ -205,6 +205,37 @@ mod tests {
         assert_eq!(id_bytes.ct_eq(&id).unwrap_u8(), 1);
     }
 
+    #[test]
+    fn test_public_key_id() {
+        let pubkey_hex = "14253D61EF42D166D02E68D540D07FDF8D65A9AF0ACAA46302688E788A8521E2";
+        let pubkey_bytes = &hex::decode_upper(pubkey_hex).unwrap();
+        let pubkey = crate::PublicKey::from_raw_ed25519(pubkey_bytes).unwrap();
+
+        let id = Id::from(pubkey);
+        assert_eq!(id.to_string(), "0CDA3F47EF3C4906693B170EF650EB968C5F4B2C");
+
+        #[cfg(feature = "secp256k1")]
+        {
+            let pubkey_hex = "02950E1CDFCB133D6024109FD489F734EEB4502418E538C28481F22BCE276F248C";
+            let pubkey_bytes = &hex::decode_upper(pubkey_hex).unwrap();
+            let pubkey = crate::PublicKey::from_raw_secp256k1(pubkey_bytes).unwrap();
+
+            let id = Id::from(pubkey);
+            assert_eq!(id.to_string(), "7C2BB42A8BE69791EC763E51F5A49BCD41E82237");
+        }
+    }
+
+    #[test]
+    fn test_id_hex_roundtrip() {
+        let id_hex = "0CDA3F47EF3C4906693B170EF650EB968C5F4B2C";
+        let id = Id::from_str(id_hex).unwrap();
+        assert_eq!(id.to_string(), id_hex);
+        assert_eq!(Id::from_str(&id_hex.to_lowercase()).unwrap(), id);
+
+        assert!(Id::from_str("0CDA3F47").is_err());
+        assert!(Id::from_str("not hex").is_err());
+    }
+
     #[test]
     #[cfg(feature = "secp256k1")]
     fn test_secp_id() {
@@ -19,7 +19,7 @@ pub use self::{
     block_id_flag::BlockIdFlag,
     commit::*,
     commit_sig::*,
-    header::Header,
+    header::{HashedHeader, Header},
     height::*,
     id::{Id, ParseId},
     meta::Meta,
@@ -206,3 +206,69 @@ tendermint_pb_modules! {
         assert_eq!(commit_sig, CommitSig::BlockIdFlagAbsent);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of;
+
+    use tendermint_proto::v0_38::types::CommitSig as RawCommitSig;
+
+    use super::*;
+    use crate::signature::SIGNATURE_LENGTH;
+
+    #[test]
+    fn signatures_are_stored_inline() {
+        // With signatures stored in a `Vec`, a `CommitSig` took 64 bytes along with
+        // a separate heap allocation of 64 bytes for its signature, if any.
+        // Signatures are now stored inline, without any allocation.
+        assert_eq!(size_of::<Signature>(), SIGNATURE_LENGTH);
+        assert!(size_of::<CommitSig>() <= 100);
+    }
+
+    #[test]
+    fn round_trips_through_protobuf() {
+        let commit_sig = CommitSig::BlockIdFlagCommit {
+            validator_address: account::Id::new([0xAB; account::LENGTH]),
+            timestamp: Time::from_unix_timestamp(1_600_000_000, 0).unwrap(),
+            signature: Signature::new([0xCD; SIGNATURE_LENGTH]).unwrap(),
+        };
+
+        let raw = RawCommitSig::from(commit_sig.clone());
+        assert_eq!(raw.signature, vec![0xCD; SIGNATURE_LENGTH]);
+        assert_eq!(CommitSig::try_from(raw).unwrap(), commit_sig);
+
+        let absent = RawCommitSig::from(CommitSig::BlockIdFlagAbsent);
+        assert!(CommitSig::try_from(absent).unwrap().is_absent());
+    }
+
+    #[test]
+    fn rejects_malformed_flag_and_signature_combinations() {
+        let signed = |block_id_flag| RawCommitSig {
+            block_id_flag,
+            validator_address: vec![0xAB; account::LENGTH],
+            timestamp: Some(Time::from_unix_timestamp(1_600_000_000, 0).unwrap().into()),
+            signature: vec![0xCD; SIGNATURE_LENGTH],
+        };
+        let unsigned = |block_id_flag| RawCommitSig {
+            signature: Vec::new(),
+            ..signed(block_id_flag)
+        };
+
+        // Absent votes have no signature, while votes for a block or nil are signed
+        let absent = RawCommitSig {
+            validator_address: Vec::new(),
+            timestamp: Some(ZERO_TIMESTAMP),
+            ..signed(1)
+        };
+        assert!(CommitSig::try_from(absent).is_err());
+        assert!(CommitSig::try_from(unsigned(2)).is_err());
+        assert!(CommitSig::try_from(unsigned(3)).is_err());
+
+        assert!(CommitSig::try_from(signed(2)).unwrap().is_commit());
+        assert!(CommitSig::try_from(signed(3)).unwrap().is_nil());
+
+        // Unknown flag
+        assert!(CommitSig::try_from(signed(0)).is_err());
+        assert!(CommitSig::try_from(signed(4)).is_err());
+    }
+}
@@ -104,6 +104,65 @@ impl Header {
     }
 }
 
+/// A block [`Header`] along with its hash, computed once and for all when the header
+/// is wrapped, so that the hash can be looked up repeatedly at no cost.
+///
+/// The header cannot be mutated while wrapped, so that its hash always matches it.
+/// It can be unwrapped with [`HashedHeader::into_header`] to be modified, and then
+/// wrapped again to compute the new hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashedHeader {
+    header: Header,
+    hash: Hash,
+}
+
+impl HashedHeader {
+    /// Wrap the given header, computing its hash.
+    #[cfg(feature = "rust-crypto")]
+    pub fn new(header: Header) -> Self {
+        Self::new_with::<crate::crypto::default::Sha256>(header)
+    }
+
+    /// Wrap the given header, computing its hash with a Merkle hasher
+    /// provided by a crypto provider.
+    pub fn new_with<H>(header: Header) -> Self
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        let hash = header.hash_with::<H>();
+        Self { header, hash }
+    }
+
+    /// The wrapped header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// The hash of the wrapped header.
+    pub fn hash(&self) -> Hash {
+        self.hash
+    }
+
+    /// Unwrap the header, discarding its hash.
+    pub fn into_header(self) -> Header {
+        self.header
+    }
+}
+
+impl core::ops::Deref for HashedHeader {
+    type Target = Header;
+
+    fn deref(&self) -> &Header {
+        &self.header
+    }
+}
+
+impl From<HashedHeader> for Header {
+    fn from(hashed: HashedHeader) -> Self {
+        hashed.header
+    }
+}
+
 /// `Version` contains the protocol version for the blockchain and the
 /// application.
 ///
@@ -264,7 +323,7 @@ mod tests {
     #[cfg(feature = "rust-crypto")]
     mod crypto {
         use super::*;
-        use crate::{hash::Algorithm, Hash};
+        use crate::{block::HashedHeader, hash::Algorithm, Hash};
 
         #[test]
         fn header_hashing() {
@@ -279,5 +338,24 @@ mod tests {
             .unwrap();
             assert_eq!(expected_hash, header.hash());
         }
+
+        #[test]
+        fn hashed_header_matches_header() {
+            let header: Header = serde_json::from_str(include_str!(
+                "../../tests/support/serialization/block/header_with_known_hash.json"
+            ))
+            .unwrap();
+
+            let hashed = HashedHeader::new(header.clone());
+            assert_eq!(hashed.hash(), header.hash());
+            assert_eq!(hashed.height, header.height);
+
+            // Modifying the header requires unwrapping it, and hashing it again
+            let mut header = hashed.clone().into_header();
+            header.height = header.height.increment();
+            let rehashed = HashedHeader::new(header.clone());
+            assert_eq!(rehashed.hash(), header.hash());
+            assert_ne!(rehashed.hash(), hashed.hash());
+        }
     }
 }
@@ -1,5 +1,6 @@
 use core::{
     fmt::{self, Debug, Display},
+    ops::RangeInclusive,
     str::FromStr,
 };
 
@@ -73,9 +74,38 @@ impl Height {
     }
 
     /// Increment the block height by 1
+    ///
+    /// ## Panics
+    /// If the height overflows, see [`Height::checked_add`] otherwise.
     pub fn increment(self) -> Self {
         Height::try_from(self.0.checked_add(1).expect("height overflow")).unwrap()
     }
+
+    /// Add the given number of blocks to the height,
+    /// or return `None` if the resulting height overflows.
+    pub fn checked_add(self, blocks: u64) -> Option<Self> {
+        Height::try_from(self.0.checked_add(blocks)?).ok()
+    }
+
+    /// Subtract the given number of blocks from the height, or return `None`
+    /// if the resulting height is not the height of a block, ie. is lower than 1.
+    pub fn checked_sub(self, blocks: u64) -> Option<Self> {
+        self.0.checked_sub(blocks).filter(|h| *h > 0).map(Height)
+    }
+
+    /// Iterate over the heights of the blocks within the given range, ie. skipping
+    /// height 0, in increasing order or in decreasing order with [`Iterator::rev`].
+    ///
+    /// ```
+    /// use tendermint::block::Height;
+    ///
+    /// let heights = Height::range(Height::from(1_u32)..=Height::from(3_u32));
+    /// assert_eq!(heights.rev().map(|h| h.value()).collect::<Vec<_>>(), [3, 2, 1]);
+    /// ```
+    pub fn range(heights: RangeInclusive<Height>) -> impl DoubleEndedIterator<Item = Height> {
+        let (start, end) = heights.into_inner();
+        (start.0.max(1)..=end.0).map(Height)
+    }
 }
 
 impl Debug for Height {
@@ -135,6 +165,38 @@ mod tests {
         assert_eq!(Height::default().increment().value(), 2);
     }
 
+    #[test]
+    fn checked_arithmetic() {
+        let height = Height::from(10_u32);
+
+        assert_eq!(height.checked_add(5), Some(Height::from(15_u32)));
+        assert_eq!(height.checked_sub(9), Some(Height::from(1_u32)));
+        assert_eq!(height.checked_sub(10), None);
+        assert_eq!(height.checked_sub(11), None);
+
+        let max = Height::try_from(i64::MAX as u64).unwrap();
+        assert_eq!(max.checked_add(0), Some(max));
+        assert_eq!(max.checked_add(1), None);
+        assert_eq!(height.checked_add(u64::MAX), None);
+    }
+
+    #[test]
+    fn range_of_heights() {
+        let values = |heights: RangeInclusive<Height>| {
+            Height::range(heights)
+                .map(|h| h.value())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            values(Height::from(2_u32)..=Height::from(5_u32)),
+            [2, 3, 4, 5]
+        );
+        assert_eq!(values(Height::from(0_u32)..=Height::from(2_u32)), [1, 2]);
+        assert_eq!(values(Height::from(3_u32)..=Height::from(3_u32)), [3]);
+        assert!(values(Height::from(3_u32)..=Height::from(2_u32)).is_empty());
+    }
+
     #[test]
     fn avoid_try_unwrap_dance() {
         assert_eq!(
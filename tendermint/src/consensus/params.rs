@@ -1,9 +1,12 @@
 //! Tendermint consensus parameters
 
+use prost::Message;
 use serde::{Deserialize, Serialize};
+use tendermint_proto::v0_38::types::HashedParams as RawHashedParams;
 
 use crate::{
-    block, evidence, prelude::*, public_key, serializers::allow_empty_object::allow_empty_object,
+    block, crypto::Sha256, evidence, prelude::*, public_key,
+    serializers::allow_empty_object::allow_empty_object, Hash,
 };
 
 /// All consensus-relevant parameters that can be adjusted by the ABCI app.
@@ -29,6 +32,30 @@ pub struct Params {
     pub abci: AbciParams,
 }
 
+impl Params {
+    /// Compute the hash of these consensus parameters, as committed to by the
+    /// `consensus_hash` of block headers.
+    #[cfg(feature = "rust-crypto")]
+    pub fn hash(&self) -> Hash {
+        self.hash_with::<crate::crypto::default::Sha256>()
+    }
+
+    /// Hash these consensus parameters with a SHA256 hasher provided by a crypto provider.
+    ///
+    /// As in the Go implementation, only the size limits of blocks are hashed.
+    pub fn hash_with<H>(&self) -> Hash
+    where
+        H: Sha256,
+    {
+        let hashed_params = RawHashedParams {
+            block_max_bytes: self.block.max_bytes as i64,
+            block_max_gas: self.block.max_gas,
+        };
+
+        Hash::Sha256(H::digest(hashed_params.encode_to_vec()))
+    }
+}
+
 /// ValidatorParams restrict the public key types validators can use.
 ///
 /// [Tendermint documentation](https://docs.tendermint.com/master/spec/core/data_structures.html#validatorparams)
@@ -25,17 +25,50 @@ impl crate::crypto::signature::Verifier for Verifier {
 
                 let sig = k256::ecdsa::Signature::try_from(signature.as_bytes())
                     .map_err(|_| Error::MalformedSignature)?;
+
+                // As in Tendermint, only signatures in lower-S form are valid
+                if sig.normalize_s().is_some() {
+                    return Err(Error::MalformedSignature);
+                }
+
+                // The message is hashed with SHA-256 before verifying the signature
                 pk.verify(msg, &sig).map_err(|_| Error::VerificationFailed)
             },
             _ => Err(Error::UnsupportedKeyType),
         }
     }
+
+    /// Ed25519 signatures are verified together with batch verification,
+    /// other signatures are verified one at a time.
+    #[cfg(feature = "batch-verify")]
+    fn verify_batch(items: &[(PublicKey, &[u8], &Signature)]) -> Result<(), Error> {
+        let mut batch = ed25519_consensus::batch::Verifier::new();
+
+        for &(pubkey, msg, signature) in items {
+            #[allow(unreachable_patterns)]
+            match pubkey {
+                PublicKey::Ed25519(pk) => {
+                    let pubkey = ed25519_consensus::VerificationKey::try_from(pk)
+                        .map_err(|_| Error::MalformedPublicKey)?;
+                    let sig = ed25519_consensus::Signature::try_from(signature.as_bytes())
+                        .map_err(|_| Error::MalformedSignature)?;
+                    batch.queue((pubkey.into(), sig, msg));
+                },
+                _ => Self::verify(pubkey, msg, signature)?,
+            }
+        }
+
+        batch
+            .verify(rand_core::OsRng)
+            .map_err(|_| Error::VerificationFailed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::crypto::default::signature::Verifier;
     use crate::crypto::signature::Verifier as _;
+    use crate::prelude::*;
     use crate::{PublicKey, Signature};
 
     // From https://datatracker.ietf.org/doc/html/rfc8032#section-7.1
@@ -201,6 +234,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ed25519_test_vectors_batch() {
+        let vectors = ED25519_TEST_VECTORS
+            .iter()
+            .map(|v| {
+                (
+                    PublicKey::from_raw_ed25519(v[0]).unwrap(),
+                    v[1],
+                    Signature::try_from(v[2]).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let items = vectors
+            .iter()
+            .map(|(public_key, msg, sig)| (*public_key, *msg, sig))
+            .collect::<Vec<_>>();
+        Verifier::verify_batch(&items).expect("signatures should be valid");
+
+        // Swap the messages of the first two signatures
+        let mut items = items;
+        let (msg0, msg1) = (items[0].1, items[1].1);
+        items[0].1 = msg1;
+        items[1].1 = msg0;
+        assert!(Verifier::verify_batch(&items).is_err());
+    }
+
     // Arbitrary "valid" tests taken from
     // https://github.com/google/wycheproof/blob/2196000605e45d91097147c9c71f26b72af58003/testvectors/ecdsa_secp256k1_sha256_test.json
     //
@@ -289,4 +349,28 @@ mod tests {
                 .unwrap_or_else(|_| panic!("signature should be valid for test vector {}", i));
         }
     }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn secp256k1_rejects_high_s_signatures() {
+        use k256::ecdsa::{signature::Signer, SigningKey};
+
+        let signing_key = SigningKey::from_slice(&[1; 32]).unwrap();
+        let public_key = PublicKey::Secp256k1(*signing_key.verifying_key());
+        let msg = b"canonical vote";
+
+        let sig: k256::ecdsa::Signature = signing_key.sign(msg);
+        let (r, s) = sig.split_scalars();
+        let high_s_sig = k256::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+
+        let sig = Signature::try_from(sig.to_bytes().as_slice()).unwrap();
+        let high_s_sig = Signature::try_from(high_s_sig.to_bytes().as_slice()).unwrap();
+
+        assert!(Verifier::verify(public_key, msg, &sig).is_ok());
+        assert!(Verifier::verify(public_key, b"other vote", &sig).is_err());
+        assert!(matches!(
+            Verifier::verify(public_key, msg, &high_s_sig),
+            Err(crate::crypto::signature::Error::MalformedSignature)
+        ));
+    }
 }
@@ -32,4 +32,15 @@ impl std::error::Error for Error {}
 
 pub trait Verifier {
     fn verify(pubkey: PublicKey, msg: &[u8], signature: &Signature) -> Result<(), Error>;
+
+    /// Verify a batch of signatures, each given with its public key and message.
+    ///
+    /// Succeeds only if all the signatures are valid, without telling which
+    /// ones are not otherwise. The default implementation verifies each
+    /// signature in turn.
+    fn verify_batch(items: &[(PublicKey, &[u8], &Signature)]) -> Result<(), Error> {
+        items
+            .iter()
+            .try_for_each(|(pubkey, msg, signature)| Self::verify(*pubkey, msg, signature))
+    }
 }
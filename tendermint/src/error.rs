@@ -1,6 +1,6 @@
 //! Error types
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::num::TryFromIntError;
 
 use flex_error::{define_error, DisplayOnly};
@@ -141,6 +141,10 @@ define_error! {
             { reason: String }
             | e | { format_args!("invalid block: {}", e.reason) },
 
+        InvalidGenesis
+            { reason: String }
+            | e | { format_args!("invalid genesis: {}", e.reason) },
+
         InvalidFirstHeader
             |_| { format_args!("last_block_id is not null on first height") },
 
@@ -202,6 +206,10 @@ define_error! {
             { account: account::Id }
             |e| { format_args!("proposer with address '{0}' no found in validator set", e.account) },
 
+        ValidatorNotFound
+            { account: account::Id }
+            |e| { format_args!("validator with address '{0}' not found in validator set", e.account) },
+
         TimeParse
             [ DisplayOnly<time::error::Parse> ]
             |_| { format_args!("time parsing error") },
@@ -230,6 +238,24 @@ define_error! {
             [ DisplayOnly<TryFromIntError> ]
             |_| { "negative item index in proof" },
 
+        InvalidProof
+            { reason: String }
+            | e | { format_args!("invalid proof: {}", e.reason) },
+
+        UnsupportedProofOp
+            { op_type: String }
+            | e | { format_args!("unsupported proof operation type '{}'", e.op_type) },
+
+        QueryKeyMismatch
+            {
+                requested: Vec<u8>,
+                returned: Vec<u8>,
+            }
+            | e | {
+                format_args!("query response is for key {:X?} rather than the requested key {:X?}",
+                    e.returned, e.requested)
+            },
+
         TotalVotingPowerMismatch
             |_| { "total voting power in validator set does not match the sum of participants' powers" },
 
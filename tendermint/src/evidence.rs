@@ -2,17 +2,24 @@
 
 use core::slice;
 
+use prost::Message;
 use serde::{Deserialize, Serialize};
 use tendermint_proto::google::protobuf::Duration as RawDuration;
+use tendermint_proto::v0_38::types::{
+    DuplicateVoteEvidence as RawDuplicateVoteEvidence,
+    LightClientAttackEvidence as RawLightClientAttackEvidence,
+};
 use tendermint_proto::Protobuf;
 
 use crate::{
     block::{signed_header::SignedHeader, Height},
+    consensus,
     error::Error,
+    merkle::{self, MerkleHash},
     prelude::*,
     serializers, validator,
     vote::Power,
-    Time, Vote,
+    Hash, Time, Vote,
 };
 
 /// Evidence of malfeasance by validators (i.e. signing conflicting votes or light client attack).
@@ -25,6 +32,44 @@ pub enum Evidence {
     LightClientAttack(Box<LightClientAttackEvidence>),
 }
 
+impl Evidence {
+    /// Height of the misbehavior, ie. the height of the conflicting votes, or
+    /// the common height from which a light client attack was mounted.
+    pub fn height(&self) -> Height {
+        match self {
+            Self::DuplicateVote(ev) => ev.vote_a.height,
+            Self::LightClientAttack(ev) => ev.common_height,
+        }
+    }
+
+    /// Time of the block at the height of the misbehavior.
+    pub fn time(&self) -> Time {
+        match self {
+            Self::DuplicateVote(ev) => ev.timestamp,
+            Self::LightClientAttack(ev) => ev.timestamp,
+        }
+    }
+
+    /// Whether this evidence is too old to be committed anymore, as of the given
+    /// height and time of the chain.
+    ///
+    /// As in Tendermint, evidence expires once it is older than both the maximum
+    /// age in blocks and the maximum age in time set in the consensus parameters.
+    pub fn is_expired(
+        &self,
+        consensus_params: &consensus::Params,
+        current_height: Height,
+        current_time: Time,
+    ) -> bool {
+        let params = &consensus_params.evidence;
+
+        let age_num_blocks = current_height.value().saturating_sub(self.height().value());
+        let age_duration = current_time.duration_since(self.time()).unwrap_or_default();
+
+        age_num_blocks > params.max_age_num_blocks && age_duration > params.max_age_duration.0
+    }
+}
+
 impl From<LightClientAttackEvidence> for Evidence {
     fn from(ev: LightClientAttackEvidence) -> Self {
         Self::LightClientAttack(Box::new(ev))
@@ -111,6 +156,42 @@ impl List {
     pub fn iter(&self) -> slice::Iter<'_, Evidence> {
         self.0.iter()
     }
+
+    /// Compute the hash of this evidence data, as committed to by the
+    /// `evidence_hash` of block headers.
+    #[cfg(feature = "rust-crypto")]
+    pub fn hash(&self) -> Hash {
+        self.hash_with::<crate::crypto::default::Sha256>()
+    }
+
+    /// Hash this evidence data with a Merkle hasher provided by a crypto provider.
+    pub fn hash_with<H>(&self) -> Hash
+    where
+        H: MerkleHash + Default,
+    {
+        let evidence_bytes: Vec<Vec<u8>> = self
+            .iter()
+            .map(|evidence| match evidence {
+                Evidence::DuplicateVote(e) => {
+                    let mut raw = RawDuplicateVoteEvidence::from(e.as_ref().clone());
+                    // The Go implementation always encodes the block id of votes,
+                    // even when it is nil
+                    for vote in [&mut raw.vote_a, &mut raw.vote_b].into_iter().flatten() {
+                        vote.block_id
+                            .get_or_insert_with(Default::default)
+                            .part_set_header
+                            .get_or_insert_with(Default::default);
+                    }
+                    raw.encode_to_vec()
+                },
+                Evidence::LightClientAttack(e) => {
+                    Protobuf::<RawLightClientAttackEvidence>::encode_vec(e.as_ref().clone())
+                },
+            })
+            .collect();
+
+        Hash::Sha256(merkle::simple_hash_from_byte_vectors::<H>(&evidence_bytes))
+    }
 }
 
 impl AsRef<[Evidence]> for List {
@@ -214,7 +295,7 @@ tendermint_pb_modules! {
                 vote_a: Some(value.vote_a.into()),
                 vote_b: Some(value.vote_b.into()),
                 total_voting_power: value.total_voting_power.into(),
-                validator_power: value.total_voting_power.into(),
+                validator_power: value.validator_power.into(),
                 timestamp: Some(value.timestamp.into()),
             }
         }
@@ -380,3 +461,90 @@ impl From<Duration> for RawDuration {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration as StdDuration;
+
+    use super::*;
+    use crate::{
+        account, block,
+        consensus::params::{AbciParams, ValidatorParams},
+        vote::{self, ValidatorIndex},
+    };
+
+    const MAX_AGE_NUM_BLOCKS: u64 = 100;
+    const MAX_AGE_DURATION: StdDuration = StdDuration::from_secs(60 * 60 * 48);
+
+    fn consensus_params() -> consensus::Params {
+        consensus::Params {
+            block: block::Size {
+                max_bytes: 22020096,
+                max_gas: -1,
+                time_iota_ms: block::Size::default_time_iota_ms(),
+            },
+            evidence: Params {
+                max_age_num_blocks: MAX_AGE_NUM_BLOCKS,
+                max_age_duration: Duration(MAX_AGE_DURATION),
+                max_bytes: 1048576,
+            },
+            validator: ValidatorParams {
+                pub_key_types: vec![],
+            },
+            version: None,
+            abci: AbciParams::default(),
+        }
+    }
+
+    fn duplicate_vote_evidence(height: u64, time: Time) -> Evidence {
+        let vote = Vote {
+            vote_type: vote::Type::Precommit,
+            height: height.try_into().unwrap(),
+            round: block::Round::default(),
+            block_id: None,
+            timestamp: Some(time),
+            validator_address: account::Id::new([0; 20]),
+            validator_index: ValidatorIndex::try_from(0_u32).unwrap(),
+            signature: None,
+            extension: vec![],
+            extension_signature: None,
+        };
+
+        let mut evidence = DuplicateVoteEvidence::new(vote.clone(), vote).unwrap();
+        evidence.timestamp = time;
+        evidence.into()
+    }
+
+    #[test]
+    fn evidence_expires_once_older_than_both_max_ages() {
+        let params = consensus_params();
+        let time = Time::from_unix_timestamp(1_000_000, 0).unwrap();
+        let evidence = duplicate_vote_evidence(10, time);
+
+        let height = |age: u64| Height::try_from(10 + age).unwrap();
+        let time = |age: StdDuration| time.checked_add(age).unwrap();
+        let just_over = MAX_AGE_DURATION + StdDuration::from_secs(1);
+
+        // At both maximum ages, the evidence is still valid
+        let max_height = height(MAX_AGE_NUM_BLOCKS);
+        let max_time = time(MAX_AGE_DURATION);
+        assert!(!evidence.is_expired(&params, max_height, max_time));
+
+        // Exceeding a single one of them is not enough
+        assert!(!evidence.is_expired(&params, height(MAX_AGE_NUM_BLOCKS + 1), max_time));
+        assert!(!evidence.is_expired(&params, max_height, time(just_over)));
+
+        assert!(evidence.is_expired(&params, height(MAX_AGE_NUM_BLOCKS + 1), time(just_over)));
+    }
+
+    #[test]
+    fn evidence_from_the_future_is_not_expired() {
+        let params = consensus_params();
+        let time = Time::from_unix_timestamp(1_000_000, 0).unwrap();
+        let evidence = duplicate_vote_evidence(1_000, time);
+
+        assert_eq!(evidence.height().value(), 1_000);
+        assert_eq!(evidence.time(), time);
+        assert!(!evidence.is_expired(&params, Height::from(1_u32), Time::unix_epoch()));
+    }
+}
@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{chain, consensus, prelude::*, serializers, validator, AppHash, Time};
+use crate::{chain, consensus, prelude::*, serializers, validator, AppHash, Error, Time};
 
 /// Genesis data
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -31,3 +31,144 @@ pub struct Genesis<AppState = serde_json::Value> {
     /// App state
     pub app_state: AppState,
 }
+
+impl<AppState> Genesis<AppState> {
+    /// Check that the genesis data is valid, ie. that the initial height is not
+    /// negative, and that the initial validators form a valid validator set.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.initial_height < 0 {
+            return Err(Error::invalid_genesis(format!(
+                "negative initial height: {}",
+                self.initial_height
+            )));
+        }
+
+        self.validators().map(|_| ())
+    }
+
+    /// The initial validator set, without a proposer.
+    ///
+    /// Fails if any validator has no voting power or, with the `rust-crypto`
+    /// feature, an address which does not match its public key, or if the
+    /// total voting power exceeds the maximum allowed.
+    pub fn validators(&self) -> Result<validator::Set, Error> {
+        for validator in &self.validators {
+            if validator.power() == 0 {
+                return Err(Error::invalid_genesis(format!(
+                    "validator {} has no voting power",
+                    validator.address
+                )));
+            }
+
+            #[cfg(feature = "rust-crypto")]
+            if validator.address != crate::account::Id::from(validator.pub_key) {
+                return Err(Error::invalid_genesis(format!(
+                    "address of validator {} does not match its public key",
+                    validator.address
+                )));
+            }
+        }
+
+        validator::Set::try_from_parts(self.validators.clone(), None, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorDetail;
+
+    const GENESIS: &str = r#"{
+        "genesis_time": "2023-05-17T14:12:48.347696215Z",
+        "chain_id": "dockerchain",
+        "initial_height": "1",
+        "consensus_params": {
+            "block": { "max_bytes": "22020096", "max_gas": "-1" },
+            "evidence": {
+                "max_age_duration": "172800000000000",
+                "max_age_num_blocks": "100000",
+                "max_bytes": "1048576"
+            },
+            "validator": { "pub_key_types": ["ed25519"] },
+            "version": { "app": "0" }
+        },
+        "validators": [
+            {
+                "address": "2DD9F44FD9067555C322243C3C913BA7B51D2BE0",
+                "name": "",
+                "power": "10",
+                "pub_key": {
+                    "type": "tendermint/PubKeyEd25519",
+                    "value": "bNNlGls5R25wC3Sd8720F/3+7IZBhXcD22MNFtPk/v0="
+                }
+            }
+        ],
+        "app_hash": "",
+        "app_state": { "accounts": [{ "name": "alice", "balance": "100" }] }
+    }"#;
+
+    fn genesis() -> Genesis {
+        serde_json::from_str(GENESIS).unwrap()
+    }
+
+    fn invalid_genesis_reason(genesis: &Genesis) -> String {
+        match genesis.validate().unwrap_err().detail() {
+            ErrorDetail::InvalidGenesis(e) => e.reason.clone(),
+            e => panic!("expected an invalid genesis, got: {e:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_and_validates_genesis() {
+        let genesis = genesis();
+        genesis.validate().unwrap();
+
+        assert_eq!(genesis.chain_id.as_str(), "dockerchain");
+        assert_eq!(genesis.initial_height, 1);
+        assert_eq!(genesis.app_state["accounts"][0]["name"], "alice");
+
+        let validators = genesis.validators().unwrap();
+        assert_eq!(validators.validators().len(), 1);
+        assert_eq!(validators.total_voting_power().value(), 10);
+        assert!(validators.proposer().is_none());
+    }
+
+    #[test]
+    fn rejects_validator_without_voting_power() {
+        let mut genesis = genesis();
+        genesis.validators[0].power = 0_u32.into();
+
+        assert!(invalid_genesis_reason(&genesis).contains("no voting power"));
+    }
+
+    #[cfg(feature = "rust-crypto")]
+    #[test]
+    fn rejects_validator_with_mismatched_address() {
+        let mut genesis = genesis();
+        genesis.validators[0].address = crate::account::Id::new([0; 20]);
+
+        assert!(invalid_genesis_reason(&genesis).contains("does not match"));
+    }
+
+    #[test]
+    fn rejects_negative_initial_height() {
+        let mut genesis = genesis();
+        genesis.initial_height = -1;
+
+        assert!(invalid_genesis_reason(&genesis).contains("negative initial height"));
+    }
+
+    #[test]
+    fn rejects_excessive_total_voting_power() {
+        let mut genesis = genesis();
+        let validator = genesis.validators[0].clone();
+        genesis.validators[0].power =
+            crate::vote::Power::try_from(validator::Set::MAX_TOTAL_VOTING_POWER).unwrap();
+        genesis.validators.push(validator);
+
+        assert!(matches!(
+            genesis.validate().unwrap_err().detail(),
+            ErrorDetail::TotalVotingPowerOverflow(_)
+        ));
+    }
+}
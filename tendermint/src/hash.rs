@@ -113,6 +113,19 @@ impl Hash {
         }
     }
 
+    /// Decode a `Hash` from a base64-encoded string
+    pub fn from_base64(alg: Algorithm, s: &str) -> Result<Hash, Error> {
+        let bytes = base64::decode(s).map_err(Error::subtle_encoding)?;
+        Self::from_bytes(alg, &bytes)
+    }
+
+    /// Encode the `Hash` as a base64 string, which is empty for `Hash::None`
+    ///
+    /// See the `Display` implementation for upper-case hexadecimal.
+    pub fn to_base64(&self) -> String {
+        String::from_utf8(base64::encode(self.as_bytes())).unwrap()
+    }
+
     /// Return the digest algorithm used to produce this hash
     pub fn algorithm(self) -> Algorithm {
         match self {
@@ -299,6 +312,72 @@ impl FromStr for AppHash {
 mod tests {
     use super::*;
 
+    const HASH_HEX: &str = "31F5FD7FE6D8A08F088A845BE184FFF3F5613EFB4D8E05858138B89A63129017";
+    const HASH_BASE64: &str = "MfX9f+bYoI8IioRb4YT/8/VhPvtNjgWFgTi4mmMSkBc=";
+
+    #[test]
+    fn hash_hex_roundtrip() {
+        let hash = Hash::from_hex_upper(Algorithm::Sha256, HASH_HEX).unwrap();
+        assert_eq!(hash.to_string(), HASH_HEX);
+        assert_eq!(Hash::from_str(&hash.to_string()).unwrap(), hash);
+    }
+
+    #[test]
+    fn hash_base64_roundtrip() {
+        let hash = Hash::from_base64(Algorithm::Sha256, HASH_BASE64).unwrap();
+        assert_eq!(
+            hash,
+            Hash::from_hex_upper(Algorithm::Sha256, HASH_HEX).unwrap()
+        );
+        assert_eq!(hash.to_base64(), HASH_BASE64);
+
+        assert!(Hash::from_base64(Algorithm::Sha256, "MfX9f+bY").is_err());
+        assert!(Hash::from_base64(Algorithm::Sha256, "not base64").is_err());
+    }
+
+    #[test]
+    fn empty_hash_roundtrip() {
+        assert_eq!(
+            Hash::from_hex_upper(Algorithm::Sha256, "").unwrap(),
+            Hash::None
+        );
+        assert_eq!(Hash::None.to_string(), "");
+        assert_eq!(
+            Hash::from_base64(Algorithm::Sha256, "").unwrap(),
+            Hash::None
+        );
+        assert_eq!(Hash::None.to_base64(), "");
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct HashTest {
+        #[serde(with = "crate::serializers::hash")]
+        hex: Hash,
+        #[serde(with = "crate::serializers::hash_base64")]
+        base64: Hash,
+    }
+
+    #[test]
+    fn hash_serializers() {
+        let json = format!(r#"{{"hex":"{HASH_HEX}","base64":"{HASH_BASE64}"}}"#);
+        let test = serde_json::from_str::<HashTest>(&json).unwrap();
+        assert_eq!(test.hex, test.base64);
+        assert_eq!(serde_json::to_string(&test).unwrap(), json);
+
+        let empty = serde_json::from_str::<HashTest>(r#"{"hex":null,"base64":null}"#).unwrap();
+        assert_eq!(
+            empty,
+            HashTest {
+                hex: Hash::None,
+                base64: Hash::None,
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&empty).unwrap(),
+            r#"{"hex":"","base64":""}"#
+        );
+    }
+
     #[derive(Debug, serde::Deserialize)]
     struct Test {
         #[serde(default)]
@@ -28,6 +28,17 @@ where
     hasher.hash_byte_vectors(byte_vecs)
 }
 
+/// Compute a simple Merkle root from byte slices, hashed with the default
+/// SHA-256 implementation, as `merkle.HashFromByteSlices` does in Go.
+///
+/// Leaves are hashed as `sha256(0x00 || leaf)` and inner nodes as
+/// `sha256(0x01 || left || right)`, the left subtree holding the largest
+/// power of two of leaves which is less than their number.
+#[cfg(feature = "rust-crypto")]
+pub fn simple_hash_from_byte_slices(byte_slices: &[impl AsRef<[u8]>]) -> Hash {
+    simple_hash_from_byte_vectors::<crate::crypto::default::Sha256>(byte_slices)
+}
+
 /// Implementation of Merkle tree hashing for Tendermint.
 pub trait MerkleHash {
     // tmhash({})
@@ -106,6 +117,10 @@ where
 
 /// A wrapper for platform-provided host functions which can't do incremental
 /// hashing. One unfortunate example of such platform is Polkadot.
+///
+/// As it also implements [`Sha256`], it can be passed to the `hash_with`
+/// methods, eg. [`Header::hash_with`](crate::block::Header::hash_with), to
+/// hash blocks and validator sets with such a host function.
 pub struct NonIncremental<H>(PhantomData<H>);
 
 impl<H> Default for NonIncremental<H> {
@@ -114,6 +129,12 @@ impl<H> Default for NonIncremental<H> {
     }
 }
 
+impl<H: Sha256> Sha256 for NonIncremental<H> {
+    fn digest(data: impl AsRef<[u8]>) -> [u8; HASH_SIZE] {
+        H::digest(data)
+    }
+}
+
 impl<H: Sha256> MerkleHash for NonIncremental<H> {
     fn empty_hash(&mut self) -> Hash {
         let digest = H::digest([]);
@@ -192,6 +213,41 @@ mod tests {
         assert_eq!(node_hash, &hash);
     }
 
+    #[test]
+    fn test_hash_from_byte_slices_go_vectors() {
+        // From `TestHashFromByteSlices` in Go's `crypto/merkle`
+        let cases: [(&[&[u8]], &str); 5] = [
+            (
+                &[],
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            (
+                &[&[]],
+                "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+            ),
+            (
+                &[&[1, 2, 3]],
+                "054edec1d0211f624fed0cbca9d4f9400b0e491c43742af2c5b0abebf0c990d8",
+            ),
+            (
+                &[&[1, 2, 3], &[4, 5, 6]],
+                "82e6cfce00453804379b53962939eaa7906b39904be0813fcadd31b100773c4b",
+            ),
+            (
+                &[&[1, 2], &[3, 4], &[5, 6], &[7, 8], &[9, 10]],
+                "f326493eceab4f2d9ffbc78c59432a0a005d6ea98392045c74df5d14a113be18",
+            ),
+        ];
+
+        for (byte_slices, expected_root_hex) in cases {
+            let expected_root = hex::decode(expected_root_hex).unwrap();
+            assert_eq!(
+                simple_hash_from_byte_slices(byte_slices),
+                &expected_root[..]
+            );
+        }
+    }
+
     mod non_incremental {
         use super::*;
 
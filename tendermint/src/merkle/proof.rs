@@ -1,9 +1,19 @@
 //! Merkle proofs
 
+use prost::Message;
 use serde::{Deserialize, Serialize};
-use tendermint_proto::v0_37::crypto::Proof as RawProof;
+use tendermint_proto::v0_37::crypto::{Proof as RawProof, ValueOp as RawValueOp};
 
-use crate::{prelude::*, serializers, Hash};
+use crate::{
+    crypto::Sha256,
+    merkle::{self, MerkleHash},
+    prelude::*,
+    serializers, Error, Hash,
+};
+
+/// Type of the proof operations proving that a value is stored under a key
+/// of a simple Merkle tree, as produced by Tendermint's `ValueOp`.
+pub const PROOF_OP_SIMPLE_VALUE: &str = "simple:v";
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(try_from = "RawProof", into = "RawProof")]
@@ -43,6 +53,192 @@ pub struct ProofOp {
     pub data: Vec<u8>,
 }
 
+impl Proof {
+    /// Computes the root hash of the Merkle tree from the leaf hash and the aunts,
+    /// or `None` if the proof is malformed.
+    #[cfg(feature = "rust-crypto")]
+    pub fn compute_root_hash(&self) -> Option<Hash> {
+        self.compute_root_hash_with::<crate::crypto::default::Sha256>()
+    }
+
+    /// Computes the root hash with a Merkle hasher provided by a crypto provider.
+    pub fn compute_root_hash_with<H>(&self) -> Option<Hash>
+    where
+        H: MerkleHash + Default,
+    {
+        let leaf_hash = as_merkle_hash(&self.leaf_hash)?;
+        let aunts = self
+            .aunts
+            .iter()
+            .map(as_merkle_hash)
+            .collect::<Option<Vec<_>>>()?;
+
+        compute_hash_from_aunts(&mut H::default(), self.index, self.total, leaf_hash, &aunts)
+            .map(Hash::Sha256)
+    }
+}
+
+impl ProofOps {
+    /// Verifies that the given value is stored under the given key path
+    /// in the state whose Merkle root is `root`, eg. the app hash of a block.
+    ///
+    /// The key path goes from the outermost key to the innermost one, eg. the name
+    /// of a store then the key within that store, as in Tendermint's `/store/key`
+    /// key paths.
+    ///
+    /// The operations are run in order, starting from the value, each one taking
+    /// the output of the previous one as input. Each operation with a key must prove
+    /// the next key of the path, starting from its end, the whole path must be proven,
+    /// and the last operation must output the root hash.
+    #[cfg(feature = "rust-crypto")]
+    pub fn verify<K>(&self, root: Hash, key_path: &[K], value: &[u8]) -> Result<(), Error>
+    where
+        K: AsRef<[u8]>,
+    {
+        self.verify_with::<crate::crypto::default::Sha256, K>(root, key_path, value)
+    }
+
+    /// Verifies the proof with hashers provided by a crypto provider.
+    // https://github.com/cometbft/cometbft/blob/v0.38.0/crypto/merkle/proof.go#L59
+    pub fn verify_with<H, K>(&self, root: Hash, key_path: &[K], value: &[u8]) -> Result<(), Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+        K: AsRef<[u8]>,
+    {
+        if self.ops.is_empty() {
+            return Err(Error::invalid_proof("no proof operations".to_string()));
+        }
+
+        let mut keys = key_path.iter().map(AsRef::as_ref);
+        let mut computed = value.to_vec();
+
+        for op in &self.ops {
+            if !op.key.is_empty() {
+                let key = keys
+                    .next_back()
+                    .ok_or_else(|| Error::invalid_proof("key path has too few keys".to_string()))?;
+
+                if op.key != key {
+                    return Err(Error::invalid_proof(
+                        "key path does not match the proof operations".to_string(),
+                    ));
+                }
+            }
+
+            computed = op.run::<H>(&computed)?;
+        }
+
+        if keys.next().is_some() {
+            return Err(Error::invalid_proof(
+                "key path has too many keys".to_string(),
+            ));
+        }
+
+        if computed != root.as_bytes() {
+            return Err(Error::invalid_proof(
+                "computed root hash does not match".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl ProofOp {
+    /// Runs the operation over the given value, returning the computed root hash.
+    fn run<H>(&self, value: &[u8]) -> Result<Vec<u8>, Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        match self.field_type.as_str() {
+            PROOF_OP_SIMPLE_VALUE => self.run_simple_value::<H>(value),
+            _ => Err(Error::unsupported_proof_op(self.field_type.clone())),
+        }
+    }
+
+    // https://github.com/cometbft/cometbft/blob/v0.38.0/crypto/merkle/proof_value.go#L80
+    fn run_simple_value<H>(&self, value: &[u8]) -> Result<Vec<u8>, Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        let op = RawValueOp::decode(self.data.as_slice())
+            .map_err(|e| Error::invalid_proof(format!("cannot decode value operation: {e}")))?;
+
+        if op.key != self.key {
+            return Err(Error::invalid_proof(
+                "key of the value operation does not match".to_string(),
+            ));
+        }
+
+        let proof = Proof::try_from(
+            op.proof
+                .ok_or_else(|| Error::invalid_proof("missing proof".to_string()))?,
+        )?;
+
+        // The leaf is the length-prefixed key followed by the length-prefixed hash of the value
+        let mut leaf = Vec::new();
+        encode_byte_slice(&op.key, &mut leaf);
+        encode_byte_slice(&<H as Sha256>::digest(value), &mut leaf);
+
+        if proof.leaf_hash != Hash::Sha256(H::default().leaf_hash(&leaf)) {
+            return Err(Error::invalid_proof(
+                "leaf hash does not match the value".to_string(),
+            ));
+        }
+
+        proof
+            .compute_root_hash_with::<H>()
+            .map(Into::into)
+            .ok_or_else(|| Error::invalid_proof("malformed proof".to_string()))
+    }
+}
+
+fn as_merkle_hash(hash: &Hash) -> Option<merkle::Hash> {
+    match hash {
+        Hash::Sha256(hash) => Some(*hash),
+        Hash::None => None,
+    }
+}
+
+fn encode_byte_slice(bytes: &[u8], buf: &mut Vec<u8>) {
+    prost::encoding::encode_varint(bytes.len() as u64, buf);
+    buf.extend_from_slice(bytes);
+}
+
+// https://github.com/cometbft/cometbft/blob/v0.38.0/crypto/merkle/proof.go#L212
+fn compute_hash_from_aunts<H: MerkleHash>(
+    hasher: &mut H,
+    index: u64,
+    total: u64,
+    leaf_hash: merkle::Hash,
+    aunts: &[merkle::Hash],
+) -> Option<merkle::Hash> {
+    if index >= total {
+        return None;
+    }
+
+    match (total, aunts.split_last()) {
+        (1, None) => Some(leaf_hash),
+        (1, Some(_)) | (_, None) => None,
+        (_, Some((aunt, aunts))) => {
+            let split = total.next_power_of_two() / 2;
+            if index < split {
+                let left = compute_hash_from_aunts(hasher, index, split, leaf_hash, aunts)?;
+                Some(hasher.inner_hash(left, *aunt))
+            } else {
+                let right = compute_hash_from_aunts(
+                    hasher,
+                    index - split,
+                    total - split,
+                    leaf_hash,
+                    aunts,
+                )?;
+                Some(hasher.inner_hash(*aunt, right))
+            }
+        },
+    }
+}
+
 // =============================================================================
 // Protobuf conversions
 // =============================================================================
@@ -142,6 +338,102 @@ tendermint_pb_modules! {
 mod test {
     use super::ProofOps;
     use crate::test::test_serialization_roundtrip;
+    #[cfg(feature = "rust-crypto")]
+    use crate::{error::ErrorDetail, hash::Algorithm, prelude::*, Hash};
+
+    /// Proof operations of an `abci_query` response for the key `bravo`,
+    /// holding `2` in the `main` store, along with the app hash they prove.
+    #[cfg(feature = "rust-crypto")]
+    fn abci_query_fixture() -> (ProofOps, Hash) {
+        let response: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/support/merkle/abci_query_simple_value.json"
+        ))
+        .unwrap();
+
+        let proof_ops =
+            serde_json::from_value(response["result"]["response"]["proofOps"].clone()).unwrap();
+
+        let app_hash = Hash::from_hex_upper(
+            Algorithm::Sha256,
+            "A119851F798D1344B8C59975CDB0F561F9D4461A491FFC9507DD5F88007029DC",
+        )
+        .unwrap();
+
+        (proof_ops, app_hash)
+    }
+
+    #[test]
+    #[cfg(feature = "rust-crypto")]
+    fn verify_abci_query_proof() {
+        let (proof_ops, app_hash) = abci_query_fixture();
+        proof_ops
+            .verify(app_hash, &["main", "bravo"], b"2")
+            .unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "rust-crypto")]
+    fn verify_rejects_wrong_value_key_or_root() {
+        let (proof_ops, app_hash) = abci_query_fixture();
+
+        let invalid = |result: Result<(), crate::Error>| {
+            matches!(result.unwrap_err().detail(), ErrorDetail::InvalidProof(_))
+        };
+
+        let key_path = ["main", "bravo"];
+        assert!(invalid(proof_ops.verify(app_hash, &key_path, b"3")));
+        assert!(invalid(proof_ops.verify(
+            app_hash,
+            &["main", "alpha"],
+            b"2"
+        )));
+        assert!(invalid(proof_ops.verify(
+            Hash::Sha256([0; 32]),
+            &key_path,
+            b"2"
+        )));
+
+        // Proving the value against the root of the `main` store only
+        let mut inner = proof_ops.clone();
+        inner.ops.pop();
+        assert!(invalid(inner.verify(app_hash, &key_path, b"2")));
+        assert!(invalid(inner.verify(app_hash, &["bravo"], b"2")));
+
+        let empty = ProofOps { ops: vec![] };
+        assert!(invalid(empty.verify(app_hash, &key_path, b"2")));
+    }
+
+    #[test]
+    #[cfg(feature = "rust-crypto")]
+    fn verify_checks_every_key_of_the_path() {
+        let (proof_ops, app_hash) = abci_query_fixture();
+
+        let invalid = |key_path: &[&str]| {
+            let err = proof_ops.verify(app_hash, key_path, b"2").unwrap_err();
+            matches!(err.detail(), ErrorDetail::InvalidProof(_))
+        };
+
+        // The value is stored under the same key, but in another store
+        assert!(invalid(&["other", "bravo"]));
+        // The store is left out, or the path goes deeper than the proof
+        assert!(invalid(&["bravo"]));
+        assert!(invalid(&["root", "main", "bravo"]));
+    }
+
+    #[test]
+    #[cfg(feature = "rust-crypto")]
+    fn verify_rejects_unsupported_proof_op() {
+        let (mut proof_ops, app_hash) = abci_query_fixture();
+        proof_ops.ops[0].field_type = "ics23:iavl".to_string();
+
+        let err = proof_ops
+            .verify(app_hash, &["main", "bravo"], b"2")
+            .unwrap_err();
+        match err.detail() {
+            ErrorDetail::UnsupportedProofOp(e) => assert_eq!(e.op_type, "ics23:iavl"),
+            _ => panic!("expected an unsupported proof operation, got: {err}"),
+        }
+    }
 
     #[test]
     fn serialization_roundtrip() {
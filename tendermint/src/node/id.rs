@@ -135,3 +135,34 @@ impl Serialize for Id {
         self.to_string().serialize(serializer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // From `config/tests/support/config/node_key.json`
+    const NODE_ID: &str = "1a7b6bcf3d6fb055ab3aebca415847531b626699";
+
+    #[test]
+    #[cfg(feature = "rust-crypto")]
+    fn id_from_public_key() {
+        let public_key = crate::PublicKey::from_raw_ed25519(
+            &hex::decode("a40485737bb06f2c61a7ef6980ebcaafb9b9dec77d0f86825c5c7198b4c97fb4")
+                .unwrap(),
+        )
+        .unwrap();
+
+        let id = Id::try_from(public_key).unwrap();
+        assert_eq!(id.to_string(), NODE_ID);
+    }
+
+    #[test]
+    fn id_display_from_str_roundtrip() {
+        let id = Id::from_str(NODE_ID).unwrap();
+        assert_eq!(id.to_string(), NODE_ID);
+        assert_eq!(Id::from_str(&NODE_ID.to_uppercase()).unwrap(), id);
+
+        assert!(Id::from_str(&NODE_ID[..38]).is_err());
+        assert!(Id::from_str("not a node id").is_err());
+    }
+}
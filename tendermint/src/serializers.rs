@@ -11,5 +11,6 @@ pub mod allow_empty_object;
 pub mod apphash;
 pub mod apphash_base64;
 pub mod hash;
+pub mod hash_base64;
 pub mod option_hash;
 pub mod time;
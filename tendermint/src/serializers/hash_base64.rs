@@ -0,0 +1,27 @@
+//! Base64 hash serialization with validation
+
+use alloc::borrow::Cow;
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+use crate::{hash::Algorithm, prelude::*, Hash};
+
+/// Deserialize a base64-encoded string into a [`Hash`](enum@crate::hash::Hash),
+/// which is `Hash::None` if the string is empty or missing.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Hash, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let base64_string =
+        Option::<Cow<'_, str>>::deserialize(deserializer)?.unwrap_or(Cow::Borrowed(""));
+    Hash::from_base64(Algorithm::Sha256, &base64_string).map_err(de::Error::custom)
+}
+
+/// Serialize from [`Hash`](enum@crate::hash::Hash) into a base64-encoded string.
+pub fn serialize<S>(value: &Hash, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    // Serialize as Option<String> for symmetry with deserialize
+    serializer.serialize_some(&value.to_base64())
+}
@@ -14,8 +14,13 @@ use crate::{error::Error, prelude::*};
 pub const SIGNATURE_LENGTH: usize = 64;
 
 /// Signatures
+///
+/// As all supported signatures have the same length, they are stored inline rather
+/// than on the heap, which keeps large commits compact: see [`CommitSig`].
+///
+/// [`CommitSig`]: crate::block::CommitSig
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Signature(Vec<u8>);
+pub struct Signature([u8; SIGNATURE_LENGTH]);
 
 impl Protobuf<Vec<u8>> for Signature {}
 
@@ -37,7 +42,7 @@ impl TryFrom<&[u8]> for Signature {
 
 impl From<Signature> for Vec<u8> {
     fn from(value: Signature) -> Self {
-        value.0
+        value.0.to_vec()
     }
 }
 
@@ -51,7 +56,7 @@ impl TryFrom<Bytes> for Signature {
 
 impl From<Signature> for Bytes {
     fn from(value: Signature) -> Self {
-        value.0.into()
+        Bytes::copy_from_slice(&value.0)
     }
 }
 
@@ -64,15 +69,15 @@ impl Signature {
         if bytes.is_empty() {
             return Ok(None);
         }
-        if bytes.len() != SIGNATURE_LENGTH {
-            return Err(Error::signature_invalid(format!(
+        let bytes = bytes.try_into().map_err(|_| {
+            Error::signature_invalid(format!(
                 "expected signature to be {} bytes long, but was {} bytes",
                 SIGNATURE_LENGTH,
                 bytes.len()
-            )));
-        }
+            ))
+        })?;
 
-        Ok(Some(Self(bytes.to_vec())))
+        Ok(Some(Self(bytes)))
     }
 
     fn new_non_empty<B: AsRef<[u8]>>(bytes: B) -> Result<Self, Error> {
@@ -86,7 +91,7 @@ impl Signature {
 
     /// Return the underlying byte array
     pub fn into_bytes(self) -> Vec<u8> {
-        self.0
+        self.0.to_vec()
     }
 }
 
@@ -98,20 +103,20 @@ impl AsRef<[u8]> for Signature {
 
 impl From<Ed25519Signature> for Signature {
     fn from(sig: Ed25519Signature) -> Signature {
-        Self(sig.to_vec())
+        Self(sig.to_bytes())
     }
 }
 
 #[cfg(feature = "rust-crypto")]
 impl From<ed25519_consensus::Signature> for Signature {
     fn from(sig: ed25519_consensus::Signature) -> Signature {
-        Self(sig.to_bytes().to_vec())
+        Self(sig.to_bytes())
     }
 }
 
 #[cfg(feature = "secp256k1")]
 impl From<Secp256k1Signature> for Signature {
     fn from(sig: Secp256k1Signature) -> Signature {
-        Self(sig.to_vec())
+        Self(sig.to_bytes().into())
     }
 }
@@ -29,6 +29,10 @@ use crate::{error::Error, prelude::*};
 /// up to 9 digits and no trailing zeros, and the UTC offset denoted by Z.
 /// This reproduces the behavior of Go's `time.RFC3339Nano` format.
 ///
+/// Any RFC 3339 string can be deserialized, with trailing zeros in the
+/// subsecond fraction, a UTC offset other than Z, or lowercase separators.
+/// Digits beyond the nanosecond are truncated, as in Go.
+///
 /// [specification]: https://developers.google.com/protocol-buffers/docs/reference/google.protobuf#google.protobuf.Timestamp
 // For memory efficiency, the inner member is `PrimitiveDateTime`, with assumed
 // UTC offset. The `assume_utc` method is used to get the operational
@@ -97,6 +101,9 @@ impl Time {
 
     /// Calculate the amount of time which has passed since another [`Time`]
     /// as a [`core::time::Duration`]
+    ///
+    /// Returns an error if `other` is later than this time, as a
+    /// [`core::time::Duration`] cannot be negative.
     pub fn duration_since(&self, other: Time) -> Result<Duration, Error> {
         let duration = self.0.assume_utc() - other.0.assume_utc();
         duration
@@ -128,6 +135,9 @@ impl Time {
     }
 
     /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// The result overflows when it is later than `9999-12-31T23:59:59.999999999Z`,
+    /// the latest time a `Timestamp` can represent.
     pub fn checked_add(self, duration: Duration) -> Option<Self> {
         let duration = duration.try_into().ok()?;
         let t = self.0.checked_add(duration)?;
@@ -135,6 +145,9 @@ impl Time {
     }
 
     /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// The result may precede the unix epoch, and overflows when it is earlier
+    /// than `0001-01-01T00:00:00Z`, the earliest time a `Timestamp` can represent.
     pub fn checked_sub(self, duration: Duration) -> Option<Self> {
         let duration = duration.try_into().ok()?;
         let t = self.0.checked_sub(duration)?;
@@ -297,6 +310,22 @@ mod tests {
             prop_assert_eq!(time, decoded_time);
         }
 
+        #[test]
+        fn serde_of_time_is_byte_identical(
+            datetime in pbt::time::arb_protobuf_safe_datetime()
+        ) {
+            // Re-serializing a deserialized time reproduces the same string,
+            // which has no trailing zeros in its subsecond fraction, as with
+            // Go's `time.RFC3339Nano` format.
+            let time: Time = datetime.try_into().unwrap();
+            let json = serde_json::to_string(&time).unwrap();
+            let decoded_time: Time = serde_json::from_str(&json).unwrap();
+            prop_assert_eq!(serde_json::to_string(&decoded_time).unwrap(), json.clone());
+            if let Some((_, secfrac)) = json.split_once('.') {
+                prop_assert!(!secfrac.trim_end_matches("Z\"").ends_with('0'));
+            }
+        }
+
         #[test]
         fn conversion_unix_timestamp_is_safe(
             stamp in prop_oneof![
@@ -343,6 +372,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserializes_other_rfc3339_formats() {
+        let timestamps = [
+            (
+                "2021-01-07T20:25:56.045576000Z",
+                "2021-01-07T20:25:56.045576Z",
+            ),
+            ("2021-01-07T20:25:56.0000000001Z", "2021-01-07T20:25:56Z"),
+            (
+                "2021-01-07T20:25:56.1234567891Z",
+                "2021-01-07T20:25:56.123456789Z",
+            ),
+            ("2021-01-07t20:25:56.5z", "2021-01-07T20:25:56.5Z"),
+            ("2021-01-07T20:25:56.5+02:00", "2021-01-07T18:25:56.5Z"),
+            ("2021-01-07T20:25:56-07:30", "2021-01-08T03:55:56Z"),
+        ];
+
+        for (timestamp, canonical) in timestamps {
+            let time: Time = serde_json::from_str(&format!("\"{timestamp}\"")).unwrap();
+            assert_eq!(
+                serde_json::to_string(&time).unwrap(),
+                format!("\"{canonical}\"")
+            );
+        }
+    }
+
     fn duration_from_nanos(whole_nanos: u128) -> Duration {
         let secs: u64 = (whole_nanos / 1_000_000_000).try_into().unwrap();
         let nanos = (whole_nanos % 1_000_000_000) as u32;
@@ -434,4 +489,37 @@ mod tests {
             assert_eq!(t.checked_sub(d), None);
         }
     }
+
+    #[test]
+    fn checked_arithmetic_around_epoch() {
+        let epoch = Time::unix_epoch();
+        let one_second = Duration::from_secs(1);
+
+        let before = epoch.checked_sub(one_second).unwrap();
+        assert_eq!(before.unix_timestamp(), -1);
+        assert_eq!(before.checked_add(one_second), Some(epoch));
+        assert_eq!(epoch.duration_since(before).unwrap(), one_second);
+        assert_eq!(epoch.duration_since(epoch).unwrap(), Duration::ZERO);
+
+        let e = before.duration_since(epoch).unwrap_err();
+        assert!(matches!(e.detail(), ErrorDetail::DurationOutOfRange(_)));
+    }
+
+    #[test]
+    fn checked_arithmetic_at_range_boundaries() {
+        let min = Time::parse_from_rfc3339("0001-01-01T00:00:00Z").unwrap();
+        let max = Time::parse_from_rfc3339("9999-12-31T23:59:59.999999999Z").unwrap();
+        let one_nano = Duration::from_nanos(1);
+
+        assert_eq!(min.checked_sub(Duration::ZERO), Some(min));
+        assert_eq!(min.checked_sub(one_nano), None);
+        assert_eq!(max.checked_add(Duration::ZERO), Some(max));
+        assert_eq!(max.checked_add(one_nano), None);
+        assert_eq!(min.checked_add(Duration::MAX), None);
+        assert_eq!(max.checked_sub(Duration::MAX), None);
+
+        let span = max.duration_since(min).unwrap();
+        assert_eq!(min.checked_add(span), Some(max));
+        assert_eq!(max.checked_sub(span), Some(min));
+    }
 }
@@ -57,7 +57,7 @@ impl TrustThresholdFraction {
         if denominator == 0 {
             return Err(Error::undefined_trust_threshold());
         }
-        if 3 * numerator < denominator {
+        if 3 * u128::from(numerator) < u128::from(denominator) {
             return Err(Error::trust_threshold_too_small());
         }
         Ok(Self {
@@ -96,7 +96,9 @@ impl From<TrustThresholdFraction> for RawTrustThresholdFraction {
 
 impl TrustThreshold for TrustThresholdFraction {
     fn is_enough_power(&self, signed_voting_power: u64, total_voting_power: u64) -> bool {
-        signed_voting_power * self.denominator > total_voting_power * self.numerator
+        // Widen to avoid overflowing with large voting powers or fractions
+        u128::from(signed_voting_power) * u128::from(self.denominator)
+            > u128::from(total_voting_power) * u128::from(self.numerator)
     }
 }
 
@@ -185,5 +187,12 @@ mod test {
             assert!(TrustThresholdFraction::new(num, num).is_ok());
             assert!(from_json(num, num).is_ok());
         }
+
+        #[test]
+        fn large_fractions_do_not_overflow(num in (u64::MAX / 3)..u64::MAX) {
+            let frac = TrustThresholdFraction::new(num, u64::MAX).unwrap();
+            assert!(frac.is_enough_power(u64::MAX, u64::MAX));
+            assert!(!frac.is_enough_power(0, u64::MAX));
+        }
     }
 }
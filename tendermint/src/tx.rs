@@ -1,3 +1,196 @@
 mod proof;
 
 pub use proof::Proof;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto::Sha256,
+    merkle::{self, MerkleHash},
+    prelude::*,
+    serializers, Hash,
+};
+
+/// Raw bytes of a transaction, as included in a block.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Tx(#[serde(with = "serializers::bytes::base64string")] Vec<u8>);
+
+impl Tx {
+    /// Compute the hash of this transaction, as Tendermint does to index it,
+    /// eg. in the results of `tx_search`.
+    #[cfg(feature = "rust-crypto")]
+    pub fn hash(&self) -> Hash {
+        self.hash_with::<crate::crypto::default::Sha256>()
+    }
+
+    /// Compute the hash of this transaction with a hasher provided by a crypto provider.
+    pub fn hash_with<H: Sha256>(&self) -> Hash {
+        Hash::Sha256(H::digest(&self.0))
+    }
+
+    /// Borrow the raw bytes of this transaction.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Take the raw bytes of this transaction.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for Tx {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Tx {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Tx> for Vec<u8> {
+    fn from(tx: Tx) -> Self {
+        tx.0
+    }
+}
+
+/// Transactions of a block, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Txs(Vec<Tx>);
+
+impl Txs {
+    /// Compute the Merkle root of these transactions, which matches the
+    /// `data_hash` of the header of the block they belong to.
+    #[cfg(feature = "rust-crypto")]
+    pub fn data_hash(&self) -> Hash {
+        data_hash(&self.0)
+    }
+
+    /// Compute the Merkle root of these transactions with a Merkle hasher
+    /// provided by a crypto provider.
+    pub fn data_hash_with<H>(&self) -> Hash
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        data_hash_with::<H>(&self.0)
+    }
+
+    /// Iterate over these transactions.
+    pub fn iter(&self) -> core::slice::Iter<'_, Tx> {
+        self.0.iter()
+    }
+
+    /// Number of transactions.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no transactions.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl AsRef<[Tx]> for Txs {
+    fn as_ref(&self) -> &[Tx] {
+        &self.0
+    }
+}
+
+impl From<Vec<Tx>> for Txs {
+    fn from(txs: Vec<Tx>) -> Self {
+        Self(txs)
+    }
+}
+
+impl From<Vec<Vec<u8>>> for Txs {
+    fn from(txs: Vec<Vec<u8>>) -> Self {
+        txs.into_iter().collect()
+    }
+}
+
+impl<T: Into<Tx>> FromIterator<T> for Txs {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl IntoIterator for Txs {
+    type Item = Tx;
+    type IntoIter = vec::IntoIter<Tx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Txs {
+    type Item = &'a Tx;
+    type IntoIter = core::slice::Iter<'a, Tx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Compute the hash of the given transactions, as committed to by the
+/// `data_hash` of block headers.
+#[cfg(feature = "rust-crypto")]
+pub fn data_hash(txs: &[impl AsRef<[u8]>]) -> Hash {
+    data_hash_with::<crate::crypto::default::Sha256>(txs)
+}
+
+/// Hash the given transactions with a Merkle hasher provided by a crypto provider.
+///
+/// The leaves of the Merkle tree are the SHA256 hashes of the transactions.
+pub fn data_hash_with<H>(txs: &[impl AsRef<[u8]>]) -> Hash
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    let tx_hashes: Vec<_> = txs.iter().map(H::digest).collect();
+    Hash::Sha256(merkle::simple_hash_from_byte_vectors::<H>(&tx_hashes))
+}
+
+#[cfg(all(test, feature = "rust-crypto"))]
+mod tests {
+    use super::*;
+
+    // Block at height 44 of the kvstore fixtures of the RPC crate,
+    // and the hash of its transaction as returned by `tx_search`
+    const TX: &[u8] = b"async-key=value";
+    const TX_HASH: &str = "9F28904F9C0F3AB74A81CBA48E39124DA1C680B47FBFCBA0126870DB722BCC30";
+    const DATA_HASH: &str = "3081F9915040D138B3AD7F895732D2767C29E85BA5D84388D04E17A5D8262B7A";
+
+    // Hash of the empty blocks of the same fixtures
+    const EMPTY_DATA_HASH: &str =
+        "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855";
+
+    #[test]
+    fn tx_hash() {
+        let tx = Tx::from(TX.to_vec());
+        assert_eq!(tx.hash().to_string(), TX_HASH);
+    }
+
+    #[test]
+    fn txs_data_hash() {
+        let txs = Txs::from(vec![TX.to_vec()]);
+        assert_eq!(txs.data_hash().to_string(), DATA_HASH);
+        assert_eq!(txs.data_hash(), data_hash(&[TX]));
+
+        assert_eq!(Txs::default().data_hash().to_string(), EMPTY_DATA_HASH);
+    }
+
+    #[test]
+    fn txs_serde() {
+        let txs: Txs = serde_json::from_str(r#"["YXN5bmMta2V5PXZhbHVl"]"#).unwrap();
+        assert_eq!(txs.iter().next().unwrap().as_bytes(), TX);
+        assert_eq!(
+            serde_json::to_string(&txs).unwrap(),
+            r#"["YXN5bmMta2V5PXZhbHVl"]"#
+        );
+    }
+}
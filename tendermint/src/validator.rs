@@ -1,5 +1,8 @@
 //! Tendermint validators
 
+use alloc::collections::BTreeMap;
+use core::{fmt, marker::PhantomData};
+
 use serde::{Deserialize, Serialize};
 use tendermint_proto::v0_38::types::{
     SimpleValidator as RawSimpleValidator, ValidatorSet as RawValidatorSet,
@@ -18,23 +21,47 @@ use crate::{
 };
 
 /// Validator set contains a vector of validators
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(try_from = "RawValidatorSet")]
 pub struct Set {
     validators: Vec<Info>,
     proposer: Option<Info>,
     total_voting_power: vote::Power,
+    /// Indices of the validators, sorted by address
+    #[serde(skip)]
+    by_address: Vec<usize>,
+}
+
+impl fmt::Debug for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Set")
+            .field("validators", &self.validators)
+            .field("proposer", &self.proposer)
+            .field("total_voting_power", &self.total_voting_power)
+            .finish()
+    }
 }
 
 impl Set {
+    /// Maximum total voting power of a validator set, as enforced by
+    /// Tendermint (`MaxTotalVotingPower`).
     pub const MAX_TOTAL_VOTING_POWER: u64 = (i64::MAX / 8) as u64;
 
     /// Constructor
+    ///
+    /// Panics if the total voting power of the validators exceeds
+    /// [`Set::MAX_TOTAL_VOTING_POWER`], see [`Set::try_new`] otherwise.
     pub fn new(validators: Vec<Info>, proposer: Option<Info>) -> Set {
-        Self::try_from_parts(validators, proposer, 0).unwrap()
+        Self::try_new(validators, proposer).unwrap()
+    }
+
+    /// Constructor failing if the total voting power of the validators
+    /// exceeds [`Set::MAX_TOTAL_VOTING_POWER`].
+    pub fn try_new(validators: Vec<Info>, proposer: Option<Info>) -> Result<Set, Error> {
+        Self::try_from_parts(validators, proposer, 0)
     }
 
-    fn try_from_parts(
+    pub(crate) fn try_from_parts(
         mut validators: Vec<Info>,
         proposer: Option<Info>,
         unvalidated_total_voting_power: i64,
@@ -63,10 +90,14 @@ impl Set {
 
         Self::sort_validators(&mut validators);
 
+        let mut by_address: Vec<usize> = (0..validators.len()).collect();
+        by_address.sort_by_key(|&i| validators[i].address);
+
         Ok(Set {
             validators,
             proposer,
             total_voting_power,
+            by_address,
         })
     }
 
@@ -89,7 +120,7 @@ impl Set {
 
         // Create the validator set with the given proposer.
         // This is required by IBC on-chain validation.
-        Ok(Self::new(validators, Some(proposer)))
+        Self::try_new(validators, Some(proposer))
     }
 
     /// Get Info of the underlying validators.
@@ -115,10 +146,17 @@ impl Set {
 
     /// Returns the validator with the given Id if its in the Set.
     pub fn validator(&self, val_id: account::Id) -> Option<Info> {
-        self.validators
-            .iter()
-            .find(|val| val.address == val_id)
-            .cloned()
+        self.get(val_id).cloned()
+    }
+
+    /// Returns a reference to the validator with the given address if it is in the set.
+    ///
+    /// The validator is looked up by binary search, in `O(log n)`.
+    pub fn get(&self, address: account::Id) -> Option<&Info> {
+        self.by_address
+            .binary_search_by_key(&address, |&i| self.validators[i].address)
+            .ok()
+            .map(|i| &self.validators[self.by_address[i]])
     }
 
     /// Compute the hash of this validator set.
@@ -142,6 +180,150 @@ impl Set {
     }
 }
 
+/// A validator set along with the Merkle tree over its validators, so that its
+/// hash can be recomputed after updates without rehashing the parts of the tree
+/// which are left unchanged, eg. when walking a range of blocks over which the
+/// validator set barely changes.
+#[derive(Clone, Debug)]
+pub struct IncrementalSet<H> {
+    set: Set,
+    hash: Hash,
+    /// Hashes of the leaves of the tree, by the bytes they are computed from
+    leaves: BTreeMap<Vec<u8>, merkle::Hash>,
+    /// Hashes of the inner nodes of the tree, by the hashes of their children
+    nodes: BTreeMap<(merkle::Hash, merkle::Hash), merkle::Hash>,
+    hasher: PhantomData<H>,
+}
+
+impl<H> IncrementalSet<H>
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    /// Build the Merkle tree over the given validator set.
+    pub fn new(set: Set) -> Self {
+        let mut incremental = Self {
+            set,
+            hash: Hash::None,
+            leaves: BTreeMap::new(),
+            nodes: BTreeMap::new(),
+            hasher: PhantomData,
+        };
+
+        incremental.rehash();
+        incremental
+    }
+
+    /// Get the validator set.
+    pub fn set(&self) -> &Set {
+        &self.set
+    }
+
+    /// Get the hash of the validator set, equal to the one computed by [`Set::hash_with`].
+    pub fn hash(&self) -> Hash {
+        self.hash
+    }
+
+    /// Apply the given updates to the validator set in order, returning its new hash.
+    ///
+    /// A validator is added if it is not in the set yet, or updated with its new
+    /// voting power otherwise, in which case a power of zero removes it from the set.
+    /// The validator set is left untouched if any update cannot be applied.
+    #[cfg(feature = "rust-crypto")]
+    pub fn apply_updates(&mut self, updates: &[Update]) -> Result<Hash, Error> {
+        let mut validators: BTreeMap<account::Id, Info> = self
+            .set
+            .validators
+            .iter()
+            .map(|validator| (validator.address, validator.clone()))
+            .collect();
+
+        for update in updates {
+            let address = account::Id::from(update.pub_key);
+
+            if update.power.is_zero() {
+                validators
+                    .remove(&address)
+                    .ok_or_else(|| Error::validator_not_found(address))?;
+            } else {
+                validators
+                    .entry(address)
+                    .and_modify(|validator| validator.power = update.power)
+                    .or_insert_with(|| Info::new(update.pub_key, update.power));
+            }
+        }
+
+        // Keep the proposer if it is still part of the set
+        let proposer = self
+            .set
+            .proposer
+            .as_ref()
+            .and_then(|proposer| validators.get(&proposer.address))
+            .cloned();
+
+        let validators = validators.into_values().collect();
+        self.set = Set::try_from_parts(validators, proposer, 0)?;
+        self.rehash();
+
+        Ok(self.hash)
+    }
+
+    /// Recompute the hash of the validator set, reusing the hashes from
+    /// the previous tree and keeping only those of the new one.
+    fn rehash(&mut self) {
+        let mut hasher = H::default();
+
+        let mut leaves = BTreeMap::new();
+        let leaf_hashes: Vec<merkle::Hash> = self
+            .set
+            .validators
+            .iter()
+            .map(|validator| {
+                let bytes = validator.hash_bytes();
+                let hash = match self.leaves.get(&bytes) {
+                    Some(hash) => *hash,
+                    None => hasher.leaf_hash(&bytes),
+                };
+                leaves.insert(bytes, hash);
+                hash
+            })
+            .collect();
+
+        let mut nodes = BTreeMap::new();
+        let root = if leaf_hashes.is_empty() {
+            hasher.empty_hash()
+        } else {
+            Self::subtree_hash(&mut hasher, &leaf_hashes, &self.nodes, &mut nodes)
+        };
+
+        self.hash = Hash::Sha256(root);
+        self.leaves = leaves;
+        self.nodes = nodes;
+    }
+
+    // Mirrors the recursion of `MerkleHash::hash_byte_vectors`, over the leaf hashes.
+    fn subtree_hash(
+        hasher: &mut H,
+        leaf_hashes: &[merkle::Hash],
+        cached: &BTreeMap<(merkle::Hash, merkle::Hash), merkle::Hash>,
+        nodes: &mut BTreeMap<(merkle::Hash, merkle::Hash), merkle::Hash>,
+    ) -> merkle::Hash {
+        if let [leaf_hash] = leaf_hashes {
+            return *leaf_hash;
+        }
+
+        let split = leaf_hashes.len().next_power_of_two() / 2;
+        let left = Self::subtree_hash(hasher, &leaf_hashes[..split], cached, nodes);
+        let right = Self::subtree_hash(hasher, &leaf_hashes[split..], cached, nodes);
+
+        let hash = match cached.get(&(left, right)) {
+            Some(hash) => *hash,
+            None => hasher.inner_hash(left, right),
+        };
+        nodes.insert((left, right), hash);
+        hash
+    }
+}
+
 /// Validator information
 // Todo: Remove address and make it into a function that generates it on the fly from pub_key.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -478,6 +660,135 @@ mod tests {
                 148_151_478_422_287_875 + 158_095_448_483_785_107 + 770_561_664_770_006_272
             );
         }
+
+        fn public_key(seed: u8) -> PublicKey {
+            PublicKey::from(ed25519_consensus::SigningKey::from([seed; 32]).verification_key())
+        }
+
+        #[test]
+        fn validator_set_total_voting_power_boundary() {
+            let validators = |last_power: u64| {
+                vec![
+                    Info::new(public_key(1), vote::Power::try_from(1_u64).unwrap()),
+                    Info::new(public_key(2), vote::Power::try_from(last_power).unwrap()),
+                ]
+            };
+
+            let max = Set::MAX_TOTAL_VOTING_POWER;
+            let set = Set::try_new(validators(max - 1), None).unwrap();
+            assert_eq!(set.total_voting_power().value(), max);
+
+            let err = Set::try_new(validators(max), None).unwrap_err();
+            assert!(matches!(
+                err.detail(),
+                crate::error::ErrorDetail::TotalVotingPowerOverflow(_)
+            ));
+
+            // The sum of the voting powers would overflow an `i64`
+            let max_power = i64::MAX as u64;
+            assert!(Set::try_new(validators(max_power), None).is_err());
+
+            let err =
+                Set::with_proposer(validators(max), account::Id::from(public_key(1))).unwrap_err();
+            assert!(matches!(
+                err.detail(),
+                crate::error::ErrorDetail::TotalVotingPowerOverflow(_)
+            ));
+        }
+
+        #[test]
+        fn incremental_set_rejects_removing_missing_validator() {
+            let set = Set::without_proposer(vec![Info::new(public_key(1), 10_u32.into())]);
+            let mut incremental = IncrementalSet::<crate::crypto::default::Sha256>::new(set);
+
+            let update = Update {
+                pub_key: public_key(2),
+                power: 0_u32.into(),
+            };
+            assert!(incremental.apply_updates(&[update]).is_err());
+            assert_eq!(incremental.set().validators().len(), 1);
+            assert_eq!(incremental.hash(), incremental.set().hash());
+        }
+
+        #[test]
+        fn validator_set_get_by_address() {
+            let validators: Vec<Info> = (1..=5)
+                .map(|seed| Info::new(public_key(seed), (u32::from(seed) % 3 + 1).into()))
+                .collect();
+            let proposer = validators[2].clone();
+            let set = Set::with_proposer(validators.clone(), proposer.address).unwrap();
+
+            for validator in &validators {
+                assert_eq!(set.get(validator.address), Some(validator));
+                assert_eq!(set.validator(validator.address).as_ref(), Some(validator));
+            }
+
+            let missing = account::Id::from(public_key(6));
+            assert_eq!(set.get(missing), None);
+            assert_eq!(set.proposer().as_ref(), Some(&proposer));
+
+            // The index is not part of the serialized validator set
+            let json = serde_json::to_string(&set).unwrap();
+            assert!(!json.contains("by_address"));
+        }
+
+        proptest::proptest! {
+            #[test]
+            fn incremental_hash_matches_full_hash(
+                initial in proptest::collection::btree_map(0..16_u8, 1..50_u32, 0..16),
+                rounds in proptest::collection::vec(
+                    proptest::collection::vec((0..16_u8, 0..50_u32), 0..8),
+                    1..8,
+                ),
+            ) {
+                let validators = initial
+                    .iter()
+                    .map(|(seed, power)| Info::new(public_key(*seed), (*power).into()))
+                    .collect();
+                let mut incremental =
+                    IncrementalSet::<crate::crypto::default::Sha256>::new(Set::without_proposer(validators));
+                let mut powers = initial;
+
+                assert_eq!(incremental.hash(), incremental.set().hash());
+
+                for round in rounds {
+                    // Only remove validators which are part of the set
+                    let updates: Vec<Update> = round
+                        .into_iter()
+                        .filter(|(seed, power)| {
+                            if *power == 0 {
+                                powers.remove(seed).is_some()
+                            } else {
+                                powers.insert(*seed, *power);
+                                true
+                            }
+                        })
+                        .map(|(seed, power)| Update {
+                            pub_key: public_key(seed),
+                            power: power.into(),
+                        })
+                        .collect();
+
+                    let hash = incremental.apply_updates(&updates).unwrap();
+
+                    let expected = Set::without_proposer(
+                        powers
+                            .iter()
+                            .map(|(seed, power)| Info::new(public_key(*seed), (*power).into()))
+                            .collect(),
+                    );
+                    assert_eq!(incremental.set(), &expected);
+                    assert_eq!(hash, expected.hash());
+                    assert_eq!(incremental.hash(), hash);
+
+                    for seed in 0..16 {
+                        let address = account::Id::from(public_key(seed));
+                        let validator = incremental.set().get(address);
+                        assert_eq!(validator.map(|v| v.address), powers.contains_key(&seed).then_some(address));
+                    }
+                }
+            }
+        }
     }
 
     #[test]
@@ -1,6 +1,7 @@
 //! Votes from validators
 
 mod canonical_vote;
+mod encoding;
 mod power;
 mod sign_vote;
 mod validator_index;
@@ -13,7 +14,8 @@ use tendermint_proto::v0_38::types::{CanonicalVote as RawCanonicalVote, Vote as
 use tendermint_proto::{Error as ProtobufError, Protobuf};
 
 pub use self::{
-    canonical_vote::CanonicalVote, power::Power, sign_vote::*, validator_index::ValidatorIndex,
+    canonical_vote::CanonicalVote, encoding::EncodingScheme, power::Power, sign_vote::*,
+    validator_index::ValidatorIndex,
 };
 use crate::{
     account, block, chain::Id as ChainId, consensus::State, error::Error, hash, prelude::*,
@@ -326,11 +328,22 @@ impl SignedVote {
 
     /// Return the bytes (of the canonicalized vote) that were signed.
     pub fn sign_bytes(&self) -> Vec<u8> {
-        Protobuf::<RawCanonicalVote>::encode_length_delimited_vec(self.vote.clone())
+        self.vote.sign_bytes()
     }
 
+    /// Write the bytes (of the canonicalized vote) that were signed into the given buffer.
     pub fn sign_bytes_into(&self, buf: &mut impl BufMut) -> Result<(), ProtobufError> {
-        Protobuf::<RawCanonicalVote>::encode_length_delimited(self.vote.clone(), buf)
+        self.vote.sign_bytes_into(buf)
+    }
+
+    /// Write the bytes (of the canonicalized vote) that were signed into the given buffer,
+    /// encoded with the given scheme.
+    pub fn sign_bytes_into_with(
+        &self,
+        scheme: EncodingScheme,
+        buf: &mut impl BufMut,
+    ) -> Result<(), ProtobufError> {
+        scheme.sign_bytes_into(&self.vote, buf)
     }
 
     /// Return the actual signature on the canonicalized vote.
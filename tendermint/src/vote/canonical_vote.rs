@@ -1,5 +1,7 @@
+use bytes::BufMut;
 use serde::{Deserialize, Serialize};
 use tendermint_proto::v0_37::types::CanonicalVote as RawCanonicalVote;
+use tendermint_proto::{Error as ProtobufError, Protobuf};
 
 use crate::{block, chain::Id as ChainId, prelude::*, Time};
 
@@ -87,6 +89,20 @@ impl CanonicalVote {
             chain_id,
         }
     }
+
+    /// The bytes signed by validators for this vote, ie. the length-prefixed
+    /// protobuf encoding of the canonical vote, as checked by the light client
+    /// verifier and produced by CometBFT.
+    pub fn sign_bytes(&self) -> Vec<u8> {
+        Protobuf::<RawCanonicalVote>::encode_length_delimited_vec(self.clone())
+    }
+
+    /// Write the bytes signed by validators for this vote into the given buffer.
+    ///
+    /// See [`CanonicalVote::sign_bytes`].
+    pub fn sign_bytes_into(&self, buf: &mut impl BufMut) -> Result<(), ProtobufError> {
+        Protobuf::<RawCanonicalVote>::encode_length_delimited(self.clone(), buf)
+    }
 }
 
 #[cfg(test)]
@@ -138,4 +154,65 @@ mod tests {
             assert!(CanonicalVote::try_from(proto_cp).is_err());
         }
     }
+
+    #[test]
+    #[cfg(feature = "rust-crypto")]
+    fn sign_bytes_match_signature_from_node() {
+        use core::str::FromStr;
+
+        use subtle_encoding::base64;
+
+        use crate::{
+            block,
+            chain::Id as ChainId,
+            crypto::{default::signature::Verifier, signature::Verifier as _},
+            prelude::*,
+            vote::{CanonicalVote, Type},
+            Hash, PublicKey, Signature, Time,
+        };
+
+        // Precommit for the block at height 10 of a CometBFT 0.37 node with a single validator,
+        // see `rpc/tests/kvstore_fixtures/v0_37/incoming/commit_at_height_10.json`
+        let vote = CanonicalVote {
+            vote_type: Type::Precommit,
+            height: 10_u32.into(),
+            round: 0_u16.into(),
+            block_id: Some(block::Id {
+                hash: Hash::from_str(
+                    "FCF9C2537FC3534CA71001FE1F14C4F769090948C1A521682F612E7CF73AE639",
+                )
+                .unwrap(),
+                part_set_header: block::parts::Header::new(
+                    1,
+                    Hash::from_str(
+                        "E16EDCB0EC135191F5C017FDF232967F50919E06B0F2F419FA93D006E606CF05",
+                    )
+                    .unwrap(),
+                )
+                .unwrap(),
+            }),
+            timestamp: Some(Time::from_str("2023-02-27T07:13:08.658439642Z").unwrap()),
+            chain_id: ChainId::try_from("dockerchain").unwrap(),
+        };
+
+        let pub_key = PublicKey::from_raw_ed25519(
+            &base64::decode("OYpM2RXHEO1/R3jJRhAbjY8JhvjTBbiNJKBStEKu12s=").unwrap(),
+        )
+        .unwrap();
+        let signature = Signature::new(
+            base64::decode(
+                "qJblJeAl6OtGRKkOa91+HLzX3ZGl/Nlnl5K9RiT2gRSPgPSjxq+95mSQSJ3b3I38mdZvYLUML6kEGvC/zjlJCQ==",
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .unwrap();
+
+        let sign_bytes = vote.sign_bytes();
+        Verifier::verify(pub_key, &sign_bytes, &signature).unwrap();
+
+        let mut buf = vec![];
+        vote.sign_bytes_into(&mut buf).unwrap();
+        assert_eq!(buf, sign_bytes);
+    }
 }
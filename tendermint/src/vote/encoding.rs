@@ -0,0 +1,198 @@
+//! Encoding schemes of the bytes signed by validators for votes.
+
+use bytes::BufMut;
+use tendermint_proto::v0_37::types::CanonicalVote as RawCanonicalVote;
+use tendermint_proto::{Error as ProtobufError, Protobuf};
+
+use super::CanonicalVote;
+
+/// How the canonical vote is encoded into the bytes signed by validators.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EncodingScheme {
+    /// Length-prefixed protobuf encoding, used since Tendermint 0.34.
+    #[default]
+    Protobuf,
+
+    /// Length-prefixed Amino encoding, used by Tendermint before 0.34.
+    #[cfg(feature = "amino")]
+    Amino,
+}
+
+impl EncodingScheme {
+    /// Write the bytes signed by validators for the given vote into the given buffer.
+    pub fn sign_bytes_into(
+        self,
+        vote: &CanonicalVote,
+        buf: &mut impl BufMut,
+    ) -> Result<(), ProtobufError> {
+        match self {
+            Self::Protobuf => {
+                Protobuf::<RawCanonicalVote>::encode_length_delimited(vote.clone(), buf)
+            },
+            #[cfg(feature = "amino")]
+            Self::Amino => {
+                amino::encode_length_delimited(vote, buf);
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Amino encoding of canonical votes.
+///
+/// The Amino encoding of a canonical vote only differs from its protobuf encoding
+/// by the order of the fields of the part set header of the block ID, ie. the hash
+/// comes before the total number of parts.
+#[cfg(feature = "amino")]
+mod amino {
+    use bytes::BufMut;
+    use prost::encoding::{encode_key, encode_varint, WireType};
+
+    use crate::{prelude::*, vote::CanonicalVote};
+
+    pub fn encode_length_delimited(vote: &CanonicalVote, buf: &mut impl BufMut) {
+        let mut msg = Vec::new();
+        encode_vote(vote, &mut msg);
+
+        encode_varint(msg.len() as u64, buf);
+        buf.put_slice(&msg);
+    }
+
+    fn encode_vote(vote: &CanonicalVote, buf: &mut Vec<u8>) {
+        let vote_type = i32::from(vote.vote_type);
+        if vote_type != 0 {
+            encode_key(1, WireType::Varint, buf);
+            encode_varint(vote_type as u64, buf);
+        }
+
+        let height = vote.height.value();
+        if height != 0 {
+            encode_key(2, WireType::SixtyFourBit, buf);
+            buf.put_u64_le(height);
+        }
+
+        let round = u64::from(vote.round.value());
+        if round != 0 {
+            encode_key(3, WireType::SixtyFourBit, buf);
+            buf.put_u64_le(round);
+        }
+
+        if let Some(block_id) = &vote.block_id {
+            let mut parts = Vec::new();
+            encode_bytes(1, block_id.part_set_header.hash.as_bytes(), &mut parts);
+            let total = block_id.part_set_header.total;
+            if total != 0 {
+                encode_key(2, WireType::Varint, &mut parts);
+                encode_varint(total.into(), &mut parts);
+            }
+
+            let mut id = Vec::new();
+            encode_bytes(1, block_id.hash.as_bytes(), &mut id);
+            encode_bytes(2, &parts, &mut id);
+
+            encode_bytes(4, &id, buf);
+        }
+
+        if let Some(timestamp) = vote.timestamp {
+            let timestamp = tendermint_proto::google::protobuf::Timestamp::from(timestamp);
+
+            let mut time = Vec::new();
+            if timestamp.seconds != 0 {
+                encode_key(1, WireType::Varint, &mut time);
+                encode_varint(timestamp.seconds as u64, &mut time);
+            }
+            if timestamp.nanos != 0 {
+                encode_key(2, WireType::Varint, &mut time);
+                encode_varint(timestamp.nanos as u64, &mut time);
+            }
+
+            encode_key(5, WireType::LengthDelimited, buf);
+            encode_varint(time.len() as u64, buf);
+            buf.put_slice(&time);
+        }
+
+        encode_bytes(6, vote.chain_id.as_bytes(), buf);
+    }
+
+    /// Encode a length-delimited field, omitted if empty.
+    fn encode_bytes(tag: u32, bytes: &[u8], buf: &mut Vec<u8>) {
+        if !bytes.is_empty() {
+            encode_key(tag, WireType::LengthDelimited, buf);
+            encode_varint(bytes.len() as u64, buf);
+            buf.put_slice(bytes);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "amino"))]
+mod tests {
+    use super::*;
+    use crate::{
+        block::{self, parts, Height, Round},
+        chain::Id as ChainId,
+        prelude::*,
+        vote::Type,
+        Hash, Time,
+    };
+
+    fn vote() -> CanonicalVote {
+        CanonicalVote {
+            vote_type: Type::Precommit,
+            height: Height::from(1_u32),
+            round: Round::from(1_u16),
+            block_id: Some(block::Id {
+                hash: Hash::Sha256([1; 32]),
+                part_set_header: parts::Header::new(1, Hash::Sha256([2; 32])).unwrap(),
+            }),
+            // The zero value of time in Go
+            timestamp: Some(Time::from_unix_timestamp(-62135596800, 0).unwrap()),
+            chain_id: ChainId::try_from("test_chain_id").unwrap(),
+        }
+    }
+
+    fn sign_bytes(scheme: EncodingScheme) -> Vec<u8> {
+        let mut buf = Vec::new();
+        scheme.sign_bytes_into(&vote(), &mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn amino_sign_bytes() {
+        let mut want = vec![
+            0x7a, // length
+            0x8, 0x2, // PrecommitType
+            0x11, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, // height
+            0x19, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, // round
+            0x22, 0x48, // block ID
+            0xa, 0x20, // block hash
+        ];
+        want.extend([1; 32]);
+        want.extend([
+            0x12, 0x24, // part set header
+            0xa, 0x20, // part set hash
+        ]);
+        want.extend([2; 32]);
+        want.extend([
+            0x10, 0x1, // part set total
+            0x2a, 0xb, 0x8, 0x80, 0x92, 0xb8, 0xc3, 0x98, 0xfe, 0xff, 0xff, 0xff,
+            0x1, // timestamp
+            0x32, 0xd, // chain ID
+        ]);
+        want.extend(b"test_chain_id");
+
+        assert_eq!(sign_bytes(EncodingScheme::Amino), want);
+    }
+
+    #[test]
+    fn amino_and_protobuf_differ_by_part_set_header() {
+        let amino = sign_bytes(EncodingScheme::Amino);
+        let protobuf = sign_bytes(EncodingScheme::Protobuf);
+        assert_eq!(protobuf, vote().sign_bytes());
+
+        // The fields of the part set header, from offset 59 to 95, are in another order
+        assert_ne!(amino, protobuf);
+        assert_eq!(amino.len(), protobuf.len());
+        assert_eq!(amino[..59], protobuf[..59]);
+        assert_eq!(amino[95..], protobuf[95..]);
+    }
+}
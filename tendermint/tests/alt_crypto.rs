@@ -44,9 +44,13 @@ impl Sha256 for SubstrateSha256 {
 
 mod tests {
     use super::{SubstrateSha256, SubstrateSignatureVerifier};
+    use tendermint::block::Header;
     use tendermint::crypto::signature::Verifier;
     use tendermint::crypto::Sha256;
-    use tendermint::{PublicKey, Signature};
+    use tendermint::hash::Algorithm;
+    use tendermint::merkle::NonIncremental;
+    use tendermint::validator::{Info, Set};
+    use tendermint::{Hash, PublicKey, Signature};
 
     use subtle_encoding::hex;
 
@@ -61,6 +65,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn header_can_be_hashed() {
+        let header: Header = serde_json::from_str(include_str!(
+            "support/serialization/block/header_with_known_hash.json"
+        ))
+        .unwrap();
+        let expected_hash = Hash::from_hex_upper(
+            Algorithm::Sha256,
+            "F30A71F2409FB15AACAEDB6CC122DFA2525BEE9CAE521721B06BFDCA291B8D56",
+        )
+        .unwrap();
+
+        assert_eq!(header.hash(), expected_hash);
+        assert_eq!(
+            header.hash_with::<NonIncremental<SubstrateSha256>>(),
+            expected_hash
+        );
+    }
+
+    #[test]
+    fn validator_set_can_be_hashed() {
+        let validators = (1..=5)
+            .map(|seed| {
+                let signing_key = ed25519_consensus::SigningKey::from([seed; 32]);
+                Info::new(
+                    PublicKey::from(signing_key.verification_key()),
+                    (u32::from(seed) * 10).into(),
+                )
+            })
+            .collect();
+        let validator_set = Set::without_proposer(validators);
+
+        assert_eq!(
+            validator_set.hash_with::<NonIncremental<SubstrateSha256>>(),
+            validator_set.hash()
+        );
+    }
+
     //const SIGNING_KEY: &[u8] = b"59820654790d53a23d1017b50ddcdb31242e27c682a0a1372fc63c01dd48816a";
     const VERIFYING_KEY: &[u8] =
         b"03cf7a110053a95b4b25266c3416ae342eba2ca3f4658fa1069fcf750f760b8c42";
@@ -2,8 +2,8 @@ use gumdrop::Options;
 use serde::{Deserialize, Serialize};
 use simple_error::*;
 use tendermint::{
-    account, consensus::state::Ordering, private_key, public_key, public_key::PublicKey, validator,
-    vote,
+    account, consensus::state::Ordering, private_key, private_key::PrivateKey, public_key,
+    signature::Signature, validator, vote,
 };
 
 use crate::{helpers::*, Generator};
@@ -19,6 +19,11 @@ pub struct Validator {
         meta = "PRIORITY"
     )]
     pub proposer_priority: Option<i64>,
+    #[options(
+        help = "to use a secp256k1 key rather than an ed25519 one (requires the secp256k1 feature)"
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secp256k1: Option<()>,
 }
 
 impl Validator {
@@ -27,15 +32,17 @@ impl Validator {
             id: Some(id.to_string()),
             voting_power: None,
             proposer_priority: None,
+            secp256k1: None,
         }
     }
     // Question: Why do we need this option since we're already initializing id with fn new()??
     set_option!(id, &str, Some(id.to_string()));
     set_option!(voting_power, u64);
     set_option!(proposer_priority, i64);
+    set_option!(secp256k1, bool, if secp256k1 { Some(()) } else { None });
 
-    /// Get private key for this validator companion.
-    pub fn get_private_key(&self) -> Result<private_key::Ed25519, SimpleError> {
+    /// Get the seed of the private key for this validator companion.
+    fn get_seed(&self) -> Result<Vec<u8>, SimpleError> {
         let id = match &self.id {
             None => bail!("validator identifier is missing"),
             Some(id) => id,
@@ -48,6 +55,13 @@ impl Validator {
             bail!("validator identifier is too long")
         }
         bytes.extend(vec![0u8; 32 - bytes.len()].iter());
+        Ok(bytes)
+    }
+
+    /// Get the ed25519 private key for this validator companion,
+    /// regardless of whether it uses a secp256k1 key.
+    pub fn get_private_key(&self) -> Result<private_key::Ed25519, SimpleError> {
+        let bytes = self.get_seed()?;
         let signing_key = require_with!(
             private_key::Ed25519::try_from(&bytes[..]).ok(),
             "failed to construct a seed from validator identifier"
@@ -55,6 +69,47 @@ impl Validator {
         Ok(signing_key)
     }
 
+    /// Get the private key for this validator companion, of the configured type.
+    pub fn get_signing_key(&self) -> Result<PrivateKey, SimpleError> {
+        if self.secp256k1.is_none() {
+            return self.get_private_key().map(PrivateKey::Ed25519);
+        }
+
+        #[cfg(feature = "secp256k1")]
+        {
+            let bytes = self.get_seed()?;
+            let signing_key = require_with!(
+                private_key::Secp256k1::from_slice(&bytes).ok(),
+                "failed to construct a secp256k1 key from validator identifier"
+            );
+            Ok(PrivateKey::Secp256k1(signing_key))
+        }
+
+        #[cfg(not(feature = "secp256k1"))]
+        bail!("secp256k1 keys require the secp256k1 feature")
+    }
+
+    /// Sign the given message with the private key for this validator companion.
+    ///
+    /// Secp256k1 signatures are made over the SHA-256 digest of the message.
+    pub fn sign(&self, msg: &[u8]) -> Result<Signature, SimpleError> {
+        match self.get_signing_key()? {
+            PrivateKey::Ed25519(signing_key) => {
+                let signing_key = ed25519_consensus::SigningKey::try_from(signing_key).unwrap();
+                Ok(signing_key.sign(msg).into())
+            },
+            #[cfg(feature = "secp256k1")]
+            PrivateKey::Secp256k1(signing_key) => {
+                use k256::ecdsa::signature::Signer;
+
+                let signature: k256::ecdsa::Signature = signing_key.sign(msg);
+                Ok(signature.into())
+            },
+            #[allow(unreachable_patterns)]
+            _ => bail!("unsupported private key type"),
+        }
+    }
+
     /// Get public key for this validator companion.
     pub fn get_public_key(&self) -> Result<public_key::Ed25519, SimpleError> {
         self.get_private_key()
@@ -101,14 +156,15 @@ impl Generator<validator::Info> for Validator {
             id: self.id.or(default.id),
             voting_power: self.voting_power.or(default.voting_power),
             proposer_priority: self.proposer_priority.or(default.proposer_priority),
+            secp256k1: self.secp256k1.or(default.secp256k1),
         }
     }
 
     fn generate(&self) -> Result<validator::Info, SimpleError> {
-        let verification_key = self.get_private_key()?.verification_key();
+        let public_key = self.get_signing_key()?.public_key();
         let info = validator::Info {
-            address: account::Id::from(verification_key),
-            pub_key: PublicKey::from(verification_key),
+            address: account::Id::from(public_key),
+            pub_key: public_key,
             power: vote::Power::try_from(self.voting_power.unwrap_or(0)).unwrap(),
             name: None,
             proposer_priority: validator::ProposerPriority::from(
@@ -139,6 +195,8 @@ pub fn sort_validators(vals: &[Validator]) -> Vec<Validator> {
 
 #[cfg(test)]
 mod tests {
+    use tendermint::public_key::PublicKey;
+
     use super::*;
 
     fn make_publickey(pk_string: &str) -> PublicKey {
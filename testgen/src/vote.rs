@@ -87,8 +87,6 @@ impl Generator<vote::Vote> for Vote {
             None => bail!("failed to generate vote: header is missing"),
             Some(h) => h,
         };
-        let signing_key = validator.get_private_key()?;
-        let signing_key = ed25519_consensus::SigningKey::try_from(signing_key).unwrap();
         let block_validator = validator.generate()?;
         let block_header = header.generate()?;
         let block_id = if self.nil.is_some() {
@@ -138,7 +136,7 @@ impl Generator<vote::Vote> for Vote {
         };
 
         let sign_bytes = get_vote_sign_bytes(block_header.chain_id, &vote);
-        vote.signature = Some(signing_key.sign(sign_bytes.as_slice()).into());
+        vote.signature = Some(validator.sign(sign_bytes.as_slice())?);
 
         Ok(vote)
     }
@@ -24,6 +24,28 @@ use sp_std;
 
 use core::panic::PanicInfo;
 
+use tendermint::Time;
+use tendermint_light_client_verifier::{
+    options::Options, types::LightBlock, ProdVerifier, Verdict, Verifier,
+};
+
+/// Verify a light block against a trusted one, so that the core types
+/// (`LightBlock`, `ValidatorSet`, `Header`, `Commit`) and the verification
+/// of their signatures are checked for `no_std` compliance as well.
+pub fn verify(
+    untrusted: &LightBlock,
+    trusted: &LightBlock,
+    options: &Options,
+    now: Time,
+) -> Verdict {
+    ProdVerifier::default().verify_update_header(
+        untrusted.as_untrusted_state(),
+        trusted.as_trusted_state(),
+        options,
+        now,
+    )
+}
+
 /*
 
 This function definition checks for the compliance of no-std in
@@ -87,6 +87,16 @@ pub fn broadcast_tx(method: &str, key: &str, value: &str) -> PlannedInteraction
     .into()
 }
 
+pub fn check_tx(key: &str, value: &str) -> PlannedInteraction {
+    Request::new(
+        "check_tx",
+        json!({
+            "tx": encode_kvpair(key, value),
+        }),
+    )
+    .into()
+}
+
 pub fn commit(height: u64) -> PlannedInteraction {
     Request::new(
         "commit",
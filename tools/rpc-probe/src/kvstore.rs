@@ -37,6 +37,7 @@ pub fn quick_probe_plan(output_path: &Path, request_wait: Duration) -> Result<Pl
                 broadcast_tx("async", "async-key", "value"),
                 broadcast_tx("sync", "sync-key", "value"),
                 broadcast_tx("commit", "commit-key", "value"),
+                check_tx("check-key", "value"),
                 genesis(),
                 net_info(),
                 status(),